@@ -0,0 +1,105 @@
+use std::{
+    env,
+    fs::{self, File},
+    hint::black_box,
+    io::Read,
+    path::PathBuf,
+};
+
+use base64_stream::{
+    FromBase64Reader, ToBase64Reader,
+    base64::{Engine, engine::general_purpose::STANDARD},
+};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+
+const PLAIN_LENGTH: usize = 4 * 1024 * 1024;
+
+/// Smaller than the default buffer size, so every read goes through the internal buffer and every inner read is capped by its size.
+const SMALL_DESTINATION: usize = 4095;
+
+/// Larger than the default buffer size, so reads are staged directly in the destination.
+const LARGE_DESTINATION: usize = 256 * 1024;
+
+/// Writes `data` to a temporary file, so the inner reads are real system calls like in the common case of streaming a file.
+fn temp_file(name: &str, data: &[u8]) -> PathBuf {
+    let path = env::temp_dir().join(format!("base64-stream-bench-{}-{name}", std::process::id()));
+
+    fs::write(&path, data).unwrap();
+
+    path
+}
+
+fn drain<R: Read>(mut reader: R, destination: &mut [u8]) -> usize {
+    let mut total = 0;
+
+    loop {
+        match reader.read(destination).unwrap() {
+            0 => return total,
+            c => total += c,
+        }
+    }
+}
+
+fn encode(c: &mut Criterion) {
+    let plain: Vec<u8> = (0u8..=255).cycle().take(PLAIN_LENGTH).collect();
+
+    let path = temp_file("plain", &plain);
+
+    let mut group = c.benchmark_group("ToBase64Reader::read");
+
+    group.throughput(Throughput::Bytes(PLAIN_LENGTH as u64));
+
+    for destination_length in [SMALL_DESTINATION, LARGE_DESTINATION] {
+        let mut destination = vec![0u8; destination_length];
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(destination_length),
+            &path,
+            |b, path| {
+                b.iter(|| {
+                    let reader = ToBase64Reader::new(File::open(path).unwrap());
+
+                    black_box(drain(reader, &mut destination))
+                })
+            },
+        );
+    }
+
+    group.finish();
+
+    fs::remove_file(path).unwrap();
+}
+
+fn decode(c: &mut Criterion) {
+    let plain: Vec<u8> = (0u8..=255).cycle().take(PLAIN_LENGTH).collect();
+    let encoded = STANDARD.encode(&plain).into_bytes();
+
+    let path = temp_file("encoded", &encoded);
+
+    let mut group = c.benchmark_group("FromBase64Reader::read");
+
+    group.throughput(Throughput::Bytes(encoded.len() as u64));
+
+    for destination_length in [SMALL_DESTINATION, LARGE_DESTINATION] {
+        let mut destination = vec![0u8; destination_length];
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(destination_length),
+            &path,
+            |b, path| {
+                b.iter(|| {
+                    let reader = FromBase64Reader::new(File::open(path).unwrap());
+
+                    black_box(drain(reader, &mut destination))
+                })
+            },
+        );
+    }
+
+    group.finish();
+
+    fs::remove_file(path).unwrap();
+}
+
+criterion_group!(benches, encode, decode);
+criterion_main!(benches);
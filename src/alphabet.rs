@@ -0,0 +1,31 @@
+/// Returns the table which maps every symbol of `alphabet` to its value, with 0xFF for the other bytes. [`RadixEngine`](crate::RadixEngine) and [`Base64Alphabet`](crate::Base64Alphabet) both validate their alphabets here, so that they accept the same ones.
+///
+/// # Panics
+///
+/// Panics if `alphabet` has duplicates or bytes which are not printable ASCII, or if `padding` is not printable ASCII or is in `alphabet`.
+pub(crate) const fn decode_table(alphabet: &[u8], padding: Option<u8>) -> [u8; 256] {
+    let mut decode_table = [0xFF; 256];
+    let mut i = 0;
+
+    while i < alphabet.len() {
+        let symbol = alphabet[i];
+
+        assert!(is_printable(symbol), "the alphabet must be printable ASCII");
+        assert!(decode_table[symbol as usize] == 0xFF, "the alphabet must not have duplicates");
+
+        decode_table[symbol as usize] = i as u8;
+        i += 1;
+    }
+
+    if let Some(padding) = padding {
+        assert!(is_printable(padding), "the padding must be printable ASCII");
+        assert!(decode_table[padding as usize] == 0xFF, "the padding must not be in the alphabet");
+    }
+
+    decode_table
+}
+
+#[inline]
+const fn is_printable(b: u8) -> bool {
+    b >= 0x20 && b < 0x7F
+}
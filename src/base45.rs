@@ -0,0 +1,168 @@
+use crate::Base45Error;
+
+const ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Maps each character of [`ALPHABET`] to its value, with 0xFF for the other bytes.
+const DECODE_TABLE: [u8; 256] = {
+    let mut table = [0xFF; 256];
+    let mut i = 0;
+
+    while i < 45 {
+        table[ALPHABET[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    table
+};
+
+/// Encodes plain data to Base45 (RFC 9285), carrying an odd byte over to the next call.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Base45Encoder {
+    buf:        [u8; 2],
+    buf_length: usize,
+}
+
+impl Base45Encoder {
+    /// Encodes a block of 1 or 2 bytes into 2 or 3 characters, least significant first.
+    #[inline]
+    fn encode_block(block: &[u8], output: &mut Vec<u8>) {
+        let n = block.iter().fold(0usize, |n, &b| n << 8 | usize::from(b));
+
+        output.push(ALPHABET[n % 45]);
+        output.push(ALPHABET[n / 45 % 45]);
+
+        if block.len() == 2 {
+            output.push(ALPHABET[n / (45 * 45)]);
+        }
+    }
+
+    pub(crate) fn encode(&mut self, mut input: &[u8], output: &mut Vec<u8>) {
+        if self.buf_length > 0 {
+            let Some((&b, rest)) = input.split_first() else {
+                return;
+            };
+
+            self.buf[1] = b;
+            self.buf_length = 0;
+
+            Self::encode_block(&self.buf, output);
+
+            input = rest;
+        }
+
+        let blocks = input.chunks_exact(2);
+        let remainder = blocks.remainder();
+
+        output.reserve(blocks.len() * 3);
+
+        for block in blocks {
+            Self::encode_block(block, output);
+        }
+
+        if let [b] = remainder {
+            self.buf[0] = *b;
+            self.buf_length = 1;
+        }
+    }
+
+    /// Encodes the carried byte, if any.
+    pub(crate) fn finish(&mut self, output: &mut Vec<u8>) {
+        if self.buf_length > 0 {
+            Self::encode_block(&self.buf[..1], output);
+
+            self.buf_length = 0;
+        }
+    }
+}
+
+/// Decodes Base45 (RFC 9285) data, carrying an incomplete triplet over to the next call.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Base45Decoder {
+    buf:        [u8; 3],
+    buf_length: usize,
+    /// The offset of the first character in `buf`.
+    offset:     u64,
+}
+
+impl Base45Decoder {
+    /// Decodes a block of 2 or 3 characters starting at `offset` into 1 or 2 bytes.
+    #[inline]
+    fn decode_block(block: &[u8], offset: u64, output: &mut Vec<u8>) -> Result<(), Base45Error> {
+        let mut values = [0u32; 3];
+
+        for (i, &symbol) in block.iter().enumerate() {
+            values[i] = match DECODE_TABLE[symbol as usize] {
+                0xFF => return Err(Base45Error::InvalidSymbol(offset + i as u64, symbol)),
+                value => u32::from(value),
+            };
+        }
+
+        let n = values[..block.len()].iter().rev().fold(0, |n, &value| n * 45 + value);
+
+        if block.len() == 3 {
+            let n = u16::try_from(n).map_err(|_| Base45Error::OutOfRange(offset))?;
+
+            output.extend_from_slice(&n.to_be_bytes());
+        } else {
+            output.push(u8::try_from(n).map_err(|_| Base45Error::OutOfRange(offset))?);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn decode(
+        &mut self,
+        mut input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), Base45Error> {
+        if self.buf_length > 0 {
+            let c = (3 - self.buf_length).min(input.len());
+
+            self.buf[self.buf_length..self.buf_length + c].copy_from_slice(&input[..c]);
+            self.buf_length += c;
+
+            input = &input[c..];
+
+            if self.buf_length < 3 {
+                return Ok(());
+            }
+
+            Self::decode_block(&self.buf, self.offset, output)?;
+
+            self.buf_length = 0;
+            self.offset += 3;
+        }
+
+        let blocks = input.chunks_exact(3);
+        let remainder = blocks.remainder();
+
+        output.reserve(blocks.len() * 2);
+
+        for block in blocks {
+            Self::decode_block(block, self.offset, output)?;
+
+            self.offset += 3;
+        }
+
+        self.buf[..remainder.len()].copy_from_slice(remainder);
+        self.buf_length = remainder.len();
+
+        Ok(())
+    }
+
+    /// Decodes the carried pair of characters, if any. A single character left over is an error.
+    pub(crate) fn finish(&mut self, output: &mut Vec<u8>) -> Result<(), Base45Error> {
+        match self.buf_length {
+            0 => Ok(()),
+            1 => Err(Base45Error::TruncatedBlock(self.offset)),
+            _ => {
+                Self::decode_block(&self.buf[..2], self.offset, output)?;
+
+                self.buf_length = 0;
+                self.offset += 2;
+
+                Ok(())
+            },
+        }
+    }
+}
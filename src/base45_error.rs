@@ -0,0 +1,31 @@
+use std::{error::Error, fmt};
+
+/// The error returned when decoding invalid Base45 data. It is wrapped in an [`io::Error`](std::io::Error) of the kind [`InvalidData`](std::io::ErrorKind::InvalidData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base45Error {
+    /// The byte at the given offset is not in the alphabet.
+    InvalidSymbol(u64, u8),
+    /// The triplet, or the final pair, starting at the given offset encodes a value which does not fit in its bytes.
+    OutOfRange(u64),
+    /// A single character is left over at the given offset, which cannot encode anything.
+    TruncatedBlock(u64),
+}
+
+impl fmt::Display for Base45Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base45Error::InvalidSymbol(offset, byte) => {
+                write!(f, "invalid Base45 symbol {byte:?} at offset {offset}")
+            },
+            Base45Error::OutOfRange(offset) => {
+                write!(f, "the Base45 block at offset {offset} is out of range")
+            },
+            Base45Error::TruncatedBlock(offset) => {
+                write!(f, "a single Base45 character is left over at offset {offset}")
+            },
+        }
+    }
+}
+
+impl Error for Base45Error {}
@@ -0,0 +1,141 @@
+#[cfg(feature = "base58check")]
+use sha2::{Digest, Sha256};
+
+/// The number of Base58 digits held by a limb of [`Base58Encoder`].
+const DIGITS_PER_LIMB: usize = 5;
+
+/// 58 to the power of [`DIGITS_PER_LIMB`], which fits in 30 bits.
+const LIMB_BASE: u64 = 58u64.pow(DIGITS_PER_LIMB as u32);
+
+/// The number of checksum bytes appended by Base58Check.
+#[cfg(feature = "base58check")]
+pub(crate) const CHECKSUM_LENGTH: usize = 4;
+
+/// Returns the Base58Check checksum of the data hashed by `sha256`, i.e. the first 4 bytes of their double SHA-256.
+#[cfg(feature = "base58check")]
+#[inline]
+pub(crate) fn checksum(sha256: Sha256) -> [u8; CHECKSUM_LENGTH] {
+    Sha256::digest(sha256.finalize())[..CHECKSUM_LENGTH].try_into().unwrap()
+}
+
+/// Accumulates plain bytes into a big number held in limbs of five Base58 digits.
+///
+/// Every byte multiplies the whole number, so the cost of pushing a byte grows with the number of bytes pushed before.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Base58Encoder {
+    /// The leading zero bytes, each of which becomes a zero digit.
+    zeros: u64,
+    /// Little-endian.
+    limbs: Vec<u32>,
+}
+
+impl Base58Encoder {
+    pub(crate) fn push(&mut self, mut bytes: &[u8]) {
+        if self.limbs.is_empty() {
+            let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+            self.zeros += zeros as u64;
+            bytes = &bytes[zeros..];
+        }
+
+        // four bytes at a time keep every product within 62 bits
+        for chunk in bytes.chunks(4) {
+            let shift = 8 * chunk.len() as u32;
+            let mut carry = chunk.iter().fold(0u64, |n, &b| n << 8 | u64::from(b));
+
+            for limb in self.limbs.iter_mut() {
+                let n = (u64::from(*limb) << shift) + carry;
+
+                *limb = (n % LIMB_BASE) as u32;
+                carry = n / LIMB_BASE;
+            }
+
+            while carry > 0 {
+                self.limbs.push((carry % LIMB_BASE) as u32);
+                carry /= LIMB_BASE;
+            }
+        }
+    }
+
+    /// Returns the digits of the number, most significant first.
+    pub(crate) fn into_digits(self) -> Vec<u8> {
+        let mut digits = vec![0; self.zeros as usize];
+
+        digits.reserve(self.limbs.len() * DIGITS_PER_LIMB);
+
+        for (i, &limb) in self.limbs.iter().rev().enumerate() {
+            let mut limb_digits = [0u8; DIGITS_PER_LIMB];
+            let mut limb = limb;
+
+            for digit in limb_digits.iter_mut().rev() {
+                *digit = (limb % 58) as u8;
+                limb /= 58;
+            }
+
+            // only the most significant limb has leading zeros to drop
+            let start =
+                if i == 0 { limb_digits.iter().take_while(|&&d| d == 0).count() } else { 0 };
+
+            digits.extend_from_slice(&limb_digits[start..]);
+        }
+
+        digits
+    }
+}
+
+/// Accumulates Base58 digits into a big number held in 32-bit limbs.
+///
+/// Every digit multiplies the whole number, so the cost of pushing a digit grows with the number of digits pushed before.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Base58Decoder {
+    /// The leading zero digits, each of which becomes a zero byte.
+    zeros: u64,
+    /// Little-endian.
+    limbs: Vec<u32>,
+}
+
+impl Base58Decoder {
+    pub(crate) fn push(&mut self, mut digits: &[u8]) {
+        if self.limbs.is_empty() {
+            let zeros = digits.iter().take_while(|&&d| d == 0).count();
+
+            self.zeros += zeros as u64;
+            digits = &digits[zeros..];
+        }
+
+        // five digits at a time keep every product within 62 bits
+        for chunk in digits.chunks(DIGITS_PER_LIMB) {
+            let multiplier = 58u64.pow(chunk.len() as u32);
+            let mut carry = chunk.iter().fold(0u64, |n, &d| n * 58 + u64::from(d));
+
+            for limb in self.limbs.iter_mut() {
+                let n = u64::from(*limb) * multiplier + carry;
+
+                *limb = n as u32;
+                carry = n >> 32;
+            }
+
+            if carry > 0 {
+                self.limbs.push(carry as u32);
+            }
+        }
+    }
+
+    /// Returns the bytes of the number, most significant first.
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
+        let mut bytes = vec![0; self.zeros as usize];
+
+        bytes.reserve(self.limbs.len() * 4);
+
+        for (i, &limb) in self.limbs.iter().rev().enumerate() {
+            let limb_bytes = limb.to_be_bytes();
+
+            // only the most significant limb has leading zeros to drop
+            let start = if i == 0 { limb_bytes.iter().take_while(|&&b| b == 0).count() } else { 0 };
+
+            bytes.extend_from_slice(&limb_bytes[start..]);
+        }
+
+        bytes
+    }
+}
@@ -0,0 +1,59 @@
+const BITCOIN_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const RIPPLE_ALPHABET: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+const FLICKR_ALPHABET: &[u8; 58] = b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// Builds the table mapping each character of `alphabet` to its value, with 0xFF for the other bytes.
+const fn decode_table(alphabet: &[u8; 58]) -> [u8; 256] {
+    let mut table = [0xFF; 256];
+    let mut i = 0;
+
+    while i < 58 {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    table
+}
+
+const BITCOIN_TABLE: [u8; 256] = decode_table(BITCOIN_ALPHABET);
+const RIPPLE_TABLE: [u8; 256] = decode_table(RIPPLE_ALPHABET);
+const FLICKR_TABLE: [u8; 256] = decode_table(FLICKR_ALPHABET);
+
+/// The alphabet of Base58, which leaves out the characters that look alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Base58Alphabet {
+    /// The alphabet of Bitcoin addresses, i.e. the digits, then the uppercase letters, then the lowercase letters.
+    #[default]
+    Bitcoin,
+    /// The alphabet of Ripple addresses, which starts with `r`.
+    Ripple,
+    /// The alphabet of Flickr short URLs, i.e. the digits, then the lowercase letters, then the uppercase letters.
+    Flickr,
+}
+
+impl Base58Alphabet {
+    /// Returns the 58 characters of this alphabet in the order of their values.
+    #[inline]
+    pub const fn symbols(self) -> &'static [u8; 58] {
+        match self {
+            Base58Alphabet::Bitcoin => BITCOIN_ALPHABET,
+            Base58Alphabet::Ripple => RIPPLE_ALPHABET,
+            Base58Alphabet::Flickr => FLICKR_ALPHABET,
+        }
+    }
+
+    /// Returns the value of the character `symbol`.
+    #[inline]
+    pub(crate) const fn decode_symbol(self, symbol: u8) -> Option<u8> {
+        let table = match self {
+            Base58Alphabet::Bitcoin => &BITCOIN_TABLE,
+            Base58Alphabet::Ripple => &RIPPLE_TABLE,
+            Base58Alphabet::Flickr => &FLICKR_TABLE,
+        };
+
+        match table[symbol as usize] {
+            0xFF => None,
+            value => Some(value),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::{error::Error, fmt};
+
+/// The error returned by [`FromBase58Reader`](crate::FromBase58Reader) when decoding invalid Base58 data. It is wrapped in an [`io::Error`](std::io::Error) of the kind [`InvalidData`](std::io::ErrorKind::InvalidData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base58Error {
+    /// The byte at the given offset is not in the alphabet.
+    InvalidSymbol(u64, u8),
+    /// The decoded data are shorter than the 4 bytes of a Base58Check checksum.
+    MissingChecksum,
+    /// The Base58Check checksum does not match the decoded data.
+    ChecksumMismatch,
+}
+
+impl fmt::Display for Base58Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base58Error::InvalidSymbol(offset, byte) => {
+                write!(f, "invalid Base58 symbol {byte:?} at offset {offset}")
+            },
+            Base58Error::MissingChecksum => {
+                f.write_str("the data are too short to have a Base58Check checksum")
+            },
+            Base58Error::ChecksumMismatch => {
+                f.write_str("the Base58Check checksum does not match the data")
+            },
+        }
+    }
+}
+
+impl Error for Base58Error {}
@@ -0,0 +1,191 @@
+use base64::{
+    DecodeError, DecodeSliceError, EncodeSliceError, Engine,
+    alphabet::{Alphabet, ParseAlphabetError},
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+};
+
+use crate::alphabet;
+
+/// A custom base64 alphabet of 64 symbols with an optional padding character, for the alphabets which the [`Base64Variant`](crate::Base64Variant)s do not cover.
+///
+/// The alphabet is built by a `const fn`, so it can be a `const` or a `static` whose decode table is computed at compile time, and an invalid alphabet is a compile error. With padding, the encoded data are padded to whole 4-symbol blocks and the padding is required when decoding. Without it, the padding is rejected.
+///
+/// ```rust
+/// use std::io::Write;
+///
+/// use base64_stream::{Base64Alphabet, ToBase64Writer};
+///
+/// // the alphabet of RFC 4648 with `*` as the padding
+/// static STARRED: Base64Alphabet = Base64Alphabet::new(
+///     b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+///     Some(b'*'),
+/// );
+///
+/// let mut writer = ToBase64Writer::new(Vec::new()).with_alphabet(&STARRED);
+///
+/// writer.write_all(b"f").unwrap();
+///
+/// assert_eq!(b"Zg**".as_slice(), writer.finish().unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Base64Alphabet {
+    engine:  GeneralPurpose,
+    padding: Option<u8>,
+}
+
+impl Base64Alphabet {
+    /// The alphabet of bcrypt, `./A-Za-z0-9`, without padding.
+    pub const BCRYPT: Base64Alphabet = Base64Alphabet::new(
+        b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        None,
+    );
+    /// The alphabet of IMAP mailbox names (RFC 3501), `A-Za-z0-9+,`, without padding.
+    pub const IMAP_MAILBOX: Base64Alphabet = Base64Alphabet::new(
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+,",
+        None,
+    );
+
+    /// Creates an alphabet of `symbols` in the order of their values, padded with `padding` if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `symbols` has duplicates or bytes which are not printable ASCII, or if `padding` is not printable ASCII or is in `symbols`. `=` is reserved for the padding, so it cannot be a symbol even without padding.
+    pub const fn new(symbols: &[u8; 64], padding: Option<u8>) -> Base64Alphabet {
+        let decode_table = alphabet::decode_table(symbols, padding);
+
+        assert!(decode_table[b'=' as usize] == 0xFF, "`=` is reserved for the padding");
+
+        // the checks above already rule out every error below
+        let symbols = match core::str::from_utf8(symbols) {
+            Ok(symbols) => symbols,
+            Err(_) => panic!("the alphabet must be printable ASCII"),
+        };
+
+        let alphabet = match Alphabet::new(symbols) {
+            Ok(alphabet) => alphabet,
+            Err(ParseAlphabetError::InvalidLength) => panic!("the alphabet must have 64 symbols"),
+            Err(ParseAlphabetError::DuplicatedByte(_)) => {
+                panic!("the alphabet must not have duplicates")
+            },
+            Err(ParseAlphabetError::UnprintableByte(_)) => {
+                panic!("the alphabet must be printable ASCII")
+            },
+            Err(ParseAlphabetError::ReservedByte(_)) => panic!("`=` is reserved for the padding"),
+        };
+
+        let config = GeneralPurposeConfig::new()
+            .with_encode_padding(padding.is_some())
+            .with_decode_padding_mode(if padding.is_some() {
+                DecodePaddingMode::RequireCanonical
+            } else {
+                DecodePaddingMode::RequireNone
+            });
+
+        Base64Alphabet {
+            engine: GeneralPurpose::new(&alphabet, config),
+            padding,
+        }
+    }
+
+    /// Returns the padding character, if any.
+    #[inline]
+    pub const fn padding(&self) -> Option<u8> {
+        self.padding
+    }
+}
+
+/// An engine along with the padding character which stands in for the `=` of the engine.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Base64Codec {
+    engine:  &'static GeneralPurpose,
+    padding: u8,
+}
+
+impl Base64Codec {
+    #[inline]
+    pub(crate) const fn new(engine: &'static GeneralPurpose) -> Base64Codec {
+        Base64Codec {
+            engine,
+            padding: b'=',
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_alphabet(alphabet: &'static Base64Alphabet) -> Base64Codec {
+        Base64Codec {
+            engine:  &alphabet.engine,
+            padding: match alphabet.padding {
+                Some(padding) => padding,
+                None => b'=',
+            },
+        }
+    }
+
+    /// Encodes `input` into `output` like [`Engine::encode_slice`], with the padding replaced.
+    #[inline]
+    pub(crate) fn encode_slice(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, EncodeSliceError> {
+        let length = self.engine.encode_slice(input, output)?;
+
+        if self.padding != b'=' {
+            // the padding can only be the last two symbols
+            for b in output[length.saturating_sub(2)..length].iter_mut() {
+                if *b == b'=' {
+                    *b = self.padding;
+                }
+            }
+        }
+
+        Ok(length)
+    }
+
+    /// Decodes `input` into `output` like [`Engine::decode_slice`], with the padding replaced. `=` is rejected if it is not the padding.
+    pub(crate) fn decode_slice(
+        &self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, DecodeSliceError> {
+        if self.padding == b'=' || input.is_empty() {
+            return self.engine.decode_slice(input, output);
+        }
+
+        // the padding can only be in the last block, which is translated on its own
+        let split = (input.len() - 1) & !0b11;
+        let (body, tail) = input.split_at(split);
+
+        if let Some(index) = input.iter().position(|&b| b == b'=') {
+            return Err(DecodeError::InvalidByte(index, b'=').into());
+        }
+
+        let body_length = self.engine.decode_slice(body, output)?;
+
+        let mut block = [0; 4];
+
+        for (b, &symbol) in block.iter_mut().zip(tail) {
+            *b = if symbol == self.padding { b'=' } else { symbol };
+        }
+
+        let tail_length = self
+            .engine
+            .decode_slice(&block[..tail.len()], &mut output[body_length..])
+            .map_err(|error| match error {
+                DecodeSliceError::DecodeError(DecodeError::InvalidByte(index, b)) => {
+                    let b = if b == b'=' { self.padding } else { b };
+
+                    DecodeError::InvalidByte(split + index, b).into()
+                },
+                DecodeSliceError::DecodeError(DecodeError::InvalidLength(length)) => {
+                    DecodeError::InvalidLength(split + length).into()
+                },
+                DecodeSliceError::DecodeError(DecodeError::InvalidLastSymbol(index, b)) => {
+                    DecodeError::InvalidLastSymbol(split + index, b).into()
+                },
+                error => error,
+            })?;
+
+        Ok(body_length + tail_length)
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+    fmt,
+    io::{self, Read},
+    iter::FusedIterator,
+};
+
+use base64::{DecodeError, DecodeSliceError};
+
+use crate::{FromBase64Reader, ToBase64Reader};
+
+/// The size of the buffers behind every iterator adaptor.
+const BUFFER_SIZE: usize = 64;
+
+/// The size of the buffer of the decoding reader. It is larger than the reads of [`ReadBytes`], so that the reader always decodes from its own buffer, which tells where an error is in the stream.
+const DECODE_BUFFER_SIZE: usize = BUFFER_SIZE * 2;
+
+/// Reads the bytes of an iterator.
+struct ByteReader<I>(I);
+
+impl<I: Iterator<Item = u8>> Read for ByteReader<I> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        let mut c = 0;
+
+        // `buf` goes first so that no byte is taken from the iterator once it is full
+        for (slot, b) in buf.iter_mut().zip(&mut self.0) {
+            *slot = b;
+            c += 1;
+        }
+
+        Ok(c)
+    }
+}
+
+/// Reads the bytes of an iterator of byte chunks.
+struct ChunkReader<I: Iterator> {
+    chunks: I,
+    chunk:  Option<I::Item>,
+    offset: usize,
+}
+
+impl<I: Iterator> ChunkReader<I> {
+    #[inline]
+    fn new(chunks: I) -> ChunkReader<I> {
+        ChunkReader {
+            chunks,
+            chunk: None,
+            offset: 0,
+        }
+    }
+}
+
+impl<I: Iterator> Read for ChunkReader<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            if let Some(chunk) = self.chunk.as_ref() {
+                let rest = &chunk.as_ref()[self.offset..];
+
+                if !rest.is_empty() {
+                    let c = rest.len().min(buf.len());
+
+                    buf[..c].copy_from_slice(&rest[..c]);
+
+                    self.offset += c;
+
+                    return Ok(c);
+                }
+            }
+
+            match self.chunks.next() {
+                Some(chunk) => {
+                    self.chunk = Some(chunk);
+                    self.offset = 0;
+                },
+                None => {
+                    self.chunk = None;
+
+                    return Ok(0);
+                },
+            }
+        }
+    }
+}
+
+/// Yields the bytes of a reader one by one, stopping after EOF or the first error.
+struct ReadBytes<R: Read> {
+    reader: R,
+    buf:    [u8; BUFFER_SIZE],
+    length: usize,
+    offset: usize,
+    done:   bool,
+}
+
+impl<R: Read> ReadBytes<R> {
+    #[inline]
+    fn new(reader: R) -> ReadBytes<R> {
+        ReadBytes {
+            reader,
+            buf: [0; BUFFER_SIZE],
+            length: 0,
+            offset: 0,
+            done: false,
+        }
+    }
+
+    fn next(&mut self) -> Option<Result<u8, io::Error>> {
+        if self.offset == self.length {
+            if self.done {
+                return None;
+            }
+
+            match self.reader.read(&mut self.buf) {
+                Ok(0) => {
+                    self.done = true;
+
+                    return None;
+                },
+                Ok(c) => {
+                    self.length = c;
+                    self.offset = 0;
+                },
+                Err(error) => {
+                    self.done = true;
+
+                    return Some(Err(error));
+                },
+            }
+        }
+
+        let b = self.buf[self.offset];
+
+        self.offset += 1;
+
+        Some(Ok(b))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.length - self.offset, None)
+    }
+}
+
+#[inline]
+fn expect_encoded(result: Result<u8, io::Error>) -> u8 {
+    match result {
+        Ok(b) => b,
+        Err(_) => unreachable!("encoding the bytes of an iterator never fails"),
+    }
+}
+
+/// Takes the decode error out of an error of `reader`, whose inner reader never fails, with the offset counted from the start of the stream instead of the bytes buffered by `reader`.
+fn decode_error<R: Read>(
+    reader: &FromBase64Reader<R, DECODE_BUFFER_SIZE>,
+    error: io::Error,
+) -> DecodeError {
+    let base = (reader.progress().encoded - reader.buffered_encoded_length() as u64) as usize;
+
+    match error.into_inner().and_then(|e| e.downcast::<DecodeSliceError>().ok()).map(|e| *e) {
+        Some(DecodeSliceError::DecodeError(error)) => match error {
+            DecodeError::InvalidByte(offset, b) => DecodeError::InvalidByte(base + offset, b),
+            DecodeError::InvalidLength(length) => DecodeError::InvalidLength(base + length),
+            DecodeError::InvalidLastSymbol(offset, b) => {
+                DecodeError::InvalidLastSymbol(base + offset, b)
+            },
+            DecodeError::InvalidPadding => DecodeError::InvalidPadding,
+        },
+        _ => unreachable!("decoding the bytes of an iterator only fails on invalid base64 data"),
+    }
+}
+
+/// An iterator of the base64 bytes encoded from an iterator of plain bytes. See [`EncodeBase64Ext::encode_base64`].
+pub struct EncodeBase64<I: Iterator<Item = u8>> {
+    inner: ReadBytes<ToBase64Reader<ByteReader<I>, BUFFER_SIZE>>,
+}
+
+impl<I: Iterator<Item = u8>> fmt::Debug for EncodeBase64<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodeBase64").finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator<Item = u8>> Iterator for EncodeBase64<I> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.inner.next().map(expect_encoded)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: Iterator<Item = u8>> FusedIterator for EncodeBase64<I> {}
+
+/// An iterator of the base64 characters encoded from an iterator of plain bytes. See [`EncodeBase64Ext::encode_base64_chars`].
+#[derive(Debug)]
+pub struct EncodeBase64Chars<I: Iterator<Item = u8>> {
+    inner: EncodeBase64<I>,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for EncodeBase64Chars<I> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next().map(char::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: Iterator<Item = u8>> FusedIterator for EncodeBase64Chars<I> {}
+
+/// An iterator of the base64 bytes encoded from an iterator of plain byte chunks. See [`EncodeBase64Ext::encode_base64_chunks`].
+pub struct EncodeBase64Chunks<I: Iterator>
+where
+    I::Item: AsRef<[u8]>, {
+    inner: ReadBytes<ToBase64Reader<ChunkReader<I>, BUFFER_SIZE>>,
+}
+
+impl<I: Iterator> fmt::Debug for EncodeBase64Chunks<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodeBase64Chunks").finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator> Iterator for EncodeBase64Chunks<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.inner.next().map(expect_encoded)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: Iterator> FusedIterator for EncodeBase64Chunks<I> where I::Item: AsRef<[u8]> {}
+
+/// An iterator of the plain bytes decoded from an iterator of base64 bytes. See [`DecodeBase64Ext::decode_base64`].
+pub struct DecodeBase64<I: Iterator<Item = u8>> {
+    inner: ReadBytes<FromBase64Reader<ByteReader<I>, DECODE_BUFFER_SIZE>>,
+}
+
+impl<I: Iterator<Item = u8>> fmt::Debug for DecodeBase64<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeBase64").finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator<Item = u8>> Iterator for DecodeBase64<I> {
+    type Item = Result<u8, DecodeError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<u8, DecodeError>> {
+        self.inner.next().map(|result| result.map_err(|e| decode_error(&self.inner.reader, e)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: Iterator<Item = u8>> FusedIterator for DecodeBase64<I> {}
+
+/// An iterator of the plain bytes decoded from an iterator of base64 byte chunks. See [`DecodeBase64Ext::decode_base64_chunks`].
+pub struct DecodeBase64Chunks<I: Iterator>
+where
+    I::Item: AsRef<[u8]>, {
+    inner: ReadBytes<FromBase64Reader<ChunkReader<I>, DECODE_BUFFER_SIZE>>,
+}
+
+impl<I: Iterator> fmt::Debug for DecodeBase64Chunks<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeBase64Chunks").finish_non_exhaustive()
+    }
+}
+
+impl<I: Iterator> Iterator for DecodeBase64Chunks<I>
+where
+    I::Item: AsRef<[u8]>,
+{
+    type Item = Result<u8, DecodeError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<u8, DecodeError>> {
+        self.inner.next().map(|result| result.map_err(|e| decode_error(&self.inner.reader, e)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: Iterator> FusedIterator for DecodeBase64Chunks<I> where I::Item: AsRef<[u8]> {}
+
+/// Encodes an iterator of plain bytes, or of plain byte chunks, to standard base64.
+pub trait EncodeBase64Ext: Iterator + Sized {
+    /// Encodes the bytes of this iterator to base64 bytes.
+    #[inline]
+    fn encode_base64(self) -> EncodeBase64<Self>
+    where
+        Self: Iterator<Item = u8>, {
+        EncodeBase64 {
+            inner: ReadBytes::new(ToBase64Reader::new2(ByteReader(self)))
+        }
+    }
+
+    /// Encodes the bytes of this iterator to base64 characters.
+    #[inline]
+    fn encode_base64_chars(self) -> EncodeBase64Chars<Self>
+    where
+        Self: Iterator<Item = u8>, {
+        EncodeBase64Chars {
+            inner: self.encode_base64()
+        }
+    }
+
+    /// Encodes the byte chunks of this iterator to base64 bytes, as if the chunks were joined.
+    #[inline]
+    fn encode_base64_chunks(self) -> EncodeBase64Chunks<Self>
+    where
+        Self::Item: AsRef<[u8]>, {
+        EncodeBase64Chunks {
+            inner: ReadBytes::new(ToBase64Reader::new2(ChunkReader::new(self)))
+        }
+    }
+}
+
+impl<I: Iterator> EncodeBase64Ext for I {}
+
+/// Decodes an iterator of standard base64 bytes, or of base64 byte chunks, to plain bytes.
+///
+/// The decoding iterators yield a single error for invalid base64 data and end after it. Its offset is counted from the start of the base64 data.
+pub trait DecodeBase64Ext: Iterator + Sized {
+    /// Decodes the base64 bytes of this iterator.
+    #[inline]
+    fn decode_base64(self) -> DecodeBase64<Self>
+    where
+        Self: Iterator<Item = u8>, {
+        DecodeBase64 {
+            inner: ReadBytes::new(FromBase64Reader::new2(ByteReader(self)))
+        }
+    }
+
+    /// Decodes the base64 byte chunks of this iterator, as if the chunks were joined.
+    #[inline]
+    fn decode_base64_chunks(self) -> DecodeBase64Chunks<Self>
+    where
+        Self::Item: AsRef<[u8]>, {
+        DecodeBase64Chunks {
+            inner: ReadBytes::new(FromBase64Reader::new2(ChunkReader::new(self)))
+        }
+    }
+}
+
+impl<I: Iterator> DecodeBase64Ext for I {}
@@ -0,0 +1,40 @@
+use base64::engine::{
+    GeneralPurpose,
+    general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
+};
+
+/// A combination of the standard or URL-safe alphabet and a padding mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base64Variant {
+    /// `+` and `/`, with padding.
+    Standard,
+    /// `+` and `/`, without padding.
+    StandardNoPad,
+    /// `-` and `_`, with padding.
+    UrlSafe,
+    /// `-` and `_`, without padding.
+    UrlSafeNoPad,
+}
+
+impl Base64Variant {
+    #[inline]
+    pub(crate) const fn new(url_safe: bool, padded: bool) -> Base64Variant {
+        match (url_safe, padded) {
+            (false, true) => Base64Variant::Standard,
+            (false, false) => Base64Variant::StandardNoPad,
+            (true, true) => Base64Variant::UrlSafe,
+            (true, false) => Base64Variant::UrlSafeNoPad,
+        }
+    }
+
+    /// Returns the engine which encodes and strictly decodes this variant.
+    #[inline]
+    pub const fn engine(self) -> &'static GeneralPurpose {
+        match self {
+            Base64Variant::Standard => &STANDARD,
+            Base64Variant::StandardNoPad => &STANDARD_NO_PAD,
+            Base64Variant::UrlSafe => &URL_SAFE,
+            Base64Variant::UrlSafeNoPad => &URL_SAFE_NO_PAD,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+/// The characters of the 5-bit values of the data part.
+pub(crate) const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Maps each character of [`CHARSET`], in either case, to its value, with 0xFF for the other bytes.
+pub(crate) const DECODE_TABLE: [u8; 256] = {
+    let mut table = [0xFF; 256];
+    let mut i = 0;
+
+    while i < 32 {
+        table[CHARSET[i] as usize] = i as u8;
+        table[CHARSET[i].to_ascii_uppercase() as usize] = i as u8;
+        i += 1;
+    }
+
+    table
+};
+
+/// The longest human-readable part.
+pub(crate) const MAX_HRP_LENGTH: usize = 83;
+
+/// The longest string whose checksum is guaranteed to detect errors, which is also the length limit of BIP 173.
+pub(crate) const MAX_LENGTH: usize = 90;
+
+/// The number of characters of the checksum.
+pub(crate) const CHECKSUM_LENGTH: usize = 6;
+
+const GENERATOR: [u32; 5] = [0x3B6A_57B2, 0x2650_8E6D, 0x1EA1_19FA, 0x3D42_33DD, 0x2A14_62B3];
+
+/// Feeds the 5-bit `value` into the checksum `chk`.
+#[inline]
+pub(crate) const fn polymod_step(chk: u32, value: u8) -> u32 {
+    let top = chk >> 25;
+    let mut chk = (chk & 0x01FF_FFFF) << 5 ^ value as u32;
+    let mut i = 0;
+
+    while i < 5 {
+        if top >> i & 1 == 1 {
+            chk ^= GENERATOR[i];
+        }
+
+        i += 1;
+    }
+
+    chk
+}
+
+/// Returns the checksum state after the expanded human-readable part, which must be ASCII.
+pub(crate) fn hrp_checksum(hrp: &[u8]) -> u32 {
+    let mut chk = 1;
+
+    for &b in hrp {
+        chk = polymod_step(chk, b >> 5);
+    }
+
+    chk = polymod_step(chk, 0);
+
+    for &b in hrp {
+        chk = polymod_step(chk, b & 31);
+    }
+
+    chk
+}
+
+/// Returns whether `b` is allowed in a human-readable part.
+#[inline]
+pub(crate) const fn is_hrp_char(b: u8) -> bool {
+    matches!(b, 33..=126)
+}
+
+/// Finds up to two positions in a data part of `length` values, checksum included, where substituting values explains the checksum residue `residue`, i.e. the final checksum state XOR the constant of the variant.
+///
+/// The checksum is linear, so the residue of a string is the sum of the residues of its errors, and the residue of a single error depends only on its value and its distance from the end. The result is empty if no one or two errors explain the residue.
+pub(crate) fn locate_errors(residue: u32, length: usize) -> Vec<usize> {
+    if residue == 0 || length == 0 {
+        return Vec::new();
+    }
+
+    // residues[i * 31 + v - 1] is the residue of adding v to the value at i
+    let mut residues = vec![0u32; length * 31];
+
+    for v in 1..32u8 {
+        let mut chk = u32::from(v);
+
+        for i in (0..length).rev() {
+            residues[i * 31 + usize::from(v) - 1] = chk;
+
+            chk = polymod_step(chk, 0);
+        }
+    }
+
+    if let Some(index) = residues.iter().position(|&r| r == residue) {
+        return vec![index / 31];
+    }
+
+    let singles =
+        residues.iter().enumerate().map(|(index, &r)| (r, index / 31)).collect::<HashMap<_, _>>();
+
+    for (index, &r) in residues.iter().enumerate() {
+        let i = index / 31;
+
+        if let Some(&j) = singles.get(&(residue ^ r)) {
+            if j != i {
+                return vec![i.min(j), i.max(j)];
+            }
+        }
+    }
+
+    Vec::new()
+}
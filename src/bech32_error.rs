@@ -0,0 +1,50 @@
+use std::{error::Error, fmt};
+
+/// The error returned by [`FromBech32Reader`](crate::FromBech32Reader) when decoding an invalid Bech32 string. It is wrapped in an [`io::Error`](std::io::Error) of the kind [`InvalidData`](std::io::ErrorKind::InvalidData).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Bech32Error {
+    /// There is no `1` between the human-readable part and the data part.
+    MissingSeparator,
+    /// The human-readable part is empty or longer than 83 characters.
+    InvalidHrpLength(usize),
+    /// The byte at the given offset is not allowed there.
+    InvalidSymbol(u64, u8),
+    /// The string mixes uppercase and lowercase letters.
+    MixedCase,
+    /// The data part is shorter than the 6 characters of the checksum.
+    MissingChecksum,
+    /// The checksum does not match. The offsets are those of the characters which are likely to be wrong, or empty if there are too many errors to locate or the string is longer than 90 characters.
+    ChecksumMismatch(Vec<u64>),
+    /// The bits left over after the last byte are more than 4 or not all zeros.
+    InvalidPadding,
+}
+
+impl fmt::Display for Bech32Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bech32Error::MissingSeparator => {
+                f.write_str("no separator is found in the Bech32 string")
+            },
+            Bech32Error::InvalidHrpLength(length) => {
+                write!(f, "the human-readable part is {length} characters long")
+            },
+            Bech32Error::InvalidSymbol(offset, byte) => {
+                write!(f, "invalid Bech32 symbol {byte:?} at offset {offset}")
+            },
+            Bech32Error::MixedCase => {
+                f.write_str("the Bech32 string mixes uppercase and lowercase")
+            },
+            Bech32Error::MissingChecksum => f.write_str("the Bech32 string has no checksum"),
+            Bech32Error::ChecksumMismatch(offsets) if offsets.is_empty() => {
+                f.write_str("the Bech32 checksum does not match")
+            },
+            Bech32Error::ChecksumMismatch(offsets) => {
+                write!(f, "the Bech32 checksum does not match, likely at offsets {offsets:?}")
+            },
+            Bech32Error::InvalidPadding => f.write_str("invalid padding in the Bech32 data"),
+        }
+    }
+}
+
+impl Error for Bech32Error {}
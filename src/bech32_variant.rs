@@ -0,0 +1,20 @@
+/// The checksum flavor of Bech32 strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Bech32Variant {
+    /// Bech32 (BIP 173), used by segwit version 0 addresses.
+    #[default]
+    Bech32,
+    /// Bech32m (BIP 350), which fixes the insertion weakness of Bech32 and is used by segwit version 1 and later.
+    Bech32m,
+}
+
+impl Bech32Variant {
+    /// Returns the value the checksum of a valid string leaves behind.
+    #[inline]
+    pub(crate) const fn constant(self) -> u32 {
+        match self {
+            Bech32Variant::Bech32 => 1,
+            Bech32Variant::Bech32m => 0x2BC8_30A3,
+        }
+    }
+}
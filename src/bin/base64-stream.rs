@@ -0,0 +1,289 @@
+//! Encode or decode base64 like the `base64` command of coreutils.
+
+use std::{
+    ffi::OsString,
+    fs::File,
+    io::{self, BufWriter, ErrorKind, Read, Write},
+    process::ExitCode,
+};
+
+use base64_stream::{
+    Base64Variant, FromBase64Reader, LineEnding, ToBase64Writer,
+    base64::{DecodeError, DecodeSliceError},
+};
+
+const NAME: &str = "base64-stream";
+const DEFAULT_LINE_LENGTH: usize = 76;
+
+const USAGE: &str = "Usage: base64-stream [OPTION]... [FILE]
+Base64 encode or decode FILE, or standard input, to standard output.
+
+With no FILE, or when FILE is -, read standard input.
+
+  -d, --decode          decode data
+  -i, --ignore-garbage  when decoding, ignore non-alphabet characters
+  -w, --wrap=COLS       wrap encoded lines after COLS characters (default 76).
+                          Use 0 to disable line wrapping
+      --url             use the URL and filename safe alphabet
+      --no-pad          omit the padding when encoding, and reject it when decoding
+  -h, --help            display this help and exit
+      --version         output version information and exit
+";
+
+#[derive(Debug)]
+struct Options {
+    decode:         bool,
+    ignore_garbage: bool,
+    line_length:    usize,
+    url_safe:       bool,
+    padded:         bool,
+    file:           Option<OsString>,
+}
+
+impl Options {
+    #[inline]
+    fn variant(&self) -> Base64Variant {
+        match (self.url_safe, self.padded) {
+            (false, true) => Base64Variant::Standard,
+            (false, false) => Base64Variant::StandardNoPad,
+            (true, true) => Base64Variant::UrlSafe,
+            (true, false) => Base64Variant::UrlSafeNoPad,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Command {
+    Run(Options),
+    Help,
+    Version,
+}
+
+fn parse_line_length(value: &str) -> Result<usize, String> {
+    value.parse().map_err(|_| format!("invalid wrap size: '{value}'"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = OsString>) -> Result<Command, String> {
+    let mut options = Options {
+        decode:         false,
+        ignore_garbage: false,
+        line_length:    DEFAULT_LINE_LENGTH,
+        url_safe:       false,
+        padded:         true,
+        file:           None,
+    };
+
+    let mut operands = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let Some(s) = arg.to_str() else {
+            operands.push(arg);
+
+            continue;
+        };
+
+        if s == "--" {
+            operands.extend(args.by_ref());
+
+            break;
+        }
+
+        if let Some(long) = s.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+
+            match (name, value) {
+                ("decode", None) => options.decode = true,
+                ("ignore-garbage", None) => options.ignore_garbage = true,
+                ("url", None) => options.url_safe = true,
+                ("no-pad", None) => options.padded = false,
+                ("help", None) => return Ok(Command::Help),
+                ("version", None) => return Ok(Command::Version),
+                ("wrap", Some(value)) => options.line_length = parse_line_length(value)?,
+                ("wrap", None) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| String::from("option '--wrap' requires an argument"))?;
+
+                    options.line_length = parse_line_length(&value.to_string_lossy())?;
+                },
+                _ => return Err(format!("unrecognized option '{s}'")),
+            }
+        } else if s.len() > 1 && s.starts_with('-') {
+            for (i, c) in s.char_indices().skip(1) {
+                match c {
+                    'd' => options.decode = true,
+                    'i' => options.ignore_garbage = true,
+                    'h' => return Ok(Command::Help),
+                    'w' => {
+                        let rest = &s[(i + 1)..];
+
+                        options.line_length = if rest.is_empty() {
+                            let value = args.next().ok_or_else(|| {
+                                String::from("option requires an argument -- 'w'")
+                            })?;
+
+                            parse_line_length(&value.to_string_lossy())?
+                        } else {
+                            parse_line_length(rest)?
+                        };
+
+                        break;
+                    },
+                    _ => return Err(format!("invalid option -- '{c}'")),
+                }
+            }
+        } else {
+            operands.push(arg);
+        }
+    }
+
+    if operands.len() > 1 {
+        return Err(format!("extra operand '{}'", operands[1].to_string_lossy()));
+    }
+
+    options.file = operands.pop().filter(|file| file != "-");
+
+    Ok(Command::Run(options))
+}
+
+/// Drops the line feeds, or every byte outside the alphabet if `ignore_garbage` is set.
+struct FilterReader<R: Read> {
+    inner:          R,
+    ignore_garbage: bool,
+    url_safe:       bool,
+}
+
+impl<R: Read> FilterReader<R> {
+    #[inline]
+    fn keeps(&self, b: u8) -> bool {
+        if !self.ignore_garbage {
+            return b != b'\n';
+        }
+
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'=' => true,
+            b'+' | b'/' => !self.url_safe,
+            b'-' | b'_' => self.url_safe,
+            _ => false,
+        }
+    }
+}
+
+impl<R: Read> Read for FilterReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        loop {
+            let c = self.inner.read(buf)?;
+
+            if c == 0 {
+                return Ok(0);
+            }
+
+            let mut length = 0;
+
+            for i in 0..c {
+                let b = buf[i];
+
+                if self.keeps(b) {
+                    buf[length] = b;
+                    length += 1;
+                }
+            }
+
+            if length > 0 {
+                return Ok(length);
+            }
+        }
+    }
+}
+
+fn encode<R: Read, W: Write>(mut input: R, output: W, options: &Options) -> Result<(), io::Error> {
+    let mut writer = ToBase64Writer::new(output).with_variant(options.variant());
+
+    if options.line_length > 0 {
+        writer = writer.with_line_wrap(options.line_length, LineEnding::LF);
+    }
+
+    io::copy(&mut input, &mut writer)?;
+
+    let wrapped = options.line_length > 0 && writer.progress().plain > 0;
+
+    let mut output = writer.finish()?;
+
+    // unlike the line endings between the lines, the last one is only written by the tool
+    if wrapped {
+        output.write_all(LineEnding::LF.as_bytes())?;
+    }
+
+    output.flush()
+}
+
+fn decode<R: Read, W: Write>(input: R, mut output: W, options: &Options) -> Result<(), io::Error> {
+    let mut reader = FromBase64Reader::new(FilterReader {
+        inner:          input,
+        ignore_garbage: options.ignore_garbage,
+        url_safe:       options.url_safe,
+    })
+    .with_variant(options.variant());
+
+    io::copy(&mut reader, &mut output)?;
+
+    output.flush()
+}
+
+fn run(options: &Options) -> Result<(), io::Error> {
+    let input: Box<dyn Read> = match options.file.as_ref() {
+        Some(file) => Box::new(File::open(file).map_err(|error| {
+            io::Error::new(error.kind(), format!("{}: {error}", file.to_string_lossy()))
+        })?),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let output = BufWriter::new(io::stdout().lock());
+
+    if options.decode { decode(input, output, options) } else { encode(input, output, options) }
+}
+
+fn is_decode_error(error: &io::Error) -> bool {
+    error.kind() == ErrorKind::InvalidData
+        && error.get_ref().is_some_and(|e| e.is::<DecodeSliceError>() || e.is::<DecodeError>())
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args_os().skip(1)) {
+        Ok(Command::Run(options)) => options,
+        Ok(Command::Help) => {
+            print!("{USAGE}");
+
+            return ExitCode::SUCCESS;
+        },
+        Ok(Command::Version) => {
+            println!("{NAME} {}", env!("CARGO_PKG_VERSION"));
+
+            return ExitCode::SUCCESS;
+        },
+        Err(message) => {
+            eprintln!("{NAME}: {message}");
+            eprintln!("Try '{NAME} --help' for more information.");
+
+            return ExitCode::FAILURE;
+        },
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) if error.kind() == ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) if is_decode_error(&error) => {
+            eprintln!("{NAME}: invalid input");
+
+            ExitCode::FAILURE
+        },
+        Err(error) => {
+            eprintln!("{NAME}: {error}");
+
+            ExitCode::FAILURE
+        },
+    }
+}
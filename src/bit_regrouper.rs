@@ -0,0 +1,65 @@
+/// Regroups a stream of `from_bits`-bit values into `to_bits`-bit values, carrying the leftover bits over to the next call.
+#[derive(Debug, Clone)]
+pub(crate) struct BitRegrouper {
+    from_bits: u32,
+    to_bits:   u32,
+    /// The leftover bits, in the low `bits` bits.
+    acc:       u32,
+    bits:      u32,
+}
+
+impl BitRegrouper {
+    #[inline]
+    pub(crate) const fn new(from_bits: u32, to_bits: u32) -> BitRegrouper {
+        debug_assert!(from_bits >= 1 && from_bits <= 8 && to_bits >= 1 && to_bits <= 8);
+
+        BitRegrouper {
+            from_bits,
+            to_bits,
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    /// Appends the regrouped values of `input` to `output`. Every value in `input` must fit in `from_bits` bits.
+    pub(crate) fn push(&mut self, input: &[u8], output: &mut Vec<u8>) {
+        let mask = (1 << self.to_bits) - 1;
+
+        output.reserve((input.len() * self.from_bits as usize).div_ceil(self.to_bits as usize));
+
+        for &value in input {
+            debug_assert!(u32::from(value) >> self.from_bits == 0);
+
+            self.acc = self.acc << self.from_bits | u32::from(value);
+            self.bits += self.from_bits;
+
+            while self.bits >= self.to_bits {
+                self.bits -= self.to_bits;
+
+                output.push((self.acc >> self.bits & mask) as u8);
+            }
+
+            self.acc &= (1 << self.bits) - 1;
+        }
+    }
+
+    /// Pads the leftover bits, if any, with zeros to a last value and appends it to `output`.
+    pub(crate) fn finish_padded(&mut self, output: &mut Vec<u8>) {
+        if self.bits > 0 {
+            output.push((self.acc << (self.to_bits - self.bits)) as u8);
+
+            self.acc = 0;
+            self.bits = 0;
+        }
+    }
+
+    /// Drops the leftover bits, which are padding. Returns `false` if they are too many to be padding or not all zeros.
+    pub(crate) fn finish_exact(&mut self) -> bool {
+        let valid = self.bits < self.from_bits && self.acc == 0;
+
+        self.acc = 0;
+        self.bits = 0;
+
+        valid
+    }
+}
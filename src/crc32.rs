@@ -0,0 +1,45 @@
+/// The lookup table of the reflected CRC-32 polynomial used by zlib, PNG and yEnc.
+const TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            value = if value & 1 == 1 { value >> 1 ^ 0xEDB8_8320 } else { value >> 1 };
+            bit += 1;
+        }
+
+        table[i] = value;
+        i += 1;
+    }
+
+    table
+};
+
+/// Computes a CRC-32 (IEEE) checksum incrementally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Crc32(u32);
+
+impl Default for Crc32 {
+    #[inline]
+    fn default() -> Self {
+        Crc32(0xFFFF_FFFF)
+    }
+}
+
+impl Crc32 {
+    #[inline]
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        for &b in data {
+            self.0 = TABLE[((self.0 ^ u32::from(b)) & 0xFF) as usize] ^ self.0 >> 8;
+        }
+    }
+
+    #[inline]
+    pub(crate) fn value(self) -> u32 {
+        !self.0
+    }
+}
@@ -0,0 +1,41 @@
+use std::{error::Error, fmt};
+
+use base64::DecodeError;
+
+/// The error returned when decoding RFC 2047 encoded-words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodedWordError {
+    /// The encoded text of a word is not valid base64.
+    Base64(DecodeError),
+    /// The charset of a word is not supported. It is returned as it is labeled.
+    UnsupportedCharset(String),
+    /// The decoded bytes are not valid in the charset of their words.
+    InvalidText(String),
+}
+
+impl fmt::Display for EncodedWordError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodedWordError::Base64(error) => {
+                write!(f, "invalid base64 in an encoded-word: {error}")
+            },
+            EncodedWordError::UnsupportedCharset(charset) => {
+                write!(f, "the charset {charset:?} is not supported")
+            },
+            EncodedWordError::InvalidText(charset) => {
+                write!(f, "the decoded text is not valid {charset}")
+            },
+        }
+    }
+}
+
+impl Error for EncodedWordError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EncodedWordError::Base64(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::iter::FusedIterator;
+
+use base64::Engine;
+
+use crate::{Base64Variant, EncodedWordError};
+
+/// A piece of a header value split by [`EncodedWords`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeaderSegment<'a> {
+    /// Text outside encoded-words, as it is.
+    Text(&'a str),
+    /// The decoded bytes of a "B" encoded-word.
+    Word {
+        /// The charset label, such as `UTF-8`.
+        charset:  &'a str,
+        /// The RFC 2231 language tag following the charset, if any.
+        language: Option<&'a str>,
+        /// The decoded bytes in `charset`.
+        data:     Vec<u8>,
+    },
+}
+
+/// A "B" encoded-word found at the start of a string.
+struct RawWord<'a> {
+    charset:  &'a str,
+    language: Option<&'a str>,
+    text:     &'a str,
+    length:   usize,
+}
+
+/// Parses a "B" encoded-word at the start of `s`. Other encodings are not recognized.
+fn parse_word(s: &str) -> Option<RawWord<'_>> {
+    let rest = s.strip_prefix("=?")?;
+
+    let (label, rest) = rest.split_once('?')?;
+
+    if label.is_empty() || label.bytes().any(|b| b.is_ascii_whitespace() || b.is_ascii_control()) {
+        return None;
+    }
+
+    let rest = rest.strip_prefix("B?").or_else(|| rest.strip_prefix("b?"))?;
+
+    let text_length = rest.find('?')?;
+    let text = &rest[..text_length];
+
+    if !rest[text_length..].starts_with("?=") || text.bytes().any(|b| b.is_ascii_whitespace()) {
+        return None;
+    }
+
+    let (charset, language) = match label.split_once('*') {
+        Some((charset, language)) => (charset, Some(language)),
+        None => (label, None),
+    };
+
+    Some(RawWord {
+        charset,
+        language,
+        text,
+        length: s.len() - rest.len() + text_length + 2,
+    })
+}
+
+#[inline]
+fn is_linear_whitespace(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\r' | b'\n')
+}
+
+/// Splits a header value into text and decoded RFC 2047 "B" encoded-words.
+///
+/// The whitespace between adjacent encoded-words is dropped. Malformed encoded-words and encoded-words in other encodings are returned as text. The encoded text must be standard base64 with padding.
+#[derive(Debug, Clone)]
+pub struct EncodedWords<'a> {
+    rest:       &'a str,
+    after_word: bool,
+    failed:     bool,
+}
+
+impl<'a> EncodedWords<'a> {
+    #[inline]
+    pub fn new(header: &'a str) -> EncodedWords<'a> {
+        EncodedWords {
+            rest: header, after_word: false, failed: false
+        }
+    }
+}
+
+impl<'a> Iterator for EncodedWords<'a> {
+    type Item = Result<HeaderSegment<'a>, EncodedWordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.rest.is_empty() {
+            return None;
+        }
+
+        if self.after_word {
+            let whitespace_length =
+                self.rest.bytes().take_while(|&b| is_linear_whitespace(b)).count();
+
+            if whitespace_length > 0 && parse_word(&self.rest[whitespace_length..]).is_some() {
+                self.rest = &self.rest[whitespace_length..];
+            }
+        }
+
+        if let Some(word) = parse_word(self.rest) {
+            self.rest = &self.rest[word.length..];
+            self.after_word = true;
+
+            // RFC 2047 only allows the standard alphabet with padding
+            let data = match Base64Variant::Standard.engine().decode(word.text) {
+                Ok(data) => data,
+                Err(error) => {
+                    self.failed = true;
+
+                    return Some(Err(EncodedWordError::Base64(error)));
+                },
+            };
+
+            return Some(Ok(HeaderSegment::Word {
+                charset: word.charset,
+                language: word.language,
+                data,
+            }));
+        }
+
+        let text_length = self
+            .rest
+            .match_indices("=?")
+            .map(|(i, _)| i)
+            .find(|&i| i > 0 && parse_word(&self.rest[i..]).is_some())
+            .unwrap_or(self.rest.len());
+
+        let text = &self.rest[..text_length];
+
+        self.rest = &self.rest[text_length..];
+        self.after_word = false;
+
+        Some(Ok(HeaderSegment::Text(text)))
+    }
+}
+
+impl FusedIterator for EncodedWords<'_> {}
+
+/// Converts `data` in `charset` to a string. UTF-8, US-ASCII and ISO-8859-1 are supported.
+fn charset_to_string(charset: &str, data: Vec<u8>) -> Result<String, EncodedWordError> {
+    let invalid = || EncodedWordError::InvalidText(charset.to_string());
+
+    match charset.to_ascii_lowercase().as_str() {
+        "utf-8" | "utf8" => String::from_utf8(data).map_err(|_| invalid()),
+        "us-ascii" | "ascii" => {
+            if data.is_ascii() {
+                Ok(data.into_iter().map(char::from).collect())
+            } else {
+                Err(invalid())
+            }
+        },
+        "iso-8859-1" | "iso_8859-1" | "latin1" | "l1" => {
+            Ok(data.into_iter().map(char::from).collect())
+        },
+        _ => Err(EncodedWordError::UnsupportedCharset(charset.to_string())),
+    }
+}
+
+/// Decodes the RFC 2047 "B" encoded-words in a header value.
+///
+/// Adjacent encoded-words in the same charset are joined before they are converted, so a character split across them is still decoded. UTF-8, US-ASCII and ISO-8859-1 are supported. Use [`EncodedWords`] for other charsets.
+pub fn decode_encoded_words(header: &str) -> Result<String, EncodedWordError> {
+    let mut decoded = String::with_capacity(header.len());
+    let mut run: Option<(&str, Vec<u8>)> = None;
+
+    for segment in EncodedWords::new(header) {
+        match segment? {
+            HeaderSegment::Text(text) => {
+                if let Some((charset, data)) = run.take() {
+                    decoded.push_str(&charset_to_string(charset, data)?);
+                }
+
+                decoded.push_str(text);
+            },
+            HeaderSegment::Word {
+                charset,
+                data,
+                ..
+            } => match run.as_mut() {
+                Some((run_charset, run_data)) if run_charset.eq_ignore_ascii_case(charset) => {
+                    run_data.extend_from_slice(&data);
+                },
+                _ => {
+                    if let Some((charset, data)) = run.replace((charset, data)) {
+                        decoded.push_str(&charset_to_string(charset, data)?);
+                    }
+                },
+            },
+        }
+    }
+
+    if let Some((charset, data)) = run {
+        decoded.push_str(&charset_to_string(charset, data)?);
+    }
+
+    Ok(decoded)
+}
@@ -0,0 +1,120 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::base45::Base45Decoder;
+
+/// Read Base45 data (RFC 9285) and decode them to plain data.
+///
+/// A triplet of characters becomes 2 plain bytes, and a final pair becomes 1 byte. An incomplete triplet is carried over to the next read from the inner reader. Decoding fails with a [`Base45Error`](crate::Base45Error) carrying the offset of an invalid character or of a triplet whose value exceeds 65535.
+pub struct FromBase45Reader<R: Read, const N: usize = 4096> {
+    inner:       R,
+    buf:         [u8; N],
+    decoder:     Base45Decoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    eof:         bool,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for FromBase45Reader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromBase45Reader")
+            .field("decoder", &self.decoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> FromBase45Reader<R> {
+    #[inline]
+    pub fn new(reader: R) -> FromBase45Reader<R> {
+        Self::new2(reader)
+    }
+}
+
+impl<R: Read, const N: usize> FromBase45Reader<R, N> {
+    #[inline]
+    pub fn new2(reader: R) -> FromBase45Reader<R, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        FromBase45Reader {
+            inner:       reader,
+            buf:         [0u8; N],
+            decoder:     Base45Decoder::default(),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+            eof:         false,
+        }
+    }
+}
+
+impl<R: Read, const N: usize> FromBase45Reader<R, N> {
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for FromBase45Reader<R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let temp = &self.temp[self.temp_offset..];
+
+            if !temp.is_empty() {
+                let c = temp.len().min(buf.len());
+
+                buf[..c].copy_from_slice(&temp[..c]);
+
+                self.temp_offset += c;
+
+                return Ok(c);
+            }
+
+            if self.eof {
+                return Ok(0);
+            }
+
+            self.temp.clear();
+            self.temp_offset = 0;
+
+            let c = self.inner.read(&mut self.buf)?;
+
+            let result = if c == 0 {
+                self.eof = true;
+
+                self.decoder.finish(&mut self.temp)
+            } else {
+                self.decoder.decode(&self.buf[..c], &mut self.temp)
+            };
+
+            result.map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+        }
+    }
+}
+
+impl<R: Read> From<R> for FromBase45Reader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        FromBase45Reader::new(reader)
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+#[cfg(feature = "base58check")]
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "base58check")]
+use crate::base58::{CHECKSUM_LENGTH, checksum};
+use crate::{Base58Alphabet, Base58Error, LimitExceeded, base58::Base58Decoder};
+
+/// Read Base58 data and decode them to plain data, optionally checking a Base58Check checksum.
+///
+/// Base58 treats the whole data as one big number, so no plain byte is known before the last Base58 byte, and the inner reader is read to EOF by the first call to [`read`](Read::read). The Base58 data are not buffered, but the number built from them is, which takes about 0.73 bytes per Base58 byte. Every Base58 byte multiplies that number, so decoding `n` bytes takes time proportional to `n²`. Use [`with_limit`](Self::with_limit) to bound both when the data come from elsewhere.
+///
+/// With [`with_check`](Self::with_check), which needs the `base58check` feature, the checksum is verified at EOF of the inner reader, before any plain byte is returned, so that unverified data are never seen.
+pub struct FromBase58Reader<R: Read> {
+    inner:       R,
+    alphabet:    Base58Alphabet,
+    #[cfg(feature = "base58check")]
+    check:       bool,
+    limit:       u64,
+    encoded:     u64,
+    decoded:     bool,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+}
+
+impl<R: Read> fmt::Debug for FromBase58Reader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("FromBase58Reader");
+
+        debug.field("alphabet", &self.alphabet);
+
+        #[cfg(feature = "base58check")]
+        debug.field("check", &self.check);
+
+        debug
+            .field("encoded", &self.encoded)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> FromBase58Reader<R> {
+    #[inline]
+    pub fn new(reader: R) -> FromBase58Reader<R> {
+        FromBase58Reader {
+            inner:                                 reader,
+            alphabet:                              Base58Alphabet::default(),
+            #[cfg(feature = "base58check")]
+            check:                                 false,
+            limit:                                 u64::MAX,
+            encoded:                               0,
+            decoded:                               false,
+            temp:                                  Vec::new(),
+            temp_offset:                           0,
+        }
+    }
+
+    /// Decodes with `alphabet` instead of the Bitcoin one.
+    #[inline]
+    pub fn with_alphabet(mut self, alphabet: Base58Alphabet) -> Self {
+        self.alphabet = alphabet;
+
+        self
+    }
+
+    /// Verifies and strips the Base58Check checksum, i.e. the last 4 decoded bytes, which must be the first 4 bytes of the double SHA-256 of the others.
+    #[cfg(feature = "base58check")]
+    #[inline]
+    pub fn with_check(mut self) -> Self {
+        self.check = true;
+
+        self
+    }
+
+    /// Fails with [`LimitExceeded::Encoded`] once more than `limit` Base58 bytes have been read from the inner reader.
+    #[inline]
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+
+        self
+    }
+}
+
+impl<R: Read> FromBase58Reader<R> {
+    /// Reads the inner reader to EOF and decodes everything into `self.temp`.
+    fn decode(&mut self) -> Result<(), io::Error> {
+        let mut decoder = Base58Decoder::default();
+        let mut buf = [0u8; 4096];
+
+        loop {
+            let c = match self.inner.read(&mut buf) {
+                Ok(0) => break,
+                Ok(c) => c,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+
+            if self.encoded + c as u64 > self.limit {
+                return Err(io::Error::other(LimitExceeded::Encoded(self.limit)));
+            }
+
+            for (i, b) in buf[..c].iter_mut().enumerate() {
+                *b = self.alphabet.decode_symbol(*b).ok_or_else(|| {
+                    io::Error::new(
+                        ErrorKind::InvalidData,
+                        Base58Error::InvalidSymbol(self.encoded + i as u64, *b),
+                    )
+                })?;
+            }
+
+            decoder.push(&buf[..c]);
+
+            self.encoded += c as u64;
+        }
+
+        let plain = decoder.into_bytes();
+
+        #[cfg(feature = "base58check")]
+        let plain = if self.check { strip_checksum(plain)? } else { plain };
+
+        self.temp = plain;
+        self.decoded = true;
+
+        Ok(())
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Verifies the last 4 bytes of `plain` against the checksum of the others and removes them.
+#[cfg(feature = "base58check")]
+fn strip_checksum(mut plain: Vec<u8>) -> Result<Vec<u8>, io::Error> {
+    let Some(length) = plain.len().checked_sub(CHECKSUM_LENGTH) else {
+        return Err(io::Error::new(ErrorKind::InvalidData, Base58Error::MissingChecksum));
+    };
+
+    if checksum(Sha256::new_with_prefix(&plain[..length])) != plain[length..] {
+        return Err(io::Error::new(ErrorKind::InvalidData, Base58Error::ChecksumMismatch));
+    }
+
+    plain.truncate(length);
+
+    Ok(plain)
+}
+
+impl<R: Read> Read for FromBase58Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if !self.decoded {
+            self.decode()?;
+        }
+
+        let temp = &self.temp[self.temp_offset..];
+        let c = temp.len().min(buf.len());
+
+        buf[..c].copy_from_slice(&temp[..c]);
+
+        self.temp_offset += c;
+
+        Ok(c)
+    }
+}
+
+impl<R: Read> From<R> for FromBase58Reader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        FromBase58Reader::new(reader)
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    fmt,
+    io::{self, IoSliceMut, Read},
+};
+
+use crate::{
+    Base64Variant, FromBase64Reader, PushbackReader, json_string_reader::JsonStringReader,
+};
+
+/// Read a JSON string of base64 data and decode its contents to plain data.
+///
+/// The inner reader must be positioned at the string, optionally preceded by whitespace. JSON escapes such as `\/` are resolved before decoding, and escaped line breaks are skipped. The reader reaches EOF at the closing quote.
+pub struct FromBase64JsonReader<R: Read, const N: usize = 4096> {
+    inner: FromBase64Reader<JsonStringReader<R>, N>,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for FromBase64JsonReader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromBase64JsonReader").field("inner", &self.inner).finish()
+    }
+}
+
+impl<R: Read> FromBase64JsonReader<R> {
+    #[inline]
+    pub fn new(reader: R) -> FromBase64JsonReader<R> {
+        Self::new2(reader)
+    }
+}
+
+impl<R: Read, const N: usize> FromBase64JsonReader<R, N> {
+    #[inline]
+    pub fn new2(reader: R) -> FromBase64JsonReader<R, N> {
+        FromBase64JsonReader {
+            inner: FromBase64Reader::new2(JsonStringReader::new(reader))
+        }
+    }
+
+    /// Decodes `variant` instead of standard base64. This replaces [`with_auto_detect`](Self::with_auto_detect).
+    #[inline]
+    pub fn with_variant(mut self, variant: Base64Variant) -> Self {
+        self.inner = self.inner.with_variant(variant);
+
+        self
+    }
+
+    /// Detects the alphabet and the padding mode instead of requiring standard base64. See [`FromBase64Reader::with_auto_detect`].
+    #[inline]
+    pub fn with_auto_detect(mut self) -> Self {
+        self.inner = self.inner.with_auto_detect();
+
+        self
+    }
+
+    /// Fails with [`LimitExceeded::Decoded`](crate::LimitExceeded::Decoded) instead of returning more than `limit` decoded bytes.
+    #[inline]
+    pub fn with_decoded_limit(mut self, limit: u64) -> Self {
+        self.inner = self.inner.with_decoded_limit(limit);
+
+        self
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref().get_ref()
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut().get_mut()
+    }
+
+    /// Returns the inner reader positioned right after the closing quote, consuming this wrapper.
+    ///
+    /// Bytes which have been read ahead are pushed back in front of it. This is meant to be called once this reader has reached EOF.
+    #[inline]
+    pub fn into_rest(self) -> PushbackReader<R> {
+        self.inner.into_inner().into_rest()
+    }
+
+    /// Returns the inner reader, consuming this wrapper.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner().into_inner()
+    }
+}
+
+impl<R: Read, const N: usize> Read for FromBase64JsonReader<R, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        self.inner.read(buf)
+    }
+
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize, io::Error> {
+        self.inner.read_vectored(bufs)
+    }
+}
+
+impl<R: Read> From<R> for FromBase64JsonReader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        FromBase64JsonReader::new(reader)
+    }
+}
@@ -1,27 +1,76 @@
 use std::{
     fmt,
-    io::{self, ErrorKind, Read},
+    io::{self, ErrorKind, IoSliceMut, Read},
 };
 
 use base64::{
-    DecodeSliceError, Engine,
-    engine::{GeneralPurpose, general_purpose::STANDARD},
+    DecodeSliceError, alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig, general_purpose::STANDARD},
 };
 
+use crate::{
+    Base64Alphabet, Base64Variant, LimitExceeded, PendingBytes, Progress, PushbackReader,
+    Terminator, base64_alphabet::Base64Codec, plain_digest::PlainDigest, progress::ProgressCounter,
+    terminator::Boundary,
+};
+
+/// Reads from `inner`, or only from the region before the terminator if there is a `boundary`.
+#[inline]
+fn read_source<R: Read>(
+    inner: &mut R,
+    boundary: &mut Option<Boundary>,
+    buf: &mut [u8],
+) -> Result<usize, io::Error> {
+    match boundary {
+        Some(boundary) => boundary.read(inner, buf),
+        None => inner.read(buf),
+    }
+}
+
 #[inline]
 fn decode_error_to_io_error(error: DecodeSliceError) -> io::Error {
     io::Error::new(ErrorKind::InvalidData, error)
 }
 
+/// Base64 bytes decoded per step of [`decode_in_place`]. Must be a multiple of 4.
+const IN_PLACE_CHUNK_SIZE: usize = 1024;
+
+/// Accepts data with or without padding while the variant is being detected.
+const INDIFFERENT: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+
+const STANDARD_INDIFFERENT: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, INDIFFERENT);
+
+const URL_SAFE_INDIFFERENT: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, INDIFFERENT);
+
+/// Returns whether the first symbol specific to one alphabet in `data` is URL-safe, or `None` if there is no such symbol.
+#[inline]
+fn find_url_safe(data: &[u8]) -> Option<bool> {
+    data.iter().find_map(|b| match b {
+        b'+' | b'/' => Some(false),
+        b'-' | b'_' => Some(true),
+        _ => None,
+    })
+}
+
 /// Read base64 data and decode them to plain data.
 pub struct FromBase64Reader<R: Read, const N: usize = 4096> {
-    inner:       R,
-    buf:         [u8; N],
-    buf_length:  usize,
-    buf_offset:  usize,
-    temp:        [u8; 2],
-    temp_length: usize,
-    engine:      &'static GeneralPurpose,
+    inner:         R,
+    buf:           [u8; N],
+    buf_length:    usize,
+    buf_offset:    usize,
+    temp:          [u8; 2],
+    temp_length:   usize,
+    engine:        Base64Codec,
+    digest:        PlainDigest,
+    progress:      ProgressCounter,
+    auto_detect:   bool,
+    url_safe:      Option<bool>,
+    padded:        bool,
+    decoded_limit: u64,
+    encoded_limit: u64,
+    exceeded:      Option<LimitExceeded>,
+    boundary:      Option<Boundary>,
 }
 
 impl<R: Read, const N: usize> fmt::Debug for FromBase64Reader<R, N> {
@@ -31,6 +80,7 @@ impl<R: Read, const N: usize> fmt::Debug for FromBase64Reader<R, N> {
             .field("buf_offset", &self.buf_offset)
             .field("temp", &&self.temp[..self.temp_length])
             .field("temp_length", &self.temp_length)
+            .field("progress", &self.progress.progress())
             .finish_non_exhaustive()
     }
 }
@@ -47,18 +97,191 @@ impl<R: Read, const N: usize> FromBase64Reader<R, N> {
     pub fn new2(reader: R) -> FromBase64Reader<R, N> {
         const { assert!(N >= 4, "buffer size N must be at least 4") };
         FromBase64Reader {
-            inner:       reader,
-            buf:         [0u8; N],
-            buf_length:  0,
-            buf_offset:  0,
-            temp:        [0; 2],
-            temp_length: 0,
-            engine:      &STANDARD,
+            inner:         reader,
+            buf:           [0u8; N],
+            buf_length:    0,
+            buf_offset:    0,
+            temp:          [0; 2],
+            temp_length:   0,
+            engine:        Base64Codec::new(&STANDARD),
+            digest:        PlainDigest::default(),
+            progress:      ProgressCounter::default(),
+            auto_detect:   false,
+            url_safe:      None,
+            padded:        true,
+            decoded_limit: u64::MAX,
+            encoded_limit: u64::MAX,
+            exceeded:      None,
+            boundary:      None,
         }
     }
+
+    /// Decodes `variant` instead of standard base64. This replaces [`with_auto_detect`](Self::with_auto_detect).
+    #[inline]
+    pub fn with_variant(mut self, variant: Base64Variant) -> Self {
+        self.engine = Base64Codec::new(variant.engine());
+        self.auto_detect = false;
+        self.url_safe = None;
+
+        self
+    }
+
+    /// Decodes the custom `alphabet` instead of standard base64. This replaces [`with_auto_detect`](Self::with_auto_detect).
+    #[inline]
+    pub fn with_alphabet(mut self, alphabet: &'static Base64Alphabet) -> Self {
+        self.engine = Base64Codec::from_alphabet(alphabet);
+        self.auto_detect = false;
+        self.url_safe = None;
+
+        self
+    }
+
+    /// Detects the alphabet and the padding mode instead of requiring standard base64.
+    ///
+    /// The alphabet is chosen by the first `+`, `/`, `-` or `_`, and a stream which mixes the two alphabets is rejected.
+    #[inline]
+    pub fn with_auto_detect(mut self) -> Self {
+        self.engine = Base64Codec::new(&STANDARD_INDIFFERENT);
+        self.auto_detect = true;
+
+        self
+    }
+
+    /// Decodes only the region of the inner reader before `terminator` and reaches EOF there. Line breaks within the region are skipped.
+    ///
+    /// Use [`into_rest`](Self::into_rest) afterwards to read what follows the region.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `terminator` is an empty delimiter.
+    #[inline]
+    pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+        self.boundary = Some(Boundary::new(terminator));
+
+        self
+    }
+
+    /// Fails with [`LimitExceeded::Decoded`] instead of returning more than `limit` decoded bytes.
+    #[inline]
+    pub fn with_decoded_limit(mut self, limit: u64) -> Self {
+        self.decoded_limit = limit;
+
+        self
+    }
+
+    /// Fails with [`LimitExceeded::Encoded`] once more than `limit` base64 bytes have been read from the inner reader, and on every read after that.
+    #[inline]
+    pub fn with_encoded_limit(mut self, limit: u64) -> Self {
+        self.encoded_limit = limit;
+
+        self
+    }
+
+    /// Calls `callback` with the byte counts every time another `interval` plain bytes have been read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[inline]
+    pub fn with_progress<F: FnMut(Progress) + Send + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress.set_callback(interval, callback);
+
+        self
+    }
+
+    /// Feeds the decoded data into `digest` as it is read.
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn with_digest<D: digest::DynDigest + Send + Sync + 'static>(mut self, digest: D) -> Self {
+        self.digest = PlainDigest::new(digest);
+
+        self
+    }
 }
 
 impl<R: Read, const N: usize> FromBase64Reader<R, N> {
+    /// Picks the alphabet if `self.buf[self.buf_offset..self.buf_offset + length]` is the first data to reveal it.
+    #[inline]
+    fn detect_alphabet(&mut self, length: usize) {
+        if self.auto_detect && self.url_safe.is_none() {
+            self.set_url_safe(find_url_safe(
+                &self.buf[self.buf_offset..(self.buf_offset + length)],
+            ));
+        }
+    }
+
+    #[inline]
+    fn set_url_safe(&mut self, url_safe: Option<bool>) {
+        if url_safe == Some(true) {
+            self.engine = Base64Codec::new(&URL_SAFE_INDIFFERENT);
+        }
+
+        self.url_safe = url_safe;
+    }
+
+    /// Falls back to the standard alphabet if the whole stream has been decoded without revealing it.
+    #[inline]
+    fn detect_end(&mut self) {
+        if self.auto_detect && self.url_safe.is_none() {
+            self.url_safe = Some(false);
+        }
+    }
+
+    /// Fails once more base64 bytes than the encoded limit have been read, and from then on.
+    #[inline]
+    fn check_encoded_limit(&mut self) -> Result<(), io::Error> {
+        if self.progress.progress().encoded > self.encoded_limit {
+            let exceeded = LimitExceeded::Encoded(self.encoded_limit);
+
+            self.exceeded = Some(exceeded);
+
+            Err(io::Error::other(exceeded))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Shortens `buf` so that filling it exceeds the decoded limit by one byte at most, which is enough to detect it.
+    #[inline]
+    fn limit_buf<'a>(&self, buf: &'a mut [u8]) -> Result<&'a mut [u8], io::Error> {
+        if let Some(exceeded) = self.exceeded {
+            return Err(io::Error::other(exceeded));
+        }
+
+        let remaining = self.decoded_limit - self.progress.progress().plain;
+
+        Ok(if buf.len() as u64 > remaining { &mut buf[..=(remaining as usize)] } else { buf })
+    }
+
+    /// Accounts for `data` having been decoded into the caller's buffer and returns how much of it is within the decoded limit.
+    ///
+    /// Once the limit has been exceeded, this reader only fails.
+    #[inline]
+    fn count_decoded(&mut self, data: &[u8]) -> Result<usize, io::Error> {
+        let remaining = self.decoded_limit - self.progress.progress().plain;
+
+        let length = if data.len() as u64 > remaining {
+            self.exceeded = Some(LimitExceeded::Decoded(self.decoded_limit));
+
+            if remaining == 0 {
+                return Err(io::Error::other(LimitExceeded::Decoded(self.decoded_limit)));
+            }
+
+            remaining as usize
+        } else {
+            data.len()
+        };
+
+        self.digest.update(&data[..length]);
+        self.progress.add_plain(length);
+
+        Ok(length)
+    }
+
     fn buf_left_shift(&mut self, distance: usize) {
         debug_assert!(self.buf_length >= distance);
 
@@ -95,12 +318,18 @@ impl<R: Read, const N: usize> FromBase64Reader<R, N> {
 
         let drain_length = self.buf_length.min(4);
 
+        self.detect_alphabet(drain_length);
+
         let mut b = [0; 3];
 
         let decode_length = self
             .engine
             .decode_slice(&self.buf[self.buf_offset..(self.buf_offset + drain_length)], &mut b)?;
 
+        if drain_length < 4 {
+            self.padded = false;
+        }
+
         self.buf_left_shift(drain_length);
 
         let buf_length = buf.len();
@@ -142,6 +371,8 @@ impl<R: Read, const N: usize> FromBase64Reader<R, N> {
 
             let drain_length = max_available_self_buf_length.min(actual_max_read_size);
 
+            self.detect_alphabet(drain_length);
+
             let decode_length = self
                 .engine
                 .decode_slice(&self.buf[self.buf_offset..(self.buf_offset + drain_length)], buf)?;
@@ -164,18 +395,108 @@ impl<R: Read, const N: usize> FromBase64Reader<R, N> {
             buf = self.drain_temp(buf);
         }
 
-        if !buf.is_empty() && self.buf_length > 0 { self.drain_block(buf) } else { Ok(buf) }
+        let buf = if !buf.is_empty() && self.buf_length > 0 { self.drain_block(buf)? } else { buf };
+
+        self.detect_end();
+
+        Ok(buf)
     }
 
-    /// Returns the inner reader, consuming this wrapper.
+    /// Drains already buffered data into `buf` without reading the inner reader.
     #[inline]
-    pub fn into_inner(self) -> R {
-        self.inner
+    fn drain_buffered<'a>(
+        &mut self,
+        mut buf: &'a mut [u8],
+    ) -> Result<&'a mut [u8], DecodeSliceError> {
+        if self.temp_length > 0 {
+            buf = self.drain_temp(buf);
+        }
+
+        if !buf.is_empty() && self.buf_length >= 4 { self.drain(buf) } else { Ok(buf) }
     }
-}
 
-impl<R: Read, const N: usize> Read for FromBase64Reader<R, N> {
-    fn read(&mut self, mut buf: &mut [u8]) -> Result<usize, io::Error> {
+    /// Reads base64 data straight into a large `buf` and decodes it in place, front to back, without staging it in `self.buf`.
+    ///
+    /// Decoding shrinks the data, so the write cursor never overtakes the first unread base64 byte.
+    fn read_direct(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        debug_assert!(self.temp_length == 0);
+        debug_assert!(self.buf_length < 4);
+
+        let carried_length = self.buf_length;
+
+        buf[..carried_length]
+            .copy_from_slice(&self.buf[self.buf_offset..self.buf_offset + carried_length]);
+
+        let mut input_length = carried_length;
+        let mut eof = false;
+
+        while input_length < 4 {
+            match read_source(&mut self.inner, &mut self.boundary, &mut buf[input_length..]) {
+                Ok(0) => {
+                    eof = true;
+
+                    break;
+                },
+                Ok(c) => {
+                    self.progress.add_encoded(c);
+                    self.check_encoded_limit()?;
+
+                    input_length += c;
+                },
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {},
+                Err(e) => {
+                    // keep what has been read so far, it is less than one block
+                    self.buf[..input_length].copy_from_slice(&buf[..input_length]);
+                    self.buf_offset = 0;
+                    self.buf_length = input_length;
+
+                    return Err(e);
+                },
+            }
+        }
+
+        let decode_length = input_length & !0b11;
+        let remainder_length = input_length - decode_length;
+
+        let mut remainder = [0; 4];
+        remainder[..remainder_length].copy_from_slice(&buf[decode_length..input_length]);
+
+        self.buf_offset = 0;
+        self.buf_length = 0;
+
+        if self.auto_detect && self.url_safe.is_none() {
+            self.set_url_safe(find_url_safe(&buf[..input_length]));
+        }
+
+        let mut output_length =
+            decode_in_place(&self.engine, buf, decode_length).map_err(decode_error_to_io_error)?;
+
+        if eof {
+            if remainder_length > 0 {
+                let mut b = [0; 3];
+
+                let c = self
+                    .engine
+                    .decode_slice(&remainder[..remainder_length], &mut b)
+                    .map_err(decode_error_to_io_error)?;
+
+                buf[output_length..output_length + c].copy_from_slice(&b[..c]);
+
+                output_length += c;
+
+                self.padded = false;
+            }
+
+            self.detect_end();
+        } else {
+            self.buf[..remainder_length].copy_from_slice(&remainder[..remainder_length]);
+            self.buf_length = remainder_length;
+        }
+
+        Ok(output_length)
+    }
+
+    fn read_decoded(&mut self, mut buf: &mut [u8]) -> Result<usize, io::Error> {
         let original_buf_length = buf.len();
 
         if buf.is_empty() {
@@ -194,16 +515,29 @@ impl<R: Read, const N: usize> Read for FromBase64Reader<R, N> {
             return Ok(original_buf_length - buf.len());
         }
 
+        if original_buf_length >= N {
+            return self.read_direct(buf);
+        }
+
         while self.buf_length < 4 {
             debug_assert!(self.buf_offset + self.buf_length <= N);
 
-            match self.inner.read(&mut self.buf[(self.buf_offset + self.buf_length)..]) {
+            match read_source(
+                &mut self.inner,
+                &mut self.boundary,
+                &mut self.buf[(self.buf_offset + self.buf_length)..],
+            ) {
                 Ok(0) => {
                     buf = self.drain_end(buf).map_err(decode_error_to_io_error)?;
 
                     return Ok(original_buf_length - buf.len());
                 },
-                Ok(c) => self.buf_length += c,
+                Ok(c) => {
+                    self.progress.add_encoded(c);
+                    self.check_encoded_limit()?;
+
+                    self.buf_length += c;
+                },
                 Err(ref e) if e.kind() == ErrorKind::Interrupted => {},
                 Err(e) => return Err(e),
             }
@@ -213,6 +547,156 @@ impl<R: Read, const N: usize> Read for FromBase64Reader<R, N> {
 
         Ok(original_buf_length - buf.len())
     }
+
+    /// Returns the variant detected by [`with_auto_detect`](Self::with_auto_detect), or `None` if auto-detection is disabled or the alphabet is not known yet.
+    ///
+    /// Padding is assumed until a final block without it has been decoded. A stream which never reveals its alphabet is reported as standard once it has been decoded entirely.
+    #[inline]
+    pub fn detected_variant(&self) -> Option<Base64Variant> {
+        if self.auto_detect {
+            self.url_safe.map(|url_safe| Base64Variant::new(url_safe, self.padded))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of base64 bytes which have been read from the inner reader and not decoded yet. A decode error from the buffer is at an offset relative to the first of them.
+    #[inline]
+    pub(crate) const fn buffered_encoded_length(&self) -> usize {
+        self.buf_length
+    }
+
+    /// Returns the numbers of plain and base64 bytes which have been read or written so far.
+    #[inline]
+    pub fn progress(&self) -> Progress {
+        self.progress.progress()
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader along with the bytes taken from it but not returned yet, consuming this wrapper.
+    ///
+    /// The plain bytes are decoded bytes which did not fit into the last read. The base64 bytes have been read ahead and not decoded, including anything which follows the base64 data in the inner reader.
+    #[inline]
+    pub fn into_parts(self) -> (R, PendingBytes) {
+        let pending = PendingBytes::new(
+            self.temp[..self.temp_length].to_vec(),
+            self.undecoded(),
+            self.digest,
+        );
+
+        (self.inner, pending)
+    }
+
+    /// Returns the inner reader positioned right after the consumed region, consuming this wrapper.
+    ///
+    /// Bytes which have been read ahead are pushed back in front of it. This is meant to be called once this reader has reached the end of the region given by [`with_terminator`](Self::with_terminator).
+    #[inline]
+    pub fn into_rest(self) -> PushbackReader<R> {
+        let pushback = self.undecoded();
+
+        PushbackReader::new(pushback, self.inner)
+    }
+
+    /// Returns the bytes which have been taken from the inner reader but not decoded.
+    fn undecoded(&self) -> Vec<u8> {
+        let mut undecoded = self.buf[self.buf_offset..(self.buf_offset + self.buf_length)].to_vec();
+
+        if let Some(boundary) = self.boundary.as_ref() {
+            undecoded.extend_from_slice(boundary.pending());
+        }
+
+        undecoded
+    }
+
+    /// Returns the inner reader, consuming this wrapper.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for FromBase64Reader<R, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        let buf = self.limit_buf(buf)?;
+
+        let c = self.read_decoded(buf)?;
+
+        self.count_decoded(&buf[..c])
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize, io::Error> {
+        let mut total_length = 0;
+
+        for buf in bufs.iter_mut().filter(|buf| !buf.is_empty()) {
+            let buf_length = buf.len();
+
+            // only the first slice may wait for the inner reader, the others take what is buffered
+            let read_length = if total_length == 0 {
+                self.read(buf)?
+            } else {
+                if self.exceeded.is_some() || self.progress.progress().plain == self.decoded_limit {
+                    // leave detecting the excess to the next read, so this one does not fail
+                    break;
+                }
+
+                let buf = self.limit_buf(buf)?;
+
+                let c =
+                    buf.len() - self.drain_buffered(buf).map_err(decode_error_to_io_error)?.len();
+
+                self.count_decoded(&buf[..c])?
+            };
+
+            total_length += read_length;
+
+            if read_length < buf_length {
+                break;
+            }
+        }
+
+        Ok(total_length)
+    }
+}
+
+/// Decodes `buf[..input_length]` (a whole number of 4-byte blocks) into `buf[..]` and returns the decoded length.
+fn decode_in_place(
+    engine: &Base64Codec,
+    buf: &mut [u8],
+    input_length: usize,
+) -> Result<usize, DecodeSliceError> {
+    debug_assert!(input_length & 0b11 == 0);
+
+    let mut chunk = [0; IN_PLACE_CHUNK_SIZE];
+
+    let mut read_index = 0;
+    let mut write_index = 0;
+
+    while read_index < input_length {
+        let chunk_length = (input_length - read_index).min(IN_PLACE_CHUNK_SIZE);
+
+        chunk[..chunk_length].copy_from_slice(&buf[read_index..(read_index + chunk_length)]);
+
+        read_index += chunk_length;
+
+        write_index +=
+            engine.decode_slice(&chunk[..chunk_length], &mut buf[write_index..read_index])?;
+    }
+
+    Ok(write_index)
 }
 
 impl<R: Read> From<R> for FromBase64Reader<R> {
@@ -1,11 +1,15 @@
 use std::{
     fmt,
-    io::{self, ErrorKind, Write},
+    io::{self, ErrorKind, IoSlice, Write},
 };
 
-use base64::{
-    DecodeSliceError, Engine,
-    engine::{GeneralPurpose, general_purpose::STANDARD},
+use base64::{DecodeSliceError, engine::general_purpose::STANDARD};
+
+#[cfg(feature = "digest")]
+use crate::plain_digest::DigestOutput;
+use crate::{
+    Base64Alphabet, Base64Variant, PendingBytes, Progress, base64_alphabet::Base64Codec,
+    plain_digest::PlainDigest, progress::ProgressCounter,
 };
 
 #[inline]
@@ -19,7 +23,9 @@ pub struct FromBase64Writer<W: Write, const N: usize = 4096> {
     buf:        [u8; 4],
     buf_length: usize,
     temp:       [u8; N],
-    engine:     &'static GeneralPurpose,
+    engine:     Base64Codec,
+    digest:     PlainDigest,
+    progress:   ProgressCounter,
 }
 
 impl<W: Write, const N: usize> fmt::Debug for FromBase64Writer<W, N> {
@@ -27,6 +33,7 @@ impl<W: Write, const N: usize> fmt::Debug for FromBase64Writer<W, N> {
         f.debug_struct("FromBase64Writer")
             .field("buf", &&self.buf[..self.buf_length])
             .field("buf_length", &self.buf_length)
+            .field("progress", &self.progress.progress())
             .finish_non_exhaustive()
     }
 }
@@ -47,12 +54,86 @@ impl<W: Write, const N: usize> FromBase64Writer<W, N> {
             buf:        [0; 4],
             buf_length: 0,
             temp:       [0u8; N],
-            engine:     &STANDARD,
+            engine:     Base64Codec::new(&STANDARD),
+            digest:     PlainDigest::default(),
+            progress:   ProgressCounter::default(),
         }
     }
+
+    /// Decodes `variant` instead of standard base64.
+    #[inline]
+    pub fn with_variant(mut self, variant: Base64Variant) -> Self {
+        self.engine = Base64Codec::new(variant.engine());
+
+        self
+    }
+
+    /// Decodes the custom `alphabet` instead of standard base64.
+    #[inline]
+    pub fn with_alphabet(mut self, alphabet: &'static Base64Alphabet) -> Self {
+        self.engine = Base64Codec::from_alphabet(alphabet);
+
+        self
+    }
+
+    /// Calls `callback` with the byte counts every time another `interval` plain bytes have been written to the inner writer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[inline]
+    pub fn with_progress<F: FnMut(Progress) + Send + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress.set_callback(interval, callback);
+
+        self
+    }
+
+    /// Feeds the decoded data into `digest` as it is written to the inner writer.
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn with_digest<D: digest::DynDigest + Send + Sync + 'static>(mut self, digest: D) -> Self {
+        self.digest = PlainDigest::new(digest);
+
+        self
+    }
 }
 
 impl<W: Write, const N: usize> FromBase64Writer<W, N> {
+    /// Writes `self.temp[..length]` to the inner writer and feeds it into the digest.
+    #[inline]
+    fn write_temp(&mut self, length: usize) -> Result<(), io::Error> {
+        self.inner.write_all(&self.temp[..length])?;
+
+        self.digest.update(&self.temp[..length]);
+        self.progress.add_plain(length);
+
+        Ok(())
+    }
+
+    /// Ends a write which has failed to decode after `consumed_length` bytes. The data decoded before the error are written and their count is returned, so the invalid data start the next write, which then returns the error.
+    fn write_until_error(
+        &mut self,
+        consumed_length: usize,
+        temp_length: usize,
+        error: DecodeSliceError,
+    ) -> Result<usize, io::Error> {
+        if consumed_length == 0 {
+            return Err(decode_error_to_io_error(error));
+        }
+
+        if temp_length > 0 {
+            self.write_temp(temp_length)?;
+        }
+
+        self.progress.add_encoded(consumed_length);
+
+        Ok(consumed_length)
+    }
+
     fn drain_block(&mut self) -> Result<(), io::Error> {
         debug_assert!(self.buf_length > 0);
 
@@ -61,25 +142,72 @@ impl<W: Write, const N: usize> FromBase64Writer<W, N> {
             .decode_slice(&self.buf[..self.buf_length], &mut self.temp)
             .map_err(decode_error_to_io_error)?;
 
-        self.inner.write_all(&self.temp[..decode_length])?;
+        self.write_temp(decode_length)?;
 
         self.buf_length = 0;
 
         Ok(())
     }
 
-    /// Finishes decoding buffered data and returns the inner writer.
-    #[inline]
-    pub fn finish(mut self) -> Result<W, io::Error> {
+    /// Decodes the final buffered data and flushes the inner writer.
+    fn drain_end(&mut self) -> Result<(), io::Error> {
         if self.buf_length > 0 {
             self.drain_block()?;
         }
 
-        self.inner.flush()?;
+        self.inner.flush()
+    }
+
+    /// Finishes decoding buffered data and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.drain_end()?;
 
         Ok(self.inner)
     }
 
+    /// Finishes decoding buffered data and returns the inner writer along with the digest of the decoded data, or `None` if [`with_digest`](Self::with_digest) has not been called.
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn finish_with_digest(mut self) -> Result<(W, Option<DigestOutput>), io::Error> {
+        self.drain_end()?;
+
+        let digest = self.digest.finalize();
+
+        Ok((self.inner, digest))
+    }
+
+    /// Returns the numbers of plain and base64 bytes which have been read or written so far.
+    #[inline]
+    pub fn progress(&self) -> Progress {
+        self.progress.progress()
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer along with the written bytes which have not reached it yet, consuming this wrapper without finishing it.
+    ///
+    /// The base64 bytes are an incomplete block waiting for the rest of it. There are never pending plain bytes because decoded data are written immediately.
+    #[inline]
+    pub fn into_parts(self) -> (W, PendingBytes) {
+        let pending =
+            PendingBytes::new(Vec::new(), self.buf[..self.buf_length].to_vec(), self.digest);
+
+        (self.inner, pending)
+    }
+
     /// Returns the inner writer, consuming this wrapper without finishing it.
     ///
     /// Call [`finish`](Self::finish) instead to decode the final buffered data and flush the inner writer.
@@ -90,56 +218,96 @@ impl<W: Write, const N: usize> FromBase64Writer<W, N> {
 }
 
 impl<W: Write, const N: usize> Write for FromBase64Writer<W, N> {
-    fn write(&mut self, mut buf: &[u8]) -> Result<usize, io::Error> {
-        let original_buf_length = buf.len();
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.write_vectored(&[IoSlice::new(buf)])
+    }
 
-        if self.buf_length == 0 {
-            while buf.len() >= 4 {
-                let max_available_buf_length = (buf.len() & !0b11).min((N / 3) << 2); // (N / 3) * 4
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, io::Error> {
+        let mut temp_length = 0;
+        let mut total_length = 0;
 
-                let decode_length = self
-                    .engine
-                    .decode_slice(&buf[..max_available_buf_length], &mut self.temp)
-                    .map_err(decode_error_to_io_error)?;
+        for buf in bufs {
+            let mut buf: &[u8] = buf;
 
-                buf = &buf[max_available_buf_length..];
+            let slice_start = total_length;
 
-                self.inner.write_all(&self.temp[..decode_length])?;
-            }
+            total_length += buf.len();
 
-            let buf_length = buf.len();
+            if self.buf_length > 0 {
+                debug_assert!(self.buf_length < 4);
 
-            if buf_length > 0 {
-                self.buf[..buf_length].copy_from_slice(&buf[..buf_length]);
+                let drain_length = (4 - self.buf_length).min(buf.len());
 
-                self.buf_length = buf_length;
-            }
-        } else {
-            debug_assert!(self.buf_length < 4);
+                self.buf[self.buf_length..self.buf_length + drain_length]
+                    .copy_from_slice(&buf[..drain_length]);
 
-            let r = 4 - self.buf_length;
+                buf = &buf[drain_length..];
 
-            let buf_length = buf.len();
+                self.buf_length += drain_length;
+
+                if self.buf_length < 4 {
+                    continue;
+                }
+
+                if N - temp_length < 3 {
+                    self.write_temp(temp_length)?;
 
-            let drain_length = r.min(buf_length);
+                    temp_length = 0;
+                }
 
-            self.buf[self.buf_length..self.buf_length + drain_length]
-                .copy_from_slice(&buf[..drain_length]);
+                match self.engine.decode_slice(&self.buf, &mut self.temp[temp_length..]) {
+                    Ok(decode_length) => temp_length += decode_length,
+                    Err(error) => {
+                        // the bytes of this slice are given back so that the block fails again
+                        self.buf_length -= drain_length;
 
-            buf = &buf[drain_length..];
+                        return self.write_until_error(slice_start, temp_length, error);
+                    },
+                }
+
+                self.buf_length = 0;
+            }
+
+            while buf.len() >= 4 {
+                if N - temp_length < 3 {
+                    self.write_temp(temp_length)?;
 
-            self.buf_length += drain_length;
+                    temp_length = 0;
+                }
 
-            if self.buf_length == 4 {
-                self.drain_block()?;
+                let max_available_buf_length =
+                    (buf.len() & !0b11).min(((N - temp_length) / 3) << 2); // ((N - temp_length) / 3) * 4
 
-                if buf_length > r {
-                    self.write_all(buf)?;
+                match self
+                    .engine
+                    .decode_slice(&buf[..max_available_buf_length], &mut self.temp[temp_length..])
+                {
+                    Ok(decode_length) => temp_length += decode_length,
+                    Err(error) => {
+                        let consumed_length = total_length - buf.len();
+
+                        return self.write_until_error(consumed_length, temp_length, error);
+                    },
                 }
+
+                buf = &buf[max_available_buf_length..];
             }
+
+            let buf_length = buf.len();
+
+            self.buf[..buf_length].copy_from_slice(buf);
+
+            self.buf_length = buf_length;
         }
 
-        Ok(original_buf_length)
+        if temp_length > 0 {
+            self.write_temp(temp_length)?;
+        }
+
+        self.progress.add_encoded(total_length);
+
+        Ok(total_length)
     }
 
     #[inline]
@@ -0,0 +1,238 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::{
+    Bech32Error, Bech32Variant, LimitExceeded,
+    bech32::{
+        CHECKSUM_LENGTH, DECODE_TABLE, MAX_HRP_LENGTH, MAX_LENGTH, hrp_checksum, is_hrp_char,
+        locate_errors, polymod_step,
+    },
+    bit_regrouper::BitRegrouper,
+};
+
+#[inline]
+fn invalid_data(error: Bech32Error) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, error)
+}
+
+/// Read a Bech32 or Bech32m string and decode its data part to plain data.
+///
+/// The checksum covers the whole string, so the inner reader is read to EOF by the first call to [`read`](Read::read) or [`read_hrp`](Self::read_hrp), and no plain byte is returned unless the checksum matches. A mismatch is reported as [`Bech32Error::ChecksumMismatch`] along with the offsets of the characters which are likely to be wrong. Either variant is accepted unless [`with_variant`](Self::with_variant) is called.
+///
+/// Strings longer than 90 characters are rejected unless [`with_limit`](Self::with_limit) raises the limit. The errors of such strings are not located, because the checksum cannot tell them apart reliably and locating them takes time and memory in proportion to the length.
+pub struct FromBech32Reader<R: Read> {
+    inner:       R,
+    variant:     Option<Bech32Variant>,
+    limit:       u64,
+    hrp:         Option<String>,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+}
+
+impl<R: Read> fmt::Debug for FromBech32Reader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromBech32Reader")
+            .field("variant", &self.variant)
+            .field("limit", &self.limit)
+            .field("hrp", &self.hrp)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> FromBech32Reader<R> {
+    #[inline]
+    pub fn new(reader: R) -> FromBech32Reader<R> {
+        FromBech32Reader {
+            inner:       reader,
+            variant:     None,
+            limit:       MAX_LENGTH as u64,
+            hrp:         None,
+            temp:        Vec::new(),
+            temp_offset: 0,
+        }
+    }
+
+    /// Accepts only the checksum of `variant` instead of either.
+    #[inline]
+    pub fn with_variant(mut self, variant: Bech32Variant) -> Self {
+        self.variant = Some(variant);
+
+        self
+    }
+
+    /// Fails with [`LimitExceeded::Encoded`] instead of reading a string longer than `limit` bytes, which is 90 by default.
+    #[inline]
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+
+        self
+    }
+}
+
+impl<R: Read> FromBech32Reader<R> {
+    /// Reads the inner reader to EOF, checks the string and decodes its data part into `self.temp`.
+    fn decode(&mut self) -> Result<(), io::Error> {
+        let mut string = Vec::new();
+
+        // one more byte than the limit tells a string over the limit from one which just fits
+        (&mut self.inner).take(self.limit.saturating_add(1)).read_to_end(&mut string)?;
+
+        if string.len() as u64 > self.limit {
+            return Err(io::Error::other(LimitExceeded::Encoded(self.limit)));
+        }
+
+        let separator = string
+            .iter()
+            .rposition(|&b| b == b'1')
+            .ok_or_else(|| invalid_data(Bech32Error::MissingSeparator))?;
+
+        if !(1..=MAX_HRP_LENGTH).contains(&separator) {
+            return Err(invalid_data(Bech32Error::InvalidHrpLength(separator)));
+        }
+
+        if string.iter().any(u8::is_ascii_lowercase) && string.iter().any(u8::is_ascii_uppercase) {
+            return Err(invalid_data(Bech32Error::MixedCase));
+        }
+
+        string.make_ascii_lowercase();
+
+        let (hrp, data) = string.split_at_mut(separator);
+        let data = &mut data[1..];
+
+        if let Some(offset) = hrp.iter().position(|&b| !is_hrp_char(b)) {
+            return Err(invalid_data(Bech32Error::InvalidSymbol(offset as u64, hrp[offset])));
+        }
+
+        let mut chk = hrp_checksum(hrp);
+
+        for (i, b) in data.iter_mut().enumerate() {
+            let value = DECODE_TABLE[usize::from(*b)];
+
+            if value == 0xFF {
+                return Err(invalid_data(Bech32Error::InvalidSymbol(
+                    (separator + 1 + i) as u64,
+                    *b,
+                )));
+            }
+
+            chk = polymod_step(chk, value);
+
+            *b = value;
+        }
+
+        let Some(length) = data.len().checked_sub(CHECKSUM_LENGTH) else {
+            return Err(invalid_data(Bech32Error::MissingChecksum));
+        };
+
+        let variants = match self.variant {
+            Some(variant) => vec![variant],
+            None => vec![Bech32Variant::Bech32, Bech32Variant::Bech32m],
+        };
+
+        let Some(&variant) = variants.iter().find(|variant| chk == variant.constant()) else {
+            if separator + 1 + data.len() > MAX_LENGTH {
+                return Err(invalid_data(Bech32Error::ChecksumMismatch(Vec::new())));
+            }
+
+            // the variant whose fewest substitutions explain the checksum is the likely one
+            let offsets = variants
+                .iter()
+                .map(|variant| locate_errors(chk ^ variant.constant(), data.len()))
+                .filter(|positions| !positions.is_empty())
+                .min_by_key(Vec::len)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|i| (separator + 1 + i) as u64)
+                .collect();
+
+            return Err(invalid_data(Bech32Error::ChecksumMismatch(offsets)));
+        };
+
+        let mut regrouper = BitRegrouper::new(5, 8);
+
+        regrouper.push(&data[..length], &mut self.temp);
+
+        if !regrouper.finish_exact() {
+            self.temp.clear();
+
+            return Err(invalid_data(Bech32Error::InvalidPadding));
+        }
+
+        self.variant = Some(variant);
+        self.hrp = Some(String::from_utf8(hrp.to_vec()).unwrap());
+
+        Ok(())
+    }
+
+    /// Reads and checks the whole string, and returns its human-readable part in lowercase.
+    pub fn read_hrp(&mut self) -> Result<&str, io::Error> {
+        if self.hrp.is_none() {
+            self.decode()?;
+        }
+
+        Ok(self.hrp.as_deref().unwrap())
+    }
+
+    /// Returns the human-readable part in lowercase once the string has been read.
+    #[inline]
+    pub fn hrp(&self) -> Option<&str> {
+        self.hrp.as_deref()
+    }
+
+    /// Returns the variant of the checksum once the string has been read.
+    #[inline]
+    pub fn variant(&self) -> Option<Bech32Variant> {
+        self.hrp.as_ref().and(self.variant)
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for FromBech32Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.hrp.is_none() {
+            self.decode()?;
+        }
+
+        let temp = &self.temp[self.temp_offset..];
+        let c = temp.len().min(buf.len());
+
+        buf[..c].copy_from_slice(&temp[..c]);
+
+        self.temp_offset += c;
+
+        Ok(c)
+    }
+}
+
+impl<R: Read> From<R> for FromBech32Reader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        FromBech32Reader::new(reader)
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::{LineEnding, quoted_printable::QuotedPrintableDecoder};
+
+/// Read quoted-printable data and decode them to plain data.
+///
+/// Hard line breaks, either CRLF, LF or a lone CR, are normalized to CRLF, soft line breaks are removed and the whitespace at the end of lines is dropped.
+pub struct FromQuotedPrintableReader<R: Read, const N: usize = 4096> {
+    inner:       R,
+    buf:         [u8; N],
+    decoder:     QuotedPrintableDecoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    eof:         bool,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for FromQuotedPrintableReader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromQuotedPrintableReader")
+            .field("decoder", &self.decoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> FromQuotedPrintableReader<R> {
+    #[inline]
+    pub fn new(reader: R) -> FromQuotedPrintableReader<R> {
+        Self::new2(reader)
+    }
+}
+
+impl<R: Read, const N: usize> FromQuotedPrintableReader<R, N> {
+    #[inline]
+    pub fn new2(reader: R) -> FromQuotedPrintableReader<R, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        FromQuotedPrintableReader {
+            inner:       reader,
+            buf:         [0u8; N],
+            decoder:     QuotedPrintableDecoder::new(),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+            eof:         false,
+        }
+    }
+
+    /// Normalizes hard line breaks to `line_ending` instead of CRLF.
+    #[inline]
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.decoder.set_line_ending(line_ending);
+
+        self
+    }
+}
+
+impl<R: Read, const N: usize> FromQuotedPrintableReader<R, N> {
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for FromQuotedPrintableReader<R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let temp = &self.temp[self.temp_offset..];
+
+            if !temp.is_empty() {
+                let c = temp.len().min(buf.len());
+
+                buf[..c].copy_from_slice(&temp[..c]);
+
+                self.temp_offset += c;
+
+                return Ok(c);
+            }
+
+            if self.eof {
+                return Ok(0);
+            }
+
+            self.temp.clear();
+            self.temp_offset = 0;
+
+            let c = self.inner.read(&mut self.buf)?;
+
+            let result = if c == 0 {
+                self.eof = true;
+
+                self.decoder.finish()
+            } else {
+                self.decoder.decode(&self.buf[..c], &mut self.temp)
+            };
+
+            result.map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+        }
+    }
+}
+
+impl<R: Read> From<R> for FromQuotedPrintableReader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        FromQuotedPrintableReader::new(reader)
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+};
+
+use crate::{LineEnding, quoted_printable::QuotedPrintableDecoder};
+
+/// Write quoted-printable data and decode them to plain data.
+///
+/// Hard line breaks, either CRLF, LF or a lone CR, are normalized to CRLF, soft line breaks are removed and the whitespace at the end of lines is dropped.
+pub struct FromQuotedPrintableWriter<W: Write, const N: usize = 4096> {
+    inner:       W,
+    decoder:     QuotedPrintableDecoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for FromQuotedPrintableWriter<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromQuotedPrintableWriter")
+            .field("decoder", &self.decoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> FromQuotedPrintableWriter<W> {
+    #[inline]
+    pub fn new(writer: W) -> FromQuotedPrintableWriter<W> {
+        Self::new2(writer)
+    }
+}
+
+impl<W: Write, const N: usize> FromQuotedPrintableWriter<W, N> {
+    #[inline]
+    pub fn new2(writer: W) -> FromQuotedPrintableWriter<W, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        FromQuotedPrintableWriter {
+            inner:       writer,
+            decoder:     QuotedPrintableDecoder::new(),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+        }
+    }
+
+    /// Normalizes hard line breaks to `line_ending` instead of CRLF.
+    #[inline]
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.decoder.set_line_ending(line_ending);
+
+        self
+    }
+}
+
+impl<W: Write, const N: usize> FromQuotedPrintableWriter<W, N> {
+    /// Writes the decoded bytes which have not reached the inner writer yet.
+    fn drain_temp(&mut self) -> Result<(), io::Error> {
+        while self.temp_offset < self.temp.len() {
+            match self.inner.write(&self.temp[self.temp_offset..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(c) => self.temp_offset += c,
+                Err(error) if error.kind() == ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        self.temp.clear();
+        self.temp_offset = 0;
+
+        Ok(())
+    }
+
+    /// Finishes decoding buffered data and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.drain_temp()?;
+
+        self.decoder.finish().map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to decode the final buffered data and flush the inner writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, const N: usize> Write for FromQuotedPrintableWriter<W, N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        // the bytes left by a failed call are written first
+        self.drain_temp()?;
+
+        let c = buf.len().min(N);
+
+        self.decoder
+            .decode(&buf[..c], &mut self.temp)
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+
+        // a failure is left to the next call along with the unwritten bytes, so that no error is returned after bytes have been taken
+        let _ = self.drain_temp();
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.drain_temp()?;
+
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> From<W> for FromQuotedPrintableWriter<W> {
+    #[inline]
+    fn from(writer: W) -> Self {
+        FromQuotedPrintableWriter::new(writer)
+    }
+}
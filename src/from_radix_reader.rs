@@ -0,0 +1,142 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::{Progress, RadixEngine, progress::ProgressCounter, radix::RadixDecoder};
+
+/// Read data encoded with a [`RadixEngine`], such as base32, and decode them to plain data.
+///
+/// The bits of an incomplete byte are carried over to the next read from the inner reader. Reading fails with a [`RadixError`](crate::RadixError) carrying the offset of an invalid symbol, or at EOF if the padding is wrong or the leftover bits are not a valid end.
+pub struct FromRadixReader<R: Read, const N: usize = 4096> {
+    inner:       R,
+    buf:         [u8; N],
+    decoder:     RadixDecoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    eof:         bool,
+    progress:    ProgressCounter,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for FromRadixReader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromRadixReader")
+            .field("decoder", &self.decoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .field("eof", &self.eof)
+            .field("progress", &self.progress.progress())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> FromRadixReader<R> {
+    #[inline]
+    pub fn new(reader: R, engine: &'static RadixEngine) -> FromRadixReader<R> {
+        Self::new2(reader, engine)
+    }
+}
+
+impl<R: Read, const N: usize> FromRadixReader<R, N> {
+    #[inline]
+    pub fn new2(reader: R, engine: &'static RadixEngine) -> FromRadixReader<R, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        FromRadixReader {
+            inner:       reader,
+            buf:         [0u8; N],
+            decoder:     RadixDecoder::new(engine),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+            eof:         false,
+            progress:    ProgressCounter::default(),
+        }
+    }
+
+    /// Calls `callback` with the byte counts every time another `interval` plain bytes have been read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[inline]
+    pub fn with_progress<F: FnMut(Progress) + Send + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress.set_callback(interval, callback);
+
+        self
+    }
+}
+
+impl<R: Read, const N: usize> FromRadixReader<R, N> {
+    /// Returns the numbers of plain and encoded bytes which have been read so far.
+    #[inline]
+    pub fn progress(&self) -> Progress {
+        self.progress.progress()
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for FromRadixReader<R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let temp = &self.temp[self.temp_offset..];
+
+            if !temp.is_empty() {
+                let c = temp.len().min(buf.len());
+
+                buf[..c].copy_from_slice(&temp[..c]);
+
+                self.temp_offset += c;
+
+                self.progress.add_plain(c);
+
+                return Ok(c);
+            }
+
+            if self.eof {
+                return Ok(0);
+            }
+
+            self.temp.clear();
+            self.temp_offset = 0;
+
+            let c = self.inner.read(&mut self.buf)?;
+
+            let result = if c == 0 {
+                self.eof = true;
+
+                self.decoder.finish()
+            } else {
+                self.progress.add_encoded(c);
+
+                self.decoder.decode(&self.buf[..c], &mut self.temp)
+            };
+
+            result.map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+};
+
+use crate::{Progress, RadixEngine, progress::ProgressCounter, radix::RadixDecoder};
+
+/// Write data encoded with a [`RadixEngine`], such as base32, and decode them to plain data.
+///
+/// The bits of an incomplete byte are carried over to the next write. [`finish`](Self::finish) fails with a [`RadixError`](crate::RadixError) if the padding is wrong or the leftover bits are not a valid end.
+pub struct FromRadixWriter<W: Write, const N: usize = 4096> {
+    inner:       W,
+    decoder:     RadixDecoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    progress:    ProgressCounter,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for FromRadixWriter<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromRadixWriter")
+            .field("decoder", &self.decoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .field("progress", &self.progress.progress())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> FromRadixWriter<W> {
+    #[inline]
+    pub fn new(writer: W, engine: &'static RadixEngine) -> FromRadixWriter<W> {
+        Self::new2(writer, engine)
+    }
+}
+
+impl<W: Write, const N: usize> FromRadixWriter<W, N> {
+    #[inline]
+    pub fn new2(writer: W, engine: &'static RadixEngine) -> FromRadixWriter<W, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        FromRadixWriter {
+            inner:       writer,
+            decoder:     RadixDecoder::new(engine),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+            progress:    ProgressCounter::default(),
+        }
+    }
+
+    /// Calls `callback` with the byte counts every time another `interval` plain bytes have been written to the inner writer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[inline]
+    pub fn with_progress<F: FnMut(Progress) + Send + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress.set_callback(interval, callback);
+
+        self
+    }
+}
+
+impl<W: Write, const N: usize> FromRadixWriter<W, N> {
+    /// Writes the decoded bytes which have not reached the inner writer yet.
+    fn drain_temp(&mut self) -> Result<(), io::Error> {
+        while self.temp_offset < self.temp.len() {
+            match self.inner.write(&self.temp[self.temp_offset..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(c) => {
+                    self.temp_offset += c;
+
+                    self.progress.add_plain(c);
+                },
+                Err(error) if error.kind() == ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        self.temp.clear();
+        self.temp_offset = 0;
+
+        Ok(())
+    }
+
+    /// Finishes decoding buffered data and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.drain_temp()?;
+
+        self.decoder.finish().map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Returns the numbers of plain and encoded bytes which have been written so far.
+    #[inline]
+    pub fn progress(&self) -> Progress {
+        self.progress.progress()
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to decode the final buffered data and flush the inner writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, const N: usize> Write for FromRadixWriter<W, N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        // the bytes left by a failed call are written first
+        self.drain_temp()?;
+
+        let c = buf.len().min(N);
+
+        self.decoder
+            .decode(&buf[..c], &mut self.temp)
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+
+        self.progress.add_encoded(c);
+
+        // a failure is left to the next call along with the unwritten bytes, so that no error is returned after bytes have been taken
+        let _ = self.drain_temp();
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.drain_temp()?;
+
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::{PushbackReader, UuencodeVariant};
+
+/// Bytes read from the inner reader per step.
+const CHUNK_SIZE: usize = 4096;
+/// The longest line accepted, far more than the 61 characters of a full line.
+const MAX_LINE_LENGTH: usize = 1024;
+
+/// The `begin <mode> <name>` line of uuencoded data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UuencodeHeader {
+    /// The permission bits of the file, such as `0o644`.
+    pub mode: u32,
+    /// The name of the file.
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Looking for the `begin` line.
+    Begin,
+    /// Reading the encoded lines.
+    Data,
+    /// After the zero-length line, expecting the `end` line.
+    End,
+    Done,
+}
+
+#[inline]
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+/// Parses `begin <mode> <name>`.
+fn parse_header(line: &[u8]) -> Option<UuencodeHeader> {
+    let line = std::str::from_utf8(line).ok()?;
+
+    let (mode, name) = line.strip_prefix("begin ")?.trim_start().split_once(' ')?;
+
+    Some(UuencodeHeader {
+        mode: u32::from_str_radix(mode, 8).ok()?, name: name.to_string()
+    })
+}
+
+/// Read uuencoded data and decode them to plain data.
+///
+/// The lines before the `begin <mode> <name>` line are skipped, and reading ends at the `end` line. Lines may end with LF or CRLF.
+pub struct FromUuencodeReader<R: Read> {
+    inner:       R,
+    variant:     UuencodeVariant,
+    header:      Option<UuencodeHeader>,
+    state:       State,
+    buf:         Vec<u8>,
+    buf_offset:  usize,
+    eof:         bool,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+}
+
+impl<R: Read> fmt::Debug for FromUuencodeReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromUuencodeReader")
+            .field("variant", &self.variant)
+            .field("header", &self.header)
+            .field("state", &self.state)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> FromUuencodeReader<R> {
+    #[inline]
+    pub fn new(reader: R) -> FromUuencodeReader<R> {
+        FromUuencodeReader {
+            inner:       reader,
+            variant:     UuencodeVariant::Uuencode,
+            header:      None,
+            state:       State::Begin,
+            buf:         Vec::new(),
+            buf_offset:  0,
+            eof:         false,
+            temp:        Vec::new(),
+            temp_offset: 0,
+        }
+    }
+
+    /// Decodes `variant` instead of uuencode.
+    #[inline]
+    pub fn with_variant(mut self, variant: UuencodeVariant) -> Self {
+        self.variant = variant;
+
+        self
+    }
+}
+
+impl<R: Read> FromUuencodeReader<R> {
+    /// Takes the next line without its line ending, or `None` at EOF.
+    fn next_line(&mut self) -> Result<Option<Vec<u8>>, io::Error> {
+        loop {
+            let pending = &self.buf[self.buf_offset..];
+
+            if let Some(p) = pending.iter().position(|&b| b == b'\n') {
+                let mut line = pending[..p].to_vec();
+
+                self.buf_offset += p + 1;
+
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+
+                return Ok(Some(line));
+            }
+
+            if pending.len() > MAX_LINE_LENGTH {
+                return Err(invalid_data("a line of uuencoded data is too long"));
+            }
+
+            if self.eof {
+                if pending.is_empty() {
+                    return Ok(None);
+                }
+
+                let line = pending.to_vec();
+
+                self.buf_offset = self.buf.len();
+
+                return Ok(Some(line));
+            }
+
+            self.buf.drain(..self.buf_offset);
+            self.buf_offset = 0;
+
+            let length = self.buf.len();
+
+            self.buf.resize(length + CHUNK_SIZE, 0);
+
+            match self.inner.read(&mut self.buf[length..]) {
+                Ok(c) => {
+                    self.buf.truncate(length + c);
+
+                    if c == 0 {
+                        self.eof = true;
+                    }
+                },
+                Err(error) => {
+                    self.buf.truncate(length);
+
+                    return Err(error);
+                },
+            }
+        }
+    }
+
+    /// Skips the lines up to the `begin` line and returns the header in it.
+    pub fn read_header(&mut self) -> Result<&UuencodeHeader, io::Error> {
+        while self.state == State::Begin {
+            let Some(line) = self.next_line()? else {
+                return Err(io::Error::new(ErrorKind::UnexpectedEof, "no begin line is found"));
+            };
+
+            if let Some(header) = parse_header(&line) {
+                self.header = Some(header);
+                self.state = State::Data;
+            }
+        }
+
+        Ok(self.header.as_ref().unwrap())
+    }
+
+    /// Returns the header once the `begin` line has been read.
+    #[inline]
+    pub fn header(&self) -> Option<&UuencodeHeader> {
+        self.header.as_ref()
+    }
+
+    /// Decodes the next line into `self.temp`.
+    fn decode_next_line(&mut self) -> Result<(), io::Error> {
+        if self.state == State::Begin {
+            self.read_header()?;
+        }
+
+        let Some(line) = self.next_line()? else {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "no end line is found"));
+        };
+
+        match self.state {
+            State::Data => {
+                self.temp.clear();
+                self.temp_offset = 0;
+
+                self.variant
+                    .decode_line(&line, &mut self.temp)
+                    .ok_or_else(|| invalid_data("invalid character or line length in uuencoded data"))?;
+
+                if self.temp.is_empty() {
+                    self.state = State::End;
+                }
+            },
+            State::End => {
+                if line.trim_ascii_end() != b"end" {
+                    return Err(invalid_data("no end line follows the uuencoded data"));
+                }
+
+                self.state = State::Done;
+            },
+            State::Begin | State::Done => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns a reader of the data which have not been parsed yet, such as what follows the `end` line, consuming this wrapper.
+    #[inline]
+    pub fn into_rest(mut self) -> PushbackReader<R> {
+        self.buf.drain(..self.buf_offset);
+
+        PushbackReader::new(self.buf, self.inner)
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for FromUuencodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let temp = &self.temp[self.temp_offset..];
+
+            if !temp.is_empty() {
+                let c = temp.len().min(buf.len());
+
+                buf[..c].copy_from_slice(&temp[..c]);
+
+                self.temp_offset += c;
+
+                return Ok(c);
+            }
+
+            if self.state == State::Done {
+                return Ok(0);
+            }
+
+            self.decode_next_line()?;
+        }
+    }
+}
+
+impl<R: Read> From<R> for FromUuencodeReader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        FromUuencodeReader::new(reader)
+    }
+}
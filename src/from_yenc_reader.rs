@@ -0,0 +1,383 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::{
+    PushbackReader, YencError, YencHeader, YencPart, YencTrailer,
+    crc32::Crc32,
+    yenc::{param, parse_params},
+};
+
+/// The longest line accepted, far more than the usual 128 or 256 characters.
+const MAX_LINE_LENGTH: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Looking for the `=ybegin` line.
+    Begin,
+    /// Reading the encoded lines.
+    Data,
+    /// After the `=yend` line.
+    Done,
+}
+
+#[inline]
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+#[inline]
+fn parse_number<T: std::str::FromStr>(value: Option<&str>) -> Result<T, io::Error> {
+    value.and_then(|value| value.parse().ok()).ok_or_else(|| invalid_data("invalid yEnc header"))
+}
+
+#[inline]
+fn parse_crc32(value: Option<&str>) -> Result<Option<u32>, io::Error> {
+    value
+        .map(|value| {
+            u32::from_str_radix(value, 16).map_err(|_| invalid_data("invalid CRC-32 in yEnc data"))
+        })
+        .transpose()
+}
+
+/// Read yEnc data and decode them to plain data.
+///
+/// The lines before the `=ybegin` line are skipped, and reading ends at the `=yend` line, where the size and the CRC-32 of the decoded data are checked. A part of a multipart file decodes to the bytes of that part only, which belong at [`YencPart::begin`] in the file.
+pub struct FromYencReader<R: Read, const N: usize = 4096> {
+    inner:       R,
+    buf:         Vec<u8>,
+    buf_offset:  usize,
+    eof:         bool,
+    state:       State,
+    header:      Option<YencHeader>,
+    trailer:     Option<YencTrailer>,
+    escape:      bool,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    decoded:     u64,
+    crc32:       Crc32,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for FromYencReader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromYencReader")
+            .field("state", &self.state)
+            .field("header", &self.header)
+            .field("trailer", &self.trailer)
+            .field("decoded", &self.decoded)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> FromYencReader<R> {
+    #[inline]
+    pub fn new(reader: R) -> FromYencReader<R> {
+        Self::new2(reader)
+    }
+}
+
+impl<R: Read, const N: usize> FromYencReader<R, N> {
+    #[inline]
+    pub fn new2(reader: R) -> FromYencReader<R, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        FromYencReader {
+            inner:       reader,
+            buf:         Vec::with_capacity(N),
+            buf_offset:  0,
+            eof:         false,
+            state:       State::Begin,
+            header:      None,
+            trailer:     None,
+            escape:      false,
+            temp:        Vec::new(),
+            temp_offset: 0,
+            decoded:     0,
+            crc32:       Crc32::default(),
+        }
+    }
+}
+
+impl<R: Read, const N: usize> FromYencReader<R, N> {
+    /// Takes the next line without its line ending, or `None` at EOF.
+    fn next_line(&mut self) -> Result<Option<Vec<u8>>, io::Error> {
+        loop {
+            let pending = &self.buf[self.buf_offset..];
+
+            if let Some(p) = pending.iter().position(|&b| b == b'\n') {
+                let mut line = pending[..p].to_vec();
+
+                self.buf_offset += p + 1;
+
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+
+                return Ok(Some(line));
+            }
+
+            if pending.len() > MAX_LINE_LENGTH {
+                return Err(invalid_data("a line of yEnc data is too long"));
+            }
+
+            if self.eof {
+                if pending.is_empty() {
+                    return Ok(None);
+                }
+
+                let line = pending.to_vec();
+
+                self.buf_offset = self.buf.len();
+
+                return Ok(Some(line));
+            }
+
+            self.buf.drain(..self.buf_offset);
+            self.buf_offset = 0;
+
+            let length = self.buf.len();
+
+            self.buf.resize(length + N, 0);
+
+            match self.inner.read(&mut self.buf[length..]) {
+                Ok(c) => {
+                    self.buf.truncate(length + c);
+
+                    if c == 0 {
+                        self.eof = true;
+                    }
+                },
+                Err(error) => {
+                    self.buf.truncate(length);
+
+                    return Err(error);
+                },
+            }
+        }
+    }
+
+    /// Skips the lines up to the `=ybegin` line and returns the header in it, along with the `=ypart` line of a part.
+    pub fn read_header(&mut self) -> Result<&YencHeader, io::Error> {
+        while self.state == State::Begin {
+            let Some(line) = self.next_line()? else {
+                return Err(io::Error::new(ErrorKind::UnexpectedEof, "no =ybegin line is found"));
+            };
+
+            let Some(params) = line.strip_prefix(b"=ybegin ") else {
+                continue;
+            };
+
+            let params = String::from_utf8_lossy(params);
+            let (params, name) = parse_params(&params);
+
+            let part = match param(&params, "part") {
+                Some(number) => {
+                    let number = parse_number(Some(number))?;
+                    let total = param(&params, "total")
+                        .map(|total| parse_number(Some(total)))
+                        .transpose()?;
+
+                    let Some(line) = self.next_line()? else {
+                        return Err(io::Error::new(
+                            ErrorKind::UnexpectedEof,
+                            "no =ypart line is found",
+                        ));
+                    };
+
+                    let Some(part_params) = line.strip_prefix(b"=ypart ") else {
+                        return Err(invalid_data(
+                            "no =ypart line follows a =ybegin line of a part",
+                        ));
+                    };
+
+                    let part_params = String::from_utf8_lossy(part_params);
+                    let (part_params, _) = parse_params(&part_params);
+
+                    let begin: u64 = parse_number(param(&part_params, "begin"))?;
+                    let end: u64 = parse_number(param(&part_params, "end"))?;
+
+                    if begin == 0 || begin > end {
+                        return Err(invalid_data("invalid yEnc part range"));
+                    }
+
+                    Some(YencPart {
+                        number,
+                        total,
+                        begin,
+                        end,
+                    })
+                },
+                None => None,
+            };
+
+            self.header = Some(YencHeader {
+                name: name.ok_or_else(|| invalid_data("no name in a =ybegin line"))?.to_string(),
+                size: parse_number(param(&params, "size"))?,
+                line_length: param(&params, "line")
+                    .map(|line| parse_number(Some(line)))
+                    .transpose()?,
+                part,
+            });
+            self.state = State::Data;
+        }
+
+        Ok(self.header.as_ref().unwrap())
+    }
+
+    /// Returns the header once the `=ybegin` line has been read.
+    #[inline]
+    pub fn header(&self) -> Option<&YencHeader> {
+        self.header.as_ref()
+    }
+
+    /// Returns the trailer once the `=yend` line has been read. Its [`crc32`](YencTrailer::crc32) is the CRC-32 of the whole file, which can be checked once all the parts are put together.
+    #[inline]
+    pub fn trailer(&self) -> Option<&YencTrailer> {
+        self.trailer.as_ref()
+    }
+
+    /// Parses the `=yend` line and checks the decoded data against it.
+    fn end(&mut self, params: &[u8]) -> Result<(), io::Error> {
+        let params = String::from_utf8_lossy(params);
+        let (params, _) = parse_params(&params);
+
+        let trailer = YencTrailer {
+            size:       parse_number(param(&params, "size"))?,
+            part:       param(&params, "part").map(|part| parse_number(Some(part))).transpose()?,
+            part_crc32: parse_crc32(param(&params, "pcrc32"))?,
+            crc32:      parse_crc32(param(&params, "crc32"))?,
+        };
+
+        let header = self.header.as_ref().unwrap();
+
+        let expected_size = header.part.map_or(header.size, |part| part.size());
+
+        for expected in [expected_size, trailer.size] {
+            if self.decoded != expected {
+                return Err(io::Error::new(ErrorKind::InvalidData, YencError::SizeMismatch {
+                    expected,
+                    actual: self.decoded,
+                }));
+            }
+        }
+
+        let expected_crc32 = if header.part.is_some() { trailer.part_crc32 } else { trailer.crc32 };
+
+        if let Some(expected) = expected_crc32 {
+            let actual = self.crc32.value();
+
+            if actual != expected {
+                return Err(io::Error::new(ErrorKind::InvalidData, YencError::Crc32Mismatch {
+                    expected,
+                    actual,
+                }));
+            }
+        }
+
+        self.trailer = Some(trailer);
+        self.state = State::Done;
+
+        Ok(())
+    }
+
+    /// Decodes the next line into `self.temp`.
+    fn decode_next_line(&mut self) -> Result<(), io::Error> {
+        if self.state == State::Begin {
+            self.read_header()?;
+        }
+
+        let Some(line) = self.next_line()? else {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "no =yend line is found"));
+        };
+
+        if let Some(params) = line.strip_prefix(b"=yend") {
+            return self.end(params);
+        }
+
+        self.temp.clear();
+        self.temp_offset = 0;
+
+        for &b in &line {
+            if self.escape {
+                self.escape = false;
+
+                self.temp.push(b.wrapping_sub(64 + 42));
+            } else {
+                match b {
+                    b'=' => self.escape = true,
+                    // line breaks are never data
+                    b'\r' | b'\n' => (),
+                    _ => self.temp.push(b.wrapping_sub(42)),
+                }
+            }
+        }
+
+        self.crc32.update(&self.temp);
+        self.decoded += self.temp.len() as u64;
+
+        Ok(())
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns a reader of the data which have not been parsed yet, such as the next part after the `=yend` line, consuming this wrapper.
+    #[inline]
+    pub fn into_rest(mut self) -> PushbackReader<R> {
+        self.buf.drain(..self.buf_offset);
+
+        PushbackReader::new(self.buf, self.inner)
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for FromYencReader<R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let temp = &self.temp[self.temp_offset..];
+
+            if !temp.is_empty() {
+                let c = temp.len().min(buf.len());
+
+                buf[..c].copy_from_slice(&temp[..c]);
+
+                self.temp_offset += c;
+
+                return Ok(c);
+            }
+
+            if self.state == State::Done {
+                return Ok(0);
+            }
+
+            self.decode_next_line()?;
+        }
+    }
+}
+
+impl<R: Read> From<R> for FromYencReader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        FromYencReader::new(reader)
+    }
+}
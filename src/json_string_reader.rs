@@ -0,0 +1,212 @@
+use std::io::{self, ErrorKind, Read};
+
+use crate::PushbackReader;
+
+/// Bytes read from the inner reader per step.
+const CHUNK_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Before the opening quote.
+    Start,
+    /// Inside the string.
+    Inside,
+    /// After the closing quote.
+    Ended,
+}
+
+#[inline]
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+#[inline]
+fn hex_value(b: u8) -> Option<u16> {
+    match b {
+        b'0'..=b'9' => Some(u16::from(b - b'0')),
+        b'a'..=b'f' => Some(u16::from(b - b'a' + 10)),
+        b'A'..=b'F' => Some(u16::from(b - b'A' + 10)),
+        _ => None,
+    }
+}
+
+/// Reads the contents of a JSON string, which may be preceded by whitespace, with its escapes resolved. Escaped line breaks are skipped.
+///
+/// What is read past the closing quote is kept for [`into_rest`](Self::into_rest).
+#[derive(Debug)]
+pub(crate) struct JsonStringReader<R: Read> {
+    inner:   R,
+    pending: Vec<u8>,
+    offset:  usize,
+    eof:     bool,
+    state:   State,
+}
+
+impl<R: Read> JsonStringReader<R> {
+    #[inline]
+    pub(crate) fn new(reader: R) -> JsonStringReader<R> {
+        JsonStringReader {
+            inner:   reader,
+            pending: Vec::new(),
+            offset:  0,
+            eof:     false,
+            state:   State::Start,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    #[inline]
+    pub(crate) fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader with the bytes which have been read ahead pushed back in front of it.
+    #[inline]
+    pub(crate) fn into_rest(mut self) -> PushbackReader<R> {
+        self.pending.drain(..self.offset);
+
+        PushbackReader::new(self.pending, self.inner)
+    }
+
+    #[inline]
+    pub(crate) fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads more bytes from the inner reader into `self.pending`.
+    fn fill(&mut self) -> Result<(), io::Error> {
+        if self.eof {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "unterminated JSON string"));
+        }
+
+        self.pending.drain(..self.offset);
+        self.offset = 0;
+
+        let length = self.pending.len();
+
+        self.pending.resize(length + CHUNK_SIZE, 0);
+
+        match self.inner.read(&mut self.pending[length..]) {
+            Ok(c) => {
+                self.pending.truncate(length + c);
+
+                if c == 0 {
+                    self.eof = true;
+                }
+
+                Ok(())
+            },
+            Err(e) => {
+                self.pending.truncate(length);
+
+                Err(e)
+            },
+        }
+    }
+
+    /// Resolves the escape at `self.offset`, or returns `None` if it is not complete in `self.pending` yet.
+    fn unescape(&mut self) -> Result<Option<u8>, io::Error> {
+        let escape = &self.pending[self.offset..];
+
+        let Some(&kind) = escape.get(1) else {
+            return Ok(None);
+        };
+
+        let (b, length) = match kind {
+            b'"' | b'\\' | b'/' => (kind, 2),
+            b'b' => (0x08, 2),
+            b'f' => (0x0C, 2),
+            b'n' => (b'\n', 2),
+            b'r' => (b'\r', 2),
+            b't' => (b'\t', 2),
+            b'u' => {
+                if escape.len() < 6 {
+                    return Ok(None);
+                }
+
+                let code = escape[2..6]
+                    .iter()
+                    .try_fold(0u16, |code, &b| hex_value(b).map(|v| (code << 4) | v));
+
+                match code {
+                    Some(code) if code < 0x80 => (code as u8, 6),
+                    Some(_) => return Err(invalid_data("a non-ASCII character in base64 data")),
+                    None => return Err(invalid_data("an invalid unicode escape in a JSON string")),
+                }
+            },
+            _ => return Err(invalid_data("an invalid escape in a JSON string")),
+        };
+
+        self.offset += length;
+
+        Ok(Some(b))
+    }
+}
+
+impl<R: Read> Read for JsonStringReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.state == State::Start {
+            match self.pending.get(self.offset) {
+                Some(b' ' | b'\t' | b'\n' | b'\r') => self.offset += 1,
+                Some(b'"') => {
+                    self.offset += 1;
+                    self.state = State::Inside;
+                },
+                Some(_) => return Err(invalid_data("expected a JSON string")),
+                None => self.fill()?,
+            }
+        }
+
+        let mut written = 0;
+
+        while self.state == State::Inside && written < buf.len() {
+            let Some(&b) = self.pending.get(self.offset) else {
+                if written > 0 {
+                    break;
+                }
+
+                self.fill()?;
+
+                continue;
+            };
+
+            match b {
+                b'"' => {
+                    self.offset += 1;
+                    self.state = State::Ended;
+                },
+                b'\\' => match self.unescape()? {
+                    Some(b'\n' | b'\r') => (),
+                    Some(b) => {
+                        buf[written] = b;
+                        written += 1;
+                    },
+                    None => {
+                        if written > 0 {
+                            break;
+                        }
+
+                        self.fill()?;
+                    },
+                },
+                0x00..=0x1F => return Err(invalid_data("a control character in a JSON string")),
+                _ => {
+                    self.offset += 1;
+
+                    buf[written] = b;
+                    written += 1;
+                },
+            }
+        }
+
+        Ok(written)
+    }
+}
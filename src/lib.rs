@@ -52,6 +52,24 @@ writer.finish().unwrap();
 assert_eq!("SGkgdGhlcmUsIHRoaXMgaXMgYSBzaW1wbGUgc2VudGVuY2UgdXNlZCBmb3IgdGVzdGluZyB0aGlzIGNyYXRlLiBJIGhvcGUgYWxsIGNhc2VzIGFyZSBjb3JyZWN0Lg==", fs::read_to_string(file_path).unwrap());
 ```
 
+#### ToBase64FmtWriter
+
+```rust
+use std::{fmt::Write as _, io::Write};
+
+use base64_stream::{Base64Display, ToBase64FmtWriter};
+
+let mut writer = ToBase64FmtWriter::new(String::from("data: "));
+
+writer.write_all(b"Hi there").unwrap();
+
+let mut s = writer.finish().unwrap();
+
+write!(s, ", {}", Base64Display::new(b"Hi there".as_slice())).unwrap();
+
+assert_eq!("data: SGkgdGhlcmU=, SGkgdGhlcmU=", s);
+```
+
 ### Decode
 
 #### FromBase64Reader
@@ -101,6 +119,76 @@ writer.finish().unwrap();
 assert_eq!("Hi there, this is a simple sentence used for testing this crate. I hope all cases are correct.", fs::read_to_string(file_path).unwrap());
 ```
 
+#### Detect the Variant
+
+`FromBase64Reader` can detect whether the data uses the standard or the URL-safe alphabet, with or without padding.
+
+```rust
+use std::io::{Cursor, Read};
+
+use base64_stream::{Base64Variant, FromBase64Reader};
+
+let mut reader = FromBase64Reader::new(Cursor::new(b"Pz8_Pz4-Pj4".to_vec())).with_auto_detect();
+
+let mut test_data = String::new();
+
+reader.read_to_string(&mut test_data).unwrap();
+
+assert_eq!("????>>>>", test_data);
+assert_eq!(Some(Base64Variant::UrlSafeNoPad), reader.detected_variant());
+```
+
+### Transcode
+
+#### TranscodeBase64Reader
+
+```rust
+use std::io::{Cursor, Read};
+
+use base64_stream::{
+    base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    TranscodeBase64Reader,
+};
+
+let base64 = b"Pz8/Pz4+Pj4=".to_vec();
+
+let mut reader = TranscodeBase64Reader::new(Cursor::new(base64), &STANDARD, &URL_SAFE_NO_PAD);
+
+let mut url_safe = String::new();
+
+reader.read_to_string(&mut url_safe).unwrap();
+
+assert_eq!("Pz8_Pz4-Pj4", url_safe);
+```
+
+#### TranscodeBase64Writer
+
+```rust
+use std::io::Write;
+
+use base64_stream::{
+    base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    LineEnding, TranscodeBase64Writer,
+};
+
+let mut writer = TranscodeBase64Writer::new(Vec::new(), &URL_SAFE_NO_PAD, &STANDARD)
+    .with_line_wrap(8, LineEnding::CRLF);
+
+writer.write_all(b"Pz8_Pz4-Pj4").unwrap();
+
+assert_eq!(b"Pz8/Pz4+\r\nPj4=".as_ref(), writer.finish().unwrap());
+```
+
+Line breaks in the source are skipped, and errors are reported at their offsets in the source.
+
+## Digest of the Plain Data
+
+With the `digest` feature enabled, `with_digest` feeds the plain side of any of the above structs into a [`digest`](https://crates.io/crates/digest) hasher, such as `sha2::Sha256`. The writers return the hash from `finish_with_digest`, and every struct returns the hash of the data so far from `into_parts`.
+
+## Base58Check
+
+With the `base58check` feature enabled, `with_check` makes `ToBase58Writer` append and `FromBase58Reader` verify the Base58Check checksum, which is hashed by the [`sha2`](https://crates.io/crates/sha2) crate.
+
 ## Change the Buffer Size
 
 The default buffer size is 4096 bytes. If you want to change that, you can use the `new2` associated function and define a length explicitly to create an instance of the above structs.
@@ -122,16 +210,147 @@ reader.read_to_string(&mut base64).unwrap();
 
 assert_eq!("SGkgdGhlcmUsIHRoaXMgaXMgYSBzaW1wbGUgc2VudGVuY2UgdXNlZCBmb3IgdGVzdGluZyB0aGlzIGNyYXRlLiBJIGhvcGUgYWxsIGNhc2VzIGFyZSBjb3JyZWN0Lg==", base64);
 ```
+
+## Serde
+
+Enable the `serde` feature to serialize and deserialize fields as base64 strings with `#[serde(with = "base64_stream::serde")]`. The data are encoded chunk by chunk, so a serializer which streams strings, such as `serde_json`, never holds the whole base64 string.
+
+## Command-line Tool
+
+Enable the `cli` feature to build the `base64-stream` binary, which works like the `base64` command of coreutils. It supports `-d`, `-i`, `-w COLS`, and additionally `--url` and `--no-pad`.
+
+```text
+cargo install base64-stream --features cli
+
+echo -n foobar | base64-stream --url -w 0
+```
 */
 
 pub extern crate base64;
-
+#[cfg(feature = "digest")]
+pub extern crate digest;
+
+mod alphabet;
+mod base45;
+mod base45_error;
+mod base58;
+mod base58_alphabet;
+mod base58_error;
+mod base64_alphabet;
+mod base64_iter;
+mod base64_variant;
+mod bech32;
+mod bech32_error;
+mod bech32_variant;
+mod bit_regrouper;
+mod crc32;
+mod encoded_word_error;
+mod encoded_words;
+mod from_base45_reader;
+mod from_base45_writer;
+mod from_base58_reader;
+mod from_base64_json_reader;
 mod from_base64_reader;
 mod from_base64_writer;
+mod from_bech32_reader;
+mod from_quoted_printable_reader;
+mod from_quoted_printable_writer;
+mod from_radix_reader;
+mod from_radix_writer;
+mod from_uuencode_reader;
+mod from_yenc_reader;
+mod json_string_reader;
+mod limit_exceeded;
+mod multipart_reader;
+mod multipart_writer;
+mod pending_bytes;
+mod plain_digest;
+mod progress;
+mod pushback_reader;
+mod quoted_printable;
+mod quoted_printable_error;
+mod radix;
+mod radix_engine;
+mod radix_error;
+mod terminator;
+mod to_base45_reader;
+mod to_base45_writer;
+mod to_base58_writer;
+mod to_base64_fmt_writer;
+mod to_base64_json_writer;
 mod to_base64_reader;
 mod to_base64_writer;
-
+mod to_bech32_writer;
+mod to_encoded_word_writer;
+mod to_quoted_printable_reader;
+mod to_quoted_printable_writer;
+mod to_radix_reader;
+mod to_radix_writer;
+mod to_uuencode_writer;
+mod to_yenc_writer;
+mod transcode_base64_reader;
+mod transcode_base64_writer;
+mod transcoder;
+mod uuencode_variant;
+mod yenc;
+mod yenc_error;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+pub use base45_error::Base45Error;
+pub use base58_alphabet::Base58Alphabet;
+pub use base58_error::Base58Error;
+pub use base64_alphabet::Base64Alphabet;
+pub use base64_iter::*;
+pub use base64_variant::*;
+pub use bech32_error::Bech32Error;
+pub use bech32_variant::Bech32Variant;
+pub use encoded_word_error::EncodedWordError;
+pub use encoded_words::*;
+pub use from_base45_reader::*;
+pub use from_base45_writer::*;
+pub use from_base58_reader::*;
+pub use from_base64_json_reader::*;
 pub use from_base64_reader::*;
 pub use from_base64_writer::*;
+pub use from_bech32_reader::*;
+pub use from_quoted_printable_reader::*;
+pub use from_quoted_printable_writer::*;
+pub use from_radix_reader::*;
+pub use from_radix_writer::*;
+pub use from_uuencode_reader::*;
+pub use from_yenc_reader::*;
+pub use limit_exceeded::LimitExceeded;
+pub use multipart_reader::*;
+pub use multipart_writer::*;
+pub use pending_bytes::PendingBytes;
+#[cfg(feature = "digest")]
+pub use plain_digest::DigestOutput;
+pub use progress::Progress;
+pub use pushback_reader::PushbackReader;
+pub use quoted_printable_error::QuotedPrintableError;
+pub use radix_engine::RadixEngine;
+pub use radix_error::RadixError;
+pub use terminator::Terminator;
+pub use to_base45_reader::*;
+pub use to_base45_writer::*;
+pub use to_base58_writer::*;
+pub use to_base64_fmt_writer::*;
+pub use to_base64_json_writer::*;
 pub use to_base64_reader::*;
 pub use to_base64_writer::*;
+pub use to_bech32_writer::*;
+pub use to_encoded_word_writer::*;
+pub use to_quoted_printable_reader::*;
+pub use to_quoted_printable_writer::*;
+pub use to_radix_reader::*;
+pub use to_radix_writer::*;
+pub use to_uuencode_writer::*;
+pub use to_yenc_writer::*;
+pub use transcode_base64_reader::*;
+pub use transcode_base64_writer::*;
+pub use transcoder::LineEnding;
+pub use uuencode_variant::UuencodeVariant;
+pub use yenc::{YencHeader, YencPart, YencTrailer};
+pub use yenc_error::YencError;
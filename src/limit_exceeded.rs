@@ -0,0 +1,26 @@
+use std::{error::Error, fmt};
+
+/// The error returned by [`FromBase64Reader`](crate::FromBase64Reader), the Base58 adapters and [`FromBech32Reader`](crate::FromBech32Reader) once the data exceed one of their limits. It is wrapped in an [`io::Error`](std::io::Error) of the kind [`Other`](std::io::ErrorKind::Other).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitExceeded {
+    /// More decoded bytes than this limit were about to be returned, or to be taken by an encoder.
+    Decoded(u64),
+    /// More encoded bytes than this limit have been read from the inner reader.
+    Encoded(u64),
+}
+
+impl fmt::Display for LimitExceeded {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LimitExceeded::Decoded(limit) => {
+                write!(f, "the decoded data exceed the limit of {limit} bytes")
+            },
+            LimitExceeded::Encoded(limit) => {
+                write!(f, "the encoded data exceed the limit of {limit} bytes")
+            },
+        }
+    }
+}
+
+impl Error for LimitExceeded {}
@@ -0,0 +1,467 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use crate::{
+    FromBase64Reader, FromQuotedPrintableReader, PushbackReader, multipart_writer::assert_boundary,
+};
+
+/// Bytes read from the inner reader per step.
+const CHUNK_SIZE: usize = 4096;
+/// The maximum total length of the header lines of a part.
+const MAX_HEADER_LENGTH: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Reading the preamble or the body of a part.
+    Body,
+    /// Right after a delimiter.
+    Delimiter,
+    /// After the close delimiter.
+    Done,
+}
+
+/// Read a MIME multipart body part by part.
+///
+/// The parts are read through [`next_part`](Self::next_part) in order. Only the headers of a part and a few bytes around the delimiters are buffered, never a whole part.
+pub struct MultipartReader<R: Read> {
+    inner:         R,
+    delimiter:     Vec<u8>,
+    buf:           Vec<u8>,
+    offset:        usize,
+    eof:           bool,
+    state:         State,
+    header_length: usize,
+}
+
+impl<R: Read> fmt::Debug for MultipartReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultipartReader")
+            .field("delimiter", &String::from_utf8_lossy(&self.delimiter))
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> MultipartReader<R> {
+    /// Creates a reader of the parts separated by `boundary`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `boundary` is not a valid RFC 2046 boundary.
+    #[inline]
+    pub fn new(reader: R, boundary: &str) -> MultipartReader<R> {
+        assert_boundary(boundary);
+
+        let mut delimiter = b"\n--".to_vec();
+
+        delimiter.extend_from_slice(boundary.as_bytes());
+
+        MultipartReader {
+            inner: reader,
+            delimiter,
+            // the preamble is read like a body, so a line break in front lets a first delimiter at the very start be found like the others
+            buf: vec![b'\n'],
+            offset: 0,
+            eof: false,
+            state: State::Body,
+            header_length: 0,
+        }
+    }
+
+    /// Reads more of the inner reader into the buffer.
+    fn fill(&mut self) -> Result<(), io::Error> {
+        self.buf.drain(..self.offset);
+        self.offset = 0;
+
+        let length = self.buf.len();
+
+        self.buf.resize(length + CHUNK_SIZE, 0);
+
+        match self.inner.read(&mut self.buf[length..]) {
+            Ok(c) => {
+                self.buf.truncate(length + c);
+
+                if c == 0 {
+                    self.eof = true;
+                }
+
+                Ok(())
+            },
+            Err(error) => {
+                self.buf.truncate(length);
+
+                Err(error)
+            },
+        }
+    }
+
+    /// Reads the current body into `buf` and returns 0 once its delimiter has been consumed.
+    fn read_body(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if self.state != State::Body || buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let pending = &self.buf[self.offset..];
+
+            let (end, delimiter_start) = match find_delimiter(pending, &self.delimiter, self.eof) {
+                // the line break in front of a delimiter belongs to the delimiter
+                Some((p, true)) if p > 0 && pending[p - 1] == b'\r' => (p - 1, Some(p)),
+                Some((p, true)) => (p, Some(p)),
+                // the rest of the line tells whether it is a delimiter
+                Some((p, false)) if p > 0 && pending[p - 1] == b'\r' => (p - 1, None),
+                Some((p, false)) => (p, None),
+                None if self.eof => {
+                    return Err(io::Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "the multipart body ends without a close delimiter",
+                    ));
+                },
+                // the delimiter and the CR in front of it may begin in the last bytes
+                None => (pending.len().saturating_sub(self.delimiter.len()), None),
+            };
+
+            if end > 0 {
+                let c = end.min(buf.len());
+
+                buf[..c].copy_from_slice(&pending[..c]);
+
+                self.offset += c;
+
+                return Ok(c);
+            }
+
+            if let Some(delimiter_start) = delimiter_start {
+                self.offset += delimiter_start + self.delimiter.len();
+                self.state = State::Delimiter;
+
+                return Ok(0);
+            }
+
+            self.fill()?;
+        }
+    }
+
+    /// Takes a line without its line break. At EOF, the rest is taken as the last line if `eof_allowed` is `true`.
+    fn take_line(&mut self, eof_allowed: bool) -> Result<Vec<u8>, io::Error> {
+        loop {
+            let pending = &self.buf[self.offset..];
+
+            if let Some(p) = pending.iter().position(|&b| b == b'\n') {
+                let mut line = pending[..p].to_vec();
+
+                self.offset += p + 1;
+
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+
+                self.header_length += p + 1;
+
+                return Ok(line);
+            }
+
+            if self.header_length + pending.len() > MAX_HEADER_LENGTH {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    "the headers of a multipart part are too long",
+                ));
+            }
+
+            if self.eof {
+                if eof_allowed {
+                    let line = pending.to_vec();
+
+                    self.offset = self.buf.len();
+
+                    return Ok(line);
+                }
+
+                return Err(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "the multipart body ends in the headers of a part",
+                ));
+            }
+
+            self.fill()?;
+        }
+    }
+
+    /// Reads the header lines of a part up to the empty line after them.
+    fn read_headers(&mut self) -> Result<Vec<(String, String)>, io::Error> {
+        let mut headers: Vec<(String, String)> = Vec::new();
+
+        loop {
+            let line = self.take_line(false)?;
+
+            if line.is_empty() {
+                return Ok(headers);
+            }
+
+            let line = String::from_utf8_lossy(&line);
+
+            if line.starts_with([' ', '\t']) {
+                // a folded line continues the previous header
+                match headers.last_mut() {
+                    Some((_, value)) => {
+                        value.push(' ');
+                        value.push_str(line.trim());
+                    },
+                    None => {
+                        return Err(io::Error::new(
+                            ErrorKind::InvalidData,
+                            "a multipart part starts with a folded header line",
+                        ));
+                    },
+                }
+
+                continue;
+            }
+
+            match line.split_once(':') {
+                Some((name, value)) => {
+                    headers.push((name.trim().to_string(), value.trim().to_string()))
+                },
+                None => {
+                    return Err(io::Error::new(
+                        ErrorKind::InvalidData,
+                        "invalid header line in a multipart part",
+                    ));
+                },
+            }
+        }
+    }
+
+    /// Skips the rest of the current part, or the preamble, and returns the next part. Returns `None` after the close delimiter.
+    pub fn next_part(&mut self) -> Result<Option<Part<'_, R>>, io::Error> {
+        let mut scratch = [0; 1024];
+
+        while self.read_body(&mut scratch)? > 0 {}
+
+        if self.state == State::Done {
+            return Ok(None);
+        }
+
+        self.header_length = 0;
+
+        let line = self.take_line(true)?;
+
+        if line.starts_with(b"--") {
+            self.state = State::Done;
+
+            return Ok(None);
+        }
+
+        if !line.iter().all(|&b| b == b' ' || b == b'\t') {
+            return Err(io::Error::new(ErrorKind::InvalidData, "invalid multipart delimiter line"));
+        }
+
+        let headers = self.read_headers()?;
+
+        self.state = State::Body;
+
+        let encoding = header(&headers, "Content-Transfer-Encoding").unwrap_or_default();
+
+        let body = PartBody {
+            reader: self
+        };
+
+        let body = if encoding.eq_ignore_ascii_case("base64") {
+            PartReader::Base64(Box::new(FromBase64Reader::new(WhitespaceFilter(body))))
+        } else if encoding.eq_ignore_ascii_case("quoted-printable") {
+            PartReader::QuotedPrintable(Box::new(FromQuotedPrintableReader::new(body)))
+        } else {
+            PartReader::Plain(body)
+        };
+
+        Ok(Some(Part {
+            headers,
+            body,
+        }))
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns a reader of the data which have not been parsed yet, such as the epilogue after the close delimiter, consuming this wrapper.
+    #[inline]
+    pub fn into_rest(mut self) -> PushbackReader<R> {
+        self.buf.drain(..self.offset);
+
+        PushbackReader::new(self.buf, self.inner)
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// Finds the first delimiter in `pending` which is followed by `--` or by a line of optional whitespace, like RFC 2046 requires, skipping body lines which only begin like one. Returns its position along with `false` if the bytes after it are not enough to tell yet.
+fn find_delimiter(pending: &[u8], delimiter: &[u8], eof: bool) -> Option<(usize, bool)> {
+    let mut from = 0;
+
+    while let Some(p) =
+        pending[from..].windows(delimiter.len()).position(|window| window == delimiter)
+    {
+        let p = from + p;
+
+        match is_delimiter_end(&pending[p + delimiter.len()..], eof) {
+            Some(true) => return Some((p, true)),
+            Some(false) => from = p + 1,
+            None => return Some((p, false)),
+        }
+    }
+
+    None
+}
+
+/// Returns whether `rest`, the bytes after a delimiter, end it, or `None` if they are not enough to tell.
+fn is_delimiter_end(rest: &[u8], eof: bool) -> Option<bool> {
+    if rest.starts_with(b"--") {
+        return Some(true);
+    }
+
+    for (i, &b) in rest.iter().enumerate() {
+        match b {
+            b' ' | b'\t' => (),
+            b'-' if i == 0 && rest.len() == 1 => break,
+            b'\n' => return Some(true),
+            b'\r' => match rest.get(i + 1) {
+                Some(&b) => return Some(b == b'\n'),
+                None => break,
+            },
+            _ => return Some(false),
+        }
+    }
+
+    // the input ends in the delimiter line, which is left to the parsing after it
+    if eof { Some(true) } else { None }
+}
+
+/// Finds the value of the header `name`, ignoring case.
+#[inline]
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+}
+
+/// Reads the raw body of the current part of a [`MultipartReader`].
+struct PartBody<'a, R: Read> {
+    reader: &'a mut MultipartReader<R>,
+}
+
+impl<R: Read> Read for PartBody<'_, R> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        self.reader.read_body(buf)
+    }
+}
+
+/// Drops the line breaks and other whitespace inside base64 bodies.
+struct WhitespaceFilter<R: Read>(R);
+
+impl<R: Read> Read for WhitespaceFilter<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        loop {
+            let c = self.0.read(buf)?;
+
+            if c == 0 {
+                return Ok(0);
+            }
+
+            let mut length = 0;
+
+            for i in 0..c {
+                if !buf[i].is_ascii_whitespace() {
+                    buf[length] = buf[i];
+                    length += 1;
+                }
+            }
+
+            if length > 0 {
+                return Ok(length);
+            }
+        }
+    }
+}
+
+enum PartReader<'a, R: Read> {
+    Plain(PartBody<'a, R>),
+    Base64(Box<FromBase64Reader<WhitespaceFilter<PartBody<'a, R>>>>),
+    QuotedPrintable(Box<FromQuotedPrintableReader<PartBody<'a, R>>>),
+}
+
+/// A part read by a [`MultipartReader`].
+///
+/// Reading it returns the body of the part, decoded if its `Content-Transfer-Encoding` is `base64` or `quoted-printable` and as it is otherwise.
+pub struct Part<'a, R: Read> {
+    headers: Vec<(String, String)>,
+    body:    PartReader<'a, R>,
+}
+
+impl<R: Read> fmt::Debug for Part<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Part")
+            .field("headers", &self.headers)
+            .field("is_base64", &self.is_base64())
+            .field("is_quoted_printable", &self.is_quoted_printable())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> Part<'_, R> {
+    /// Returns the headers of this part in order, with folded lines joined. Bytes which are not valid UTF-8 are replaced with U+FFFD.
+    #[inline]
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the value of the first header named `name`, ignoring case.
+    #[inline]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+
+    /// Returns the value of the `Content-Type` header.
+    #[inline]
+    pub fn content_type(&self) -> Option<&str> {
+        self.header("Content-Type")
+    }
+
+    /// Returns whether the body of this part is decoded from base64.
+    #[inline]
+    pub fn is_base64(&self) -> bool {
+        matches!(self.body, PartReader::Base64(_))
+    }
+
+    /// Returns whether the body of this part is decoded from quoted-printable.
+    #[inline]
+    pub fn is_quoted_printable(&self) -> bool {
+        matches!(self.body, PartReader::QuotedPrintable(_))
+    }
+}
+
+impl<R: Read> Read for Part<'_, R> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        match &mut self.body {
+            PartReader::Plain(reader) => reader.read(buf),
+            PartReader::Base64(reader) => reader.read(buf),
+            PartReader::QuotedPrintable(reader) => reader.read(buf),
+        }
+    }
+}
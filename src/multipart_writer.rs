@@ -0,0 +1,259 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, IoSlice, Write},
+};
+
+use crate::{LineEnding, ToBase64Writer};
+
+/// The line length of base64 bodies, as required by RFC 2045.
+const BODY_LINE_LENGTH: usize = 76;
+
+/// Returns whether `b` may appear in a boundary (RFC 2046 `bchars`).
+#[inline]
+fn is_boundary_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"'()+_,-./:=? ".contains(&b)
+}
+
+/// Panics if `boundary` is not a valid RFC 2046 boundary.
+#[inline]
+pub(crate) fn assert_boundary(boundary: &str) {
+    assert!(
+        (1..=70).contains(&boundary.len())
+            && boundary.bytes().all(is_boundary_char)
+            && !boundary.ends_with(' '),
+        "invalid multipart boundary {boundary:?}"
+    );
+}
+
+/// Returns whether `b` may appear unescaped in an RFC 2231 extended parameter value.
+#[inline]
+fn is_attr_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&b)
+}
+
+#[inline]
+fn check_header_value(value: &str) -> Result<(), io::Error> {
+    if value.bytes().any(|b| b == b'\r' || b == b'\n') {
+        Err(io::Error::new(ErrorKind::InvalidInput, "a header value contains a line break"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns an `attachment` disposition for `filename`, as a quoted string if it is printable ASCII, or RFC 2231 encoded otherwise.
+fn attachment_disposition(filename: &str) -> String {
+    let mut disposition = String::from("attachment; ");
+
+    if filename.bytes().all(|b| (b' '..=b'~').contains(&b)) {
+        disposition.push_str("filename=\"");
+
+        for c in filename.chars() {
+            if c == '"' || c == '\\' {
+                disposition.push('\\');
+            }
+
+            disposition.push(c);
+        }
+
+        disposition.push('"');
+    } else {
+        disposition.push_str("filename*=UTF-8''");
+
+        for b in filename.bytes() {
+            if is_attr_char(b) {
+                disposition.push(char::from(b));
+            } else {
+                disposition.push_str(&format!("%{b:02X}"));
+            }
+        }
+    }
+
+    disposition
+}
+
+/// Write a MIME multipart body whose parts are encoded to base64.
+///
+/// Every part gets `Content-Type`, `Content-Transfer-Encoding: base64` and optionally `Content-Disposition` headers, and its data are wrapped every 76 symbols with CRLF. The `Content-Type: multipart/...; boundary=...` header of the whole body is left to the caller.
+pub struct MultipartWriter<W: Write> {
+    inner:      W,
+    boundary:   String,
+    part_count: usize,
+    part_open:  bool,
+}
+
+impl<W: Write> fmt::Debug for MultipartWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultipartWriter")
+            .field("boundary", &self.boundary)
+            .field("part_count", &self.part_count)
+            .field("part_open", &self.part_open)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> MultipartWriter<W> {
+    /// Creates a writer which separates the parts with `boundary`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `boundary` is not a valid RFC 2046 boundary, i.e. 1 to 70 characters of digits, letters, spaces and `'()+_,-./:=?`, not ending with a space.
+    #[inline]
+    pub fn new<S: Into<String>>(writer: W, boundary: S) -> MultipartWriter<W> {
+        let boundary = boundary.into();
+
+        assert_boundary(&boundary);
+
+        MultipartWriter {
+            inner: writer,
+            boundary,
+            part_count: 0,
+            part_open: false,
+        }
+    }
+
+    /// Returns the boundary between the parts.
+    #[inline]
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Writes the delimiter and the headers of a new part, and returns a writer which encodes the data of the part.
+    ///
+    /// The previous part must have been finished with [`PartWriter::finish`].
+    pub fn start_part(
+        &mut self,
+        content_type: &str,
+        content_disposition: Option<&str>,
+    ) -> Result<PartWriter<'_, W>, io::Error> {
+        if self.part_open {
+            return Err(io::Error::other("the previous part is not finished"));
+        }
+
+        check_header_value(content_type)?;
+
+        if let Some(content_disposition) = content_disposition {
+            check_header_value(content_disposition)?;
+        }
+
+        if self.part_count > 0 {
+            self.inner.write_all(b"\r\n")?;
+        }
+
+        write!(
+            self.inner,
+            "--{}\r\nContent-Type: {content_type}\r\nContent-Transfer-Encoding: base64\r\n",
+            self.boundary
+        )?;
+
+        if let Some(content_disposition) = content_disposition {
+            write!(self.inner, "Content-Disposition: {content_disposition}\r\n")?;
+        }
+
+        self.inner.write_all(b"\r\n")?;
+
+        self.part_count += 1;
+        self.part_open = true;
+
+        Ok(PartWriter {
+            inner: ToBase64Writer::new2(&mut self.inner)
+                .with_line_wrap(BODY_LINE_LENGTH, LineEnding::CRLF),
+            open:  &mut self.part_open,
+        })
+    }
+
+    /// Starts a part with an `attachment` disposition for `filename`. Non-ASCII file names are encoded as in RFC 2231.
+    #[inline]
+    pub fn start_attachment(
+        &mut self,
+        content_type: &str,
+        filename: &str,
+    ) -> Result<PartWriter<'_, W>, io::Error> {
+        self.start_part(content_type, Some(&attachment_disposition(filename)))
+    }
+
+    /// Returns the number of parts started so far.
+    #[inline]
+    pub fn part_count(&self) -> usize {
+        self.part_count
+    }
+
+    /// Writes the close delimiter and returns the inner writer.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        if self.part_open {
+            return Err(io::Error::other("the last part is not finished"));
+        }
+
+        if self.part_count > 0 {
+            self.inner.write_all(b"\r\n")?;
+        }
+
+        write!(self.inner, "--{}--\r\n", self.boundary)?;
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the structure written by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without writing the close delimiter.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Write the plain data of a part of a [`MultipartWriter`] and encode them to base64.
+///
+/// The part must be closed with [`finish`](Self::finish), otherwise the last buffered bytes are lost and the multipart writer refuses to continue.
+pub struct PartWriter<'a, W: Write> {
+    inner: ToBase64Writer<&'a mut W>,
+    open:  &'a mut bool,
+}
+
+impl<W: Write> fmt::Debug for PartWriter<'_, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartWriter").field("inner", &self.inner).finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> PartWriter<'_, W> {
+    /// Finishes encoding buffered data and closes this part.
+    #[inline]
+    pub fn finish(self) -> Result<(), io::Error> {
+        self.inner.finish()?;
+
+        *self.open = false;
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for PartWriter<'_, W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.inner.write(buf)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, io::Error> {
+        self.inner.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "digest")]
+use crate::DigestOutput;
+use crate::plain_digest::PlainDigest;
+
+/// Bytes an adapter has taken in but not given out yet, returned by its `into_parts`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PendingBytes {
+    /// Plain bytes which have not been emitted yet.
+    pub plain:   Vec<u8>,
+    /// Base64 bytes which have not been emitted yet.
+    pub encoded: Vec<u8>,
+    /// The digest of the plain data which have passed through so far, or `None` if `with_digest` has not been called.
+    #[cfg(feature = "digest")]
+    pub digest:  Option<DigestOutput>,
+}
+
+impl PendingBytes {
+    #[inline]
+    pub(crate) fn new(plain: Vec<u8>, encoded: Vec<u8>, digest: PlainDigest) -> PendingBytes {
+        #[cfg(not(feature = "digest"))]
+        let _ = digest;
+
+        PendingBytes {
+            plain,
+            encoded,
+            #[cfg(feature = "digest")]
+            digest: digest.finalize(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(feature = "digest")]
+use digest::DynDigest;
+
+/// The finalized digest of plain data.
+#[cfg(feature = "digest")]
+pub type DigestOutput = Box<[u8]>;
+
+/// The optional digest of the plain data passing through an adapter. It is zero-sized without the `digest` feature.
+#[derive(Default)]
+pub(crate) struct PlainDigest {
+    #[cfg(feature = "digest")]
+    inner: Option<Box<dyn DynDigest + Send + Sync>>,
+}
+
+#[cfg(feature = "digest")]
+impl PlainDigest {
+    #[inline]
+    pub(crate) fn new<D: DynDigest + Send + Sync + 'static>(digest: D) -> PlainDigest {
+        PlainDigest {
+            inner: Some(Box::new(digest))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        if let Some(inner) = self.inner.as_mut() {
+            inner.update(data);
+        }
+    }
+
+    /// Returns the digest of the data so far, or `None` if no digest has been set.
+    #[inline]
+    pub(crate) fn finalize(self) -> Option<DigestOutput> {
+        self.inner.map(|inner| inner.finalize())
+    }
+}
+
+#[cfg(not(feature = "digest"))]
+impl PlainDigest {
+    #[inline]
+    pub(crate) fn update(&mut self, _data: &[u8]) {}
+}
@@ -0,0 +1,58 @@
+/// The numbers of bytes which have passed through an adapter on its plain side and on its base64 side.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Progress {
+    /// Plain bytes read or written.
+    pub plain:   u64,
+    /// Base64 bytes read or written.
+    pub encoded: u64,
+}
+
+type ProgressCallback = Box<dyn FnMut(Progress) + Send>;
+
+/// Counts the bytes passing through an adapter and reports them to an optional callback.
+#[derive(Default)]
+pub(crate) struct ProgressCounter {
+    progress: Progress,
+    interval: u64,
+    next:     u64,
+    callback: Option<ProgressCallback>,
+}
+
+impl ProgressCounter {
+    #[inline]
+    pub(crate) fn set_callback<F: FnMut(Progress) + Send + 'static>(
+        &mut self,
+        interval: u64,
+        callback: F,
+    ) {
+        assert!(interval > 0, "interval must be greater than 0");
+
+        self.interval = interval;
+        self.next = (self.progress.plain / interval + 1) * interval;
+        self.callback = Some(Box::new(callback));
+    }
+
+    #[inline]
+    pub(crate) fn progress(&self) -> Progress {
+        self.progress
+    }
+
+    /// Counts `length` plain bytes and calls the callback if another interval has been reached.
+    #[inline]
+    pub(crate) fn add_plain(&mut self, length: usize) {
+        self.progress.plain += length as u64;
+
+        if let Some(callback) = self.callback.as_mut() {
+            if self.progress.plain >= self.next {
+                self.next = (self.progress.plain / self.interval + 1) * self.interval;
+
+                callback(self.progress);
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add_encoded(&mut self, length: usize) {
+        self.progress.encoded += length as u64;
+    }
+}
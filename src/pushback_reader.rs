@@ -0,0 +1,66 @@
+use std::io::{self, Read};
+
+/// A reader which returns some bytes pushed back in front of an inner reader before reading it.
+#[derive(Debug)]
+pub struct PushbackReader<R: Read> {
+    pushback:        Vec<u8>,
+    pushback_offset: usize,
+    inner:           R,
+}
+
+impl<R: Read> PushbackReader<R> {
+    #[inline]
+    pub fn new(pushback: Vec<u8>, reader: R) -> PushbackReader<R> {
+        PushbackReader {
+            pushback,
+            pushback_offset: 0,
+            inner: reader,
+        }
+    }
+
+    /// Returns the pushed back bytes which have not been read yet.
+    #[inline]
+    pub fn pushback(&self) -> &[u8] {
+        &self.pushback[self.pushback_offset..]
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the pushed back bytes.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader along with the pushed back bytes which have not been read yet, consuming this wrapper.
+    #[inline]
+    pub fn into_parts(mut self) -> (R, Vec<u8>) {
+        self.pushback.drain(..self.pushback_offset);
+
+        (self.inner, self.pushback)
+    }
+}
+
+impl<R: Read> Read for PushbackReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        let pushback = &self.pushback[self.pushback_offset..];
+
+        if pushback.is_empty() {
+            return self.inner.read(buf);
+        }
+
+        let c = pushback.len().min(buf.len());
+
+        buf[..c].copy_from_slice(&pushback[..c]);
+
+        self.pushback_offset += c;
+
+        Ok(c)
+    }
+}
@@ -0,0 +1,266 @@
+use std::mem;
+
+use crate::{LineEnding, QuotedPrintableError};
+
+/// The maximum length of an encoded line, including the `=` of a soft line break.
+const MAX_LINE_LENGTH: usize = 76;
+/// The longest run of spaces and tabs accepted while decoding, i.e. the line length limit of RFC 5322.
+const MAX_WHITESPACE_LENGTH: usize = 998;
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+#[inline]
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        _ => None,
+    }
+}
+
+/// Encodes plain bytes to quoted-printable data (RFC 2045), keeping the state between calls.
+#[derive(Debug, Clone)]
+pub(crate) struct QuotedPrintableEncoder {
+    line_ending: LineEnding,
+    binary:      bool,
+    column:      usize,
+    whitespace:  Option<u8>,
+    after_cr:    bool,
+}
+
+impl QuotedPrintableEncoder {
+    #[inline]
+    pub(crate) fn new() -> QuotedPrintableEncoder {
+        QuotedPrintableEncoder {
+            line_ending: LineEnding::CRLF,
+            binary:      false,
+            column:      0,
+            whitespace:  None,
+            after_cr:    false,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    #[inline]
+    pub(crate) fn set_binary(&mut self) {
+        self.binary = true;
+    }
+
+    /// Appends `token` to the current line, or to a new line after a soft line break if it does not fit.
+    fn push(&mut self, token: &[u8], output: &mut Vec<u8>) {
+        // leave room for the `=` of a soft line break
+        if self.column + token.len() > MAX_LINE_LENGTH - 1 {
+            output.push(b'=');
+            output.extend_from_slice(self.line_ending.as_bytes());
+
+            self.column = 0;
+        }
+
+        output.extend_from_slice(token);
+
+        self.column += token.len();
+    }
+
+    #[inline]
+    fn push_escaped(&mut self, b: u8, output: &mut Vec<u8>) {
+        self.push(&[b'=', HEX[usize::from(b >> 4)], HEX[usize::from(b & 0xF)]], output);
+    }
+
+    /// Writes the held space or tab, escaped if it ends a line.
+    #[inline]
+    fn flush_whitespace(&mut self, at_line_end: bool, output: &mut Vec<u8>) {
+        if let Some(b) = self.whitespace.take() {
+            if at_line_end {
+                self.push_escaped(b, output);
+            } else {
+                self.push(&[b], output);
+            }
+        }
+    }
+
+    /// Encodes `input` and appends the result to `output`.
+    pub(crate) fn encode(&mut self, input: &[u8], output: &mut Vec<u8>) {
+        for &b in input {
+            // the LF of a CRLF has already been written with the CR
+            if mem::take(&mut self.after_cr) && b == b'\n' {
+                continue;
+            }
+
+            match b {
+                b'\r' | b'\n' if !self.binary => {
+                    self.flush_whitespace(true, output);
+
+                    output.extend_from_slice(self.line_ending.as_bytes());
+
+                    self.column = 0;
+                    self.after_cr = b == b'\r';
+                },
+                b' ' | b'\t' => {
+                    // whether a space or tab can be written as it is depends on what follows it
+                    self.flush_whitespace(false, output);
+
+                    self.whitespace = Some(b);
+                },
+                b'!'..=b'~' if b != b'=' => {
+                    self.flush_whitespace(false, output);
+
+                    self.push(&[b], output);
+                },
+                _ => {
+                    self.flush_whitespace(false, output);
+
+                    self.push_escaped(b, output);
+                },
+            }
+        }
+    }
+
+    /// Appends what is held back for the end of the data to `output`.
+    #[inline]
+    pub(crate) fn finish(&mut self, output: &mut Vec<u8>) {
+        self.flush_whitespace(true, output);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DecodeState {
+    Text,
+    /// After a CR in the text.
+    Cr,
+    /// After an `=`.
+    Equals,
+    /// After an `=` and the first digit of an escape.
+    Hex(u8),
+    /// After an `=` and whitespace, i.e. in a soft line break.
+    SoftBreak,
+    /// After the CR of a soft line break.
+    SoftBreakCr,
+}
+
+/// Decodes quoted-printable data (RFC 2045) to plain bytes, keeping the state between calls.
+///
+/// Hard line breaks, either CRLF, LF or a lone CR, are normalized to the given line ending, and the whitespace at the end of lines is dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct QuotedPrintableDecoder {
+    line_ending: LineEnding,
+    state:       DecodeState,
+    whitespace:  Vec<u8>,
+    offset:      usize,
+}
+
+impl QuotedPrintableDecoder {
+    #[inline]
+    pub(crate) fn new() -> QuotedPrintableDecoder {
+        QuotedPrintableDecoder {
+            line_ending: LineEnding::CRLF,
+            state:       DecodeState::Text,
+            whitespace:  Vec::new(),
+            offset:      0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    fn decode_byte(&mut self, b: u8, output: &mut Vec<u8>) -> Result<(), QuotedPrintableError> {
+        if matches!(self.state, DecodeState::Cr | DecodeState::SoftBreakCr) {
+            self.state = DecodeState::Text;
+
+            // a lone CR is a line break on its own
+            if b == b'\n' {
+                return Ok(());
+            }
+        }
+
+        match self.state {
+            DecodeState::Text => match b {
+                b' ' | b'\t' => {
+                    if self.whitespace.len() == MAX_WHITESPACE_LENGTH {
+                        return Err(QuotedPrintableError::LineTooLong(
+                            self.offset - MAX_WHITESPACE_LENGTH,
+                        ));
+                    }
+
+                    self.whitespace.push(b);
+                },
+                b'\r' | b'\n' => {
+                    // the whitespace at the end of a line was added in transport
+                    self.whitespace.clear();
+
+                    output.extend_from_slice(self.line_ending.as_bytes());
+
+                    if b == b'\r' {
+                        self.state = DecodeState::Cr;
+                    }
+                },
+                _ => {
+                    output.append(&mut self.whitespace);
+
+                    if b == b'=' {
+                        self.state = DecodeState::Equals;
+                    } else {
+                        output.push(b);
+                    }
+                },
+            },
+            DecodeState::Equals => match b {
+                b' ' | b'\t' => self.state = DecodeState::SoftBreak,
+                b'\r' => self.state = DecodeState::SoftBreakCr,
+                b'\n' => self.state = DecodeState::Text,
+                _ => match hex_value(b) {
+                    Some(high) => self.state = DecodeState::Hex(high),
+                    None => return Err(QuotedPrintableError::InvalidByte(self.offset, b)),
+                },
+            },
+            DecodeState::Hex(high) => match hex_value(b) {
+                Some(low) => {
+                    output.push(high << 4 | low);
+
+                    self.state = DecodeState::Text;
+                },
+                None => return Err(QuotedPrintableError::InvalidByte(self.offset, b)),
+            },
+            DecodeState::SoftBreak => match b {
+                b' ' | b'\t' => (),
+                b'\r' => self.state = DecodeState::SoftBreakCr,
+                b'\n' => self.state = DecodeState::Text,
+                _ => return Err(QuotedPrintableError::InvalidByte(self.offset, b)),
+            },
+            DecodeState::Cr | DecodeState::SoftBreakCr => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    /// Decodes `input` and appends the result to `output`.
+    pub(crate) fn decode(
+        &mut self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<(), QuotedPrintableError> {
+        for &b in input {
+            self.decode_byte(b, output)?;
+
+            self.offset += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Checks the end of the data. A final `=` is taken as a soft line break, and final whitespace is dropped.
+    #[inline]
+    pub(crate) fn finish(&mut self) -> Result<(), QuotedPrintableError> {
+        self.whitespace.clear();
+
+        match mem::replace(&mut self.state, DecodeState::Text) {
+            DecodeState::Hex(_) => Err(QuotedPrintableError::IncompleteEscape),
+            _ => Ok(()),
+        }
+    }
+}
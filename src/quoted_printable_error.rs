@@ -0,0 +1,31 @@
+use std::{error::Error, fmt};
+
+/// The error returned when decoding invalid quoted-printable data. It is wrapped in an [`io::Error`](std::io::Error) of the kind [`InvalidData`](std::io::ErrorKind::InvalidData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuotedPrintableError {
+    /// A byte at the given offset cannot follow the `=` before it.
+    InvalidByte(usize, u8),
+    /// The data end in the middle of an `=XX` escape.
+    IncompleteEscape,
+    /// The run of spaces and tabs starting at the given offset is longer than any valid line.
+    LineTooLong(usize),
+}
+
+impl fmt::Display for QuotedPrintableError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuotedPrintableError::InvalidByte(offset, byte) => {
+                write!(f, "invalid byte {byte:?} in an escape at offset {offset}")
+            },
+            QuotedPrintableError::IncompleteEscape => {
+                f.write_str("the data end in the middle of an escape")
+            },
+            QuotedPrintableError::LineTooLong(offset) => {
+                write!(f, "the whitespace at offset {offset} is too long")
+            },
+        }
+    }
+}
+
+impl Error for QuotedPrintableError {}
@@ -0,0 +1,127 @@
+use crate::{RadixEngine, RadixError, bit_regrouper::BitRegrouper};
+
+/// Encodes plain data with a [`RadixEngine`], carrying the bits of an incomplete symbol over to the next call.
+#[derive(Debug, Clone)]
+pub(crate) struct RadixEncoder {
+    engine:    &'static RadixEngine,
+    regrouper: BitRegrouper,
+    /// The number of symbols in the current block.
+    column:    usize,
+}
+
+impl RadixEncoder {
+    #[inline]
+    pub(crate) const fn new(engine: &'static RadixEngine) -> RadixEncoder {
+        RadixEncoder {
+            engine,
+            regrouper: BitRegrouper::new(8, engine.bits()),
+            column: 0,
+        }
+    }
+
+    /// Turns the values appended to `output` since `start` into symbols.
+    #[inline]
+    fn map_symbols(&mut self, output: &mut [u8], start: usize) {
+        for value in output[start..].iter_mut() {
+            *value = self.engine.encode_symbol(*value);
+        }
+
+        self.column = (self.column + output.len() - start) % self.engine.block_symbols();
+    }
+
+    pub(crate) fn encode(&mut self, input: &[u8], output: &mut Vec<u8>) {
+        let start = output.len();
+
+        self.regrouper.push(input, output);
+
+        self.map_symbols(output, start);
+    }
+
+    /// Encodes the carried bits, if any, and pads the last block.
+    pub(crate) fn finish(&mut self, output: &mut Vec<u8>) {
+        let start = output.len();
+
+        self.regrouper.finish_padded(output);
+
+        self.map_symbols(output, start);
+
+        if let Some(padding) = self.engine.padding() {
+            if self.column > 0 {
+                output.resize(output.len() + self.engine.block_symbols() - self.column, padding);
+            }
+        }
+
+        self.column = 0;
+    }
+}
+
+/// Decodes data of a [`RadixEngine`], carrying the bits of an incomplete byte over to the next call.
+#[derive(Debug, Clone)]
+pub(crate) struct RadixDecoder {
+    engine:    &'static RadixEngine,
+    regrouper: BitRegrouper,
+    values:    Vec<u8>,
+    /// The number of data symbols in the current block.
+    column:    usize,
+    /// The number of padding characters seen, after which only padding may follow.
+    padding:   usize,
+    /// The offset of the next byte.
+    offset:    u64,
+}
+
+impl RadixDecoder {
+    #[inline]
+    pub(crate) const fn new(engine: &'static RadixEngine) -> RadixDecoder {
+        RadixDecoder {
+            engine,
+            regrouper: BitRegrouper::new(engine.bits(), 8),
+            values: Vec::new(),
+            column: 0,
+            padding: 0,
+            offset: 0,
+        }
+    }
+
+    pub(crate) fn decode(&mut self, input: &[u8], output: &mut Vec<u8>) -> Result<(), RadixError> {
+        self.values.clear();
+
+        for &b in input {
+            match self.engine.decode_symbol(b) {
+                Some(value) if self.padding == 0 => {
+                    self.values.push(value);
+
+                    self.column = (self.column + 1) % self.engine.block_symbols();
+                },
+                _ if self.engine.padding() == Some(b) && self.column > 0 => self.padding += 1,
+                _ => return Err(RadixError::InvalidSymbol(self.offset, b)),
+            }
+
+            self.offset += 1;
+        }
+
+        self.regrouper.push(&self.values, output);
+
+        Ok(())
+    }
+
+    /// Checks the padding and the carried bits.
+    pub(crate) fn finish(&mut self) -> Result<(), RadixError> {
+        if self.engine.padding().is_some() {
+            let expected =
+                if self.column == 0 { 0 } else { self.engine.block_symbols() - self.column };
+
+            if self.padding != expected {
+                return Err(RadixError::InvalidPadding);
+            }
+        }
+
+        if !self.regrouper.finish_exact() {
+            return Err(RadixError::InvalidTrailingBits);
+        }
+
+        self.column = 0;
+        self.padding = 0;
+
+        Ok(())
+    }
+}
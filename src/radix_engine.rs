@@ -0,0 +1,166 @@
+use crate::alphabet;
+
+/// An encoding which regroups the bits of the data into symbols of a fixed number of bits, like base16, base32 and base64, described by its alphabet and padding.
+///
+/// The engine is built by `const fn`s, so a custom one can be a `const` or a `static` whose decode table is computed at compile time, and an invalid alphabet is a compile error.
+///
+/// ```rust
+/// use base64_stream::RadixEngine;
+///
+/// // Crockford's base32, which decodes either case and reads O as 0 and I and L as 1
+/// static CROCKFORD: RadixEngine = RadixEngine::new(5, b"0123456789ABCDEFGHJKMNPQRSTVWXYZ", None)
+///     .with_alias(b'O', b'0')
+///     .with_alias(b'I', b'1')
+///     .with_alias(b'L', b'1')
+///     .with_case_insensitive();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RadixEngine {
+    bits:          u32,
+    block_symbols: usize,
+    symbols:       [u8; 64],
+    decode_table:  [u8; 256],
+    padding:       Option<u8>,
+}
+
+impl RadixEngine {
+    /// Base16 (RFC 4648) in uppercase, which decodes either case.
+    pub const BASE16: RadixEngine =
+        RadixEngine::new(4, b"0123456789ABCDEF", None).with_case_insensitive();
+    /// Base32 (RFC 4648) with `=` padding.
+    pub const BASE32: RadixEngine =
+        RadixEngine::new(5, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", Some(b'='));
+    /// Base32 with the extended hex alphabet (RFC 4648) and `=` padding.
+    pub const BASE32_HEX: RadixEngine =
+        RadixEngine::new(5, b"0123456789ABCDEFGHIJKLMNOPQRSTUV", Some(b'='));
+    /// Base64 (RFC 4648) with `=` padding, which encodes like [`ToBase64Writer`](crate::ToBase64Writer) with the standard variant.
+    pub const BASE64: RadixEngine = RadixEngine::new(
+        6,
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        Some(b'='),
+    );
+    /// Octal digits, where every 3 bytes become 8 digits.
+    pub const BASE8: RadixEngine = RadixEngine::new(3, b"01234567", None);
+
+    /// Creates an engine of `bits`-bit symbols taken from `alphabet`, whose length must be 2 to the power of `bits`. With `padding`, the encoded data are padded to whole blocks, e.g. 8 symbols for 5-bit symbols, and the padding is required when decoding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is not within 1 to 6, if the length of `alphabet` does not match, or if `alphabet` has duplicates or bytes which are not printable ASCII, or if `padding` is not printable ASCII or is in `alphabet`.
+    pub const fn new(bits: u32, alphabet: &[u8], padding: Option<u8>) -> RadixEngine {
+        assert!(bits >= 1 && bits <= 6, "bits must be within 1 to 6");
+        assert!(
+            alphabet.len() == 1 << bits,
+            "the length of the alphabet must be 2 to the power of bits"
+        );
+
+        let decode_table = alphabet::decode_table(alphabet, padding);
+
+        let mut symbols = [0u8; 64];
+        let mut i = 0;
+
+        while i < alphabet.len() {
+            symbols[i] = alphabet[i];
+            i += 1;
+        }
+
+        // the fewest symbols which hold a whole number of bytes
+        let mut block_symbols = 1;
+
+        while block_symbols * bits as usize % 8 != 0 {
+            block_symbols += 1;
+        }
+
+        RadixEngine {
+            bits,
+            block_symbols,
+            symbols,
+            decode_table,
+            padding,
+        }
+    }
+
+    /// Decodes the other case of every decodable letter to the same value, unless that case is decodable itself. Call it after [`with_alias`](Self::with_alias) to cover the aliases too.
+    pub const fn with_case_insensitive(mut self) -> Self {
+        let mut b = 0u8;
+
+        while b < 0x80 {
+            let value = self.decode_table[b as usize];
+            let other = if b.is_ascii_lowercase() {
+                b.to_ascii_uppercase()
+            } else {
+                b.to_ascii_lowercase()
+            };
+
+            if value != 0xFF && self.decode_table[other as usize] == 0xFF && !self.is_padding(other)
+            {
+                self.decode_table[other as usize] = value;
+            }
+
+            b += 1;
+        }
+
+        self
+    }
+
+    /// Decodes `alias` to the value of `symbol`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `symbol` is not in the alphabet, or if `alias` is already decodable or is the padding.
+    pub const fn with_alias(mut self, alias: u8, symbol: u8) -> Self {
+        let value = self.decode_table[symbol as usize];
+
+        assert!(value != 0xFF, "the aliased symbol must be in the alphabet");
+        assert!(
+            self.decode_table[alias as usize] == 0xFF && !self.is_padding(alias),
+            "the alias must not be decodable already"
+        );
+
+        self.decode_table[alias as usize] = value;
+
+        self
+    }
+
+    #[inline]
+    const fn is_padding(&self, b: u8) -> bool {
+        matches!(self.padding, Some(padding) if padding == b)
+    }
+
+    /// Returns the number of bits per symbol.
+    #[inline]
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Returns the symbols in the order of their values.
+    #[inline]
+    pub const fn alphabet(&self) -> &[u8] {
+        self.symbols.split_at(1 << self.bits).0
+    }
+
+    /// Returns the padding character, if any.
+    #[inline]
+    pub const fn padding(&self) -> Option<u8> {
+        self.padding
+    }
+
+    /// Returns the number of symbols in a block, i.e. the fewest symbols which hold a whole number of bytes.
+    #[inline]
+    pub(crate) const fn block_symbols(&self) -> usize {
+        self.block_symbols
+    }
+
+    #[inline]
+    pub(crate) const fn encode_symbol(&self, value: u8) -> u8 {
+        self.symbols[value as usize]
+    }
+
+    #[inline]
+    pub(crate) const fn decode_symbol(&self, symbol: u8) -> Option<u8> {
+        match self.decode_table[symbol as usize] {
+            0xFF => None,
+            value => Some(value),
+        }
+    }
+}
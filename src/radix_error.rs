@@ -0,0 +1,29 @@
+use std::{error::Error, fmt};
+
+/// The error returned when decoding invalid data of a [`RadixEngine`](crate::RadixEngine). It is wrapped in an [`io::Error`](std::io::Error) of the kind [`InvalidData`](std::io::ErrorKind::InvalidData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RadixError {
+    /// The byte at the given offset is neither in the alphabet nor valid padding there.
+    InvalidSymbol(u64, u8),
+    /// The data end with missing or too much padding.
+    InvalidPadding,
+    /// The data end with a symbol which cannot be the last one, or whose unused bits are not zeros.
+    InvalidTrailingBits,
+}
+
+impl fmt::Display for RadixError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RadixError::InvalidSymbol(offset, byte) => {
+                write!(f, "invalid symbol {byte:?} at offset {offset}")
+            },
+            RadixError::InvalidPadding => f.write_str("the data end with invalid padding"),
+            RadixError::InvalidTrailingBits => {
+                f.write_str("the data end with invalid trailing bits")
+            },
+        }
+    }
+}
+
+impl Error for RadixError {}
@@ -0,0 +1,244 @@
+/*!
+Serialize and deserialize fields as base64 strings with `#[serde(with = "base64_stream::serde")]`.
+
+Serialization encodes the data chunk by chunk through [`ToBase64Reader`] and passes them to [`Serializer::collect_str`], so serializers which stream `collect_str`, such as `serde_json`, never hold the whole base64 string. Any field which implements [`ReadSource`] can be serialized, including byte containers and readers in a `RefCell` or a `Mutex`.
+
+Deserialization decodes the string through [`FromBase64Writer`] into a sink which implements [`Write`] and [`Default`], such as `Vec<u8>` or `Cursor<Vec<u8>>`. Use [`Base64Seed`] to decode into a sink which already exists.
+
+```rust
+use std::{cell::RefCell, io::Cursor};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+struct Upload {
+    #[serde(with = "base64_stream::serde")]
+    attachment: RefCell<Cursor<Vec<u8>>>,
+}
+
+#[derive(Deserialize)]
+struct Download {
+    #[serde(with = "base64_stream::serde")]
+    attachment: Vec<u8>,
+}
+
+let upload = Upload {
+    attachment: RefCell::new(Cursor::new(b"Hi there".to_vec())),
+};
+
+let json = serde_json::to_string(&upload).unwrap();
+
+assert_eq!(r#"{"attachment":"SGkgdGhlcmU="}"#, json);
+
+let download: Download = serde_json::from_str(&json).unwrap();
+
+assert_eq!(b"Hi there", download.attachment.as_slice());
+```
+*/
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    fs::File,
+    io::{self, ErrorKind, Read, Write},
+    sync::Mutex,
+};
+
+use ::serde::{
+    Deserializer, Serializer,
+    de::{self, DeserializeSeed, Visitor},
+    ser,
+};
+
+use crate::{FromBase64Writer, ToBase64Reader};
+
+/// The size of the base64 chunks passed to the formatter.
+const CHUNK_SIZE: usize = 4096;
+
+/// Data which can be read through a shared reference, so that they can be serialized as base64.
+pub trait ReadSource {
+    /// Calls `f` with a reader of the data.
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O;
+}
+
+impl ReadSource for [u8] {
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        f(&mut &*self)
+    }
+}
+
+impl<const N: usize> ReadSource for [u8; N] {
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        self.as_slice().read_with(f)
+    }
+}
+
+impl ReadSource for Vec<u8> {
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        self.as_slice().read_with(f)
+    }
+}
+
+impl ReadSource for Box<[u8]> {
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        (**self).read_with(f)
+    }
+}
+
+impl ReadSource for File {
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        f(&mut &*self)
+    }
+}
+
+impl<R: Read> ReadSource for RefCell<R> {
+    /// # Panics
+    ///
+    /// Panics if the reader is currently borrowed.
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        f(&mut *self.borrow_mut())
+    }
+}
+
+impl<R: Read> ReadSource for Mutex<R> {
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        let mut reader = self.lock().unwrap_or_else(|error| error.into_inner());
+
+        f(&mut *reader)
+    }
+}
+
+impl<T: ReadSource + ?Sized> ReadSource for &T {
+    #[inline]
+    fn read_with<O>(&self, f: impl FnOnce(&mut dyn Read) -> O) -> O {
+        (**self).read_with(f)
+    }
+}
+
+/// Formats the data of a reader as base64, keeping the read error which has cut it short.
+struct Base64Chunks<'a, 'b> {
+    reader: RefCell<ToBase64Reader<&'a mut (dyn Read + 'b)>>,
+    error:  Cell<Option<io::Error>>,
+}
+
+impl fmt::Display for Base64Chunks<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut reader = self.reader.borrow_mut();
+        let mut chunk = [0u8; CHUNK_SIZE];
+
+        loop {
+            let c = match reader.read(&mut chunk) {
+                Ok(0) => return Ok(()),
+                Ok(c) => c,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    // only the formatter may fail, so the error is returned after the formatting
+                    self.error.set(Some(error));
+
+                    return Ok(());
+                },
+            };
+
+            // base64 data are always ASCII
+            f.write_str(std::str::from_utf8(&chunk[..c]).unwrap())?;
+        }
+    }
+}
+
+/// Serializes the data of `value` as a base64 string.
+pub fn serialize<T: ReadSource + ?Sized, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.read_with(|reader| {
+        let chunks = Base64Chunks {
+            reader: RefCell::new(ToBase64Reader::new(reader)),
+            error:  Cell::new(None),
+        };
+
+        let ok = serializer.collect_str(&chunks)?;
+
+        match chunks.error.take() {
+            Some(error) => Err(ser::Error::custom(error)),
+            None => Ok(ok),
+        }
+    })
+}
+
+/// Deserializes a base64 string into a new sink.
+pub fn deserialize<'de, W: Write + Default, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<W, D::Error> {
+    Base64Seed::new(W::default()).deserialize(deserializer)
+}
+
+/// Deserializes a base64 string by decoding it into the given sink.
+pub struct Base64Seed<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> fmt::Debug for Base64Seed<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Base64Seed").finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> Base64Seed<W> {
+    #[inline]
+    pub fn new(writer: W) -> Base64Seed<W> {
+        Base64Seed {
+            writer,
+        }
+    }
+}
+
+impl<'de, W: Write> DeserializeSeed<'de> for Base64Seed<W> {
+    type Value = W;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<W, D::Error> {
+        deserializer.deserialize_str(Base64Visitor {
+            writer: self.writer
+        })
+    }
+}
+
+struct Base64Visitor<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Base64Visitor<W> {
+    fn decode<E: de::Error>(self, v: &[u8]) -> Result<W, E> {
+        let mut writer = FromBase64Writer::new(self.writer);
+
+        writer.write_all(v).map_err(E::custom)?;
+
+        writer.finish().map_err(E::custom)
+    }
+}
+
+impl<W: Write> Visitor<'_> for Base64Visitor<W> {
+    type Value = W;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a base64 string")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<W, E> {
+        self.decode(v.as_bytes())
+    }
+
+    #[inline]
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<W, E> {
+        self.decode(v)
+    }
+}
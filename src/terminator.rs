@@ -0,0 +1,212 @@
+use std::io::{self, Read};
+
+/// Bytes read from the inner reader per step while looking for a [`Terminator`].
+const CHUNK_SIZE: usize = 4096;
+
+/// Where a base64 region read by [`FromBase64Reader`](crate::FromBase64Reader) ends.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Terminator {
+    /// Right after the first quantum which ends with padding.
+    Padding,
+    /// At an empty line, i.e. two consecutive line breaks, which is consumed.
+    BlankLine,
+    /// At the given delimiter, which is consumed.
+    Delimiter(Vec<u8>),
+}
+
+#[inline]
+fn is_line_break(byte: u8) -> bool {
+    matches!(byte, b'\r' | b'\n')
+}
+
+/// Reads the base64 region before a terminator out of an inner reader, without its line breaks.
+///
+/// What is read past the terminator is kept for [`FromBase64Reader::into_rest`](crate::FromBase64Reader::into_rest).
+#[derive(Debug)]
+pub(crate) struct Boundary {
+    terminator:   Terminator,
+    pending:      Vec<u8>,
+    offset:       usize,
+    symbol_count: usize,
+    padding_seen: bool,
+    eof:          bool,
+    ended:        bool,
+}
+
+impl Boundary {
+    #[inline]
+    pub(crate) fn new(terminator: Terminator) -> Boundary {
+        if let Terminator::Delimiter(delimiter) = &terminator {
+            assert!(!delimiter.is_empty(), "the delimiter must not be empty");
+        }
+
+        Boundary {
+            terminator,
+            pending: Vec::new(),
+            offset: 0,
+            symbol_count: 0,
+            padding_seen: false,
+            eof: false,
+            ended: false,
+        }
+    }
+
+    /// Returns the bytes read from the inner reader but not returned yet. Once the terminator has been passed, they are the bytes which follow it.
+    #[inline]
+    pub(crate) fn pending(&self) -> &[u8] {
+        &self.pending[self.offset..]
+    }
+
+    /// Returns the end of the pending bytes which certainly belong to the region, and the end of the terminator if it starts right there.
+    fn scan(&self) -> (usize, Option<usize>) {
+        let pending = &self.pending[..];
+        let length = pending.len();
+
+        match &self.terminator {
+            Terminator::Padding => {
+                if self.padding_seen && self.symbol_count & 0b11 == 0 {
+                    return (self.offset, Some(self.offset));
+                }
+
+                let mut symbol_count = self.symbol_count;
+                let mut padding_seen = self.padding_seen;
+
+                for (i, &b) in pending.iter().enumerate().skip(self.offset) {
+                    if is_line_break(b) {
+                        continue;
+                    }
+
+                    symbol_count += 1;
+                    padding_seen |= b == b'=';
+
+                    if padding_seen && symbol_count & 0b11 == 0 {
+                        return (i + 1, Some(i + 1));
+                    }
+                }
+
+                (length, None)
+            },
+            Terminator::BlankLine => {
+                for i in self.offset..length {
+                    if pending[i] != b'\n' {
+                        continue;
+                    }
+
+                    let mut j = i + 1;
+
+                    if j < length && pending[j] == b'\r' {
+                        j += 1;
+                    }
+
+                    if j == length {
+                        // the next line may still turn out to be empty
+                        return if self.eof { (length, None) } else { (i, None) };
+                    }
+
+                    if pending[j] == b'\n' {
+                        return (i, Some(j + 1));
+                    }
+                }
+
+                (length, None)
+            },
+            Terminator::Delimiter(delimiter) => {
+                match pending[self.offset..]
+                    .windows(delimiter.len())
+                    .position(|window| window == delimiter.as_slice())
+                {
+                    Some(p) => (self.offset + p, Some(self.offset + p + delimiter.len())),
+                    // the delimiter may begin in the last bytes
+                    None if !self.eof => {
+                        (length.saturating_sub(delimiter.len() - 1).max(self.offset), None)
+                    },
+                    None => (length, None),
+                }
+            },
+        }
+    }
+
+    /// Copies `self.pending[self.offset..end]` into `buf` without line breaks and returns the written length.
+    fn deliver(&mut self, end: usize, buf: &mut [u8]) -> usize {
+        let mut written = 0;
+
+        while self.offset < end && written < buf.len() {
+            let b = self.pending[self.offset];
+
+            self.offset += 1;
+
+            if is_line_break(b) {
+                continue;
+            }
+
+            buf[written] = b;
+            written += 1;
+
+            self.symbol_count += 1;
+            self.padding_seen |= b == b'=';
+        }
+
+        written
+    }
+
+    /// Reads the region into `buf` and returns 0 once the terminator or EOF has been reached.
+    pub(crate) fn read<R: Read>(&mut self, inner: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            if self.ended {
+                return Ok(0);
+            }
+
+            let (end, terminator_end) = self.scan();
+
+            if end > self.offset {
+                let c = self.deliver(end, buf);
+
+                if c > 0 {
+                    return Ok(c);
+                }
+
+                // only line breaks were delivered
+                continue;
+            }
+
+            if let Some(terminator_end) = terminator_end {
+                self.offset = terminator_end;
+                self.ended = true;
+
+                return Ok(0);
+            }
+
+            if self.eof {
+                self.ended = true;
+
+                return Ok(0);
+            }
+
+            self.pending.drain(..self.offset);
+            self.offset = 0;
+
+            let length = self.pending.len();
+
+            self.pending.resize(length + CHUNK_SIZE, 0);
+
+            match inner.read(&mut self.pending[length..]) {
+                Ok(c) => {
+                    self.pending.truncate(length + c);
+
+                    if c == 0 {
+                        self.eof = true;
+                    }
+                },
+                Err(e) => {
+                    self.pending.truncate(length);
+
+                    return Err(e);
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use crate::base45::Base45Encoder;
+
+/// Read any data and encode them to Base45 data (RFC 9285).
+///
+/// Every 2 plain bytes become 3 characters. An odd byte is carried over to the next read from the inner reader, and becomes 2 characters at its EOF.
+pub struct ToBase45Reader<R: Read, const N: usize = 4096> {
+    inner:       R,
+    buf:         [u8; N],
+    encoder:     Base45Encoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    eof:         bool,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for ToBase45Reader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToBase45Reader")
+            .field("encoder", &self.encoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> ToBase45Reader<R> {
+    #[inline]
+    pub fn new(reader: R) -> ToBase45Reader<R> {
+        Self::new2(reader)
+    }
+}
+
+impl<R: Read, const N: usize> ToBase45Reader<R, N> {
+    #[inline]
+    pub fn new2(reader: R) -> ToBase45Reader<R, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        ToBase45Reader {
+            inner:       reader,
+            buf:         [0u8; N],
+            encoder:     Base45Encoder::default(),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+            eof:         false,
+        }
+    }
+}
+
+impl<R: Read, const N: usize> ToBase45Reader<R, N> {
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for ToBase45Reader<R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let temp = &self.temp[self.temp_offset..];
+
+            if !temp.is_empty() {
+                let c = temp.len().min(buf.len());
+
+                buf[..c].copy_from_slice(&temp[..c]);
+
+                self.temp_offset += c;
+
+                return Ok(c);
+            }
+
+            if self.eof {
+                return Ok(0);
+            }
+
+            self.temp.clear();
+            self.temp_offset = 0;
+
+            let c = self.inner.read(&mut self.buf)?;
+
+            if c == 0 {
+                self.eof = true;
+
+                self.encoder.finish(&mut self.temp);
+            } else {
+                self.encoder.encode(&self.buf[..c], &mut self.temp);
+            }
+        }
+    }
+}
+
+impl<R: Read> From<R> for ToBase45Reader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        ToBase45Reader::new(reader)
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+};
+
+use crate::base45::Base45Encoder;
+
+/// Write plain data and encode them to Base45 data (RFC 9285).
+///
+/// Every 2 plain bytes become 3 characters. An odd byte is carried over to the next write, and becomes 2 characters at [`finish`](Self::finish).
+pub struct ToBase45Writer<W: Write, const N: usize = 4096> {
+    inner:       W,
+    encoder:     Base45Encoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for ToBase45Writer<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToBase45Writer")
+            .field("encoder", &self.encoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> ToBase45Writer<W> {
+    #[inline]
+    pub fn new(writer: W) -> ToBase45Writer<W> {
+        Self::new2(writer)
+    }
+}
+
+impl<W: Write, const N: usize> ToBase45Writer<W, N> {
+    #[inline]
+    pub fn new2(writer: W) -> ToBase45Writer<W, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        ToBase45Writer {
+            inner:       writer,
+            encoder:     Base45Encoder::default(),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+        }
+    }
+}
+
+impl<W: Write, const N: usize> ToBase45Writer<W, N> {
+    /// Writes the encoded bytes which have not reached the inner writer yet.
+    fn drain_temp(&mut self) -> Result<(), io::Error> {
+        while self.temp_offset < self.temp.len() {
+            match self.inner.write(&self.temp[self.temp_offset..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(c) => self.temp_offset += c,
+                Err(error) if error.kind() == ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        self.temp.clear();
+        self.temp_offset = 0;
+
+        Ok(())
+    }
+
+    /// Finishes encoding buffered data and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.drain_temp()?;
+
+        self.encoder.finish(&mut self.temp);
+
+        self.drain_temp()?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to encode the final buffered data and flush the inner writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, const N: usize> Write for ToBase45Writer<W, N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        // the bytes left by a failed call are written first
+        self.drain_temp()?;
+
+        let c = buf.len().min(N);
+
+        self.encoder.encode(&buf[..c], &mut self.temp);
+
+        // a failure is left to the next call along with the unwritten bytes, so that no error is returned after bytes have been taken
+        let _ = self.drain_temp();
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.drain_temp()?;
+
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> From<W> for ToBase45Writer<W> {
+    #[inline]
+    fn from(writer: W) -> Self {
+        ToBase45Writer::new(writer)
+    }
+}
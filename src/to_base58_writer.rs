@@ -0,0 +1,164 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+#[cfg(feature = "base58check")]
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "base58check")]
+use crate::base58::checksum;
+use crate::{Base58Alphabet, LimitExceeded, base58::Base58Encoder};
+
+/// Write plain data and encode them to Base58 data, optionally with a Base58Check checksum.
+///
+/// The checksum of [`with_check`](Self::with_check) needs the `base58check` feature.
+///
+/// Base58 treats the whole data as one big number, so no encoded byte is known before the last plain byte, and every encoded byte is written by [`finish`](Self::finish). The plain data are not buffered, but the number built from them is, which takes about 1.37 bytes per plain byte. Every written byte multiplies that number, so encoding `n` bytes takes time proportional to `n²`. This is fine for keys, addresses and other data of some kilobytes, but hundreds of kilobytes take seconds and megabytes take minutes. Use [`with_limit`](Self::with_limit) to bound both when the data come from elsewhere.
+pub struct ToBase58Writer<W: Write> {
+    inner:    W,
+    alphabet: Base58Alphabet,
+    #[cfg(feature = "base58check")]
+    sha256:   Option<Sha256>,
+    limit:    u64,
+    written:  u64,
+    encoder:  Base58Encoder,
+}
+
+impl<W: Write> fmt::Debug for ToBase58Writer<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ToBase58Writer");
+
+        debug.field("alphabet", &self.alphabet);
+
+        #[cfg(feature = "base58check")]
+        debug.field("check", &self.sha256.is_some());
+
+        debug.field("written", &self.written).finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> ToBase58Writer<W> {
+    #[inline]
+    pub fn new(writer: W) -> ToBase58Writer<W> {
+        ToBase58Writer {
+            inner:                                  writer,
+            alphabet:                               Base58Alphabet::default(),
+            #[cfg(feature = "base58check")]
+            sha256:                                 None,
+            limit:                                  u64::MAX,
+            written:                                0,
+            encoder:                                Base58Encoder::default(),
+        }
+    }
+
+    /// Encodes with `alphabet` instead of the Bitcoin one.
+    #[inline]
+    pub fn with_alphabet(mut self, alphabet: Base58Alphabet) -> Self {
+        self.alphabet = alphabet;
+
+        self
+    }
+
+    /// Appends the Base58Check checksum, i.e. the first 4 bytes of the double SHA-256 of the plain data, before encoding.
+    #[cfg(feature = "base58check")]
+    #[inline]
+    pub fn with_check(mut self) -> Self {
+        self.sha256 = Some(Sha256::default());
+
+        self
+    }
+
+    /// Fails with [`LimitExceeded::Decoded`] instead of taking more than `limit` plain bytes.
+    #[inline]
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+
+        self
+    }
+}
+
+impl<W: Write> ToBase58Writer<W> {
+    /// Encodes the written data, along with the checksum if any, writes them and returns the inner writer.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        #[cfg(feature = "base58check")]
+        if let Some(sha256) = self.sha256.take() {
+            self.encoder.push(&checksum(sha256));
+        }
+
+        let symbols = self.alphabet.symbols();
+
+        let mut encoded = self.encoder.into_digits();
+
+        for digit in encoded.iter_mut() {
+            *digit = symbols[*digit as usize];
+        }
+
+        self.inner.write_all(&encoded)?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly puts the data before everything encoded by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to write the encoded data.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ToBase58Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let remaining = self.limit - self.written;
+
+        if remaining == 0 {
+            return Err(io::Error::other(LimitExceeded::Decoded(self.limit)));
+        }
+
+        let c = usize::try_from(remaining).map_or(buf.len(), |remaining| buf.len().min(remaining));
+
+        self.encoder.push(&buf[..c]);
+
+        #[cfg(feature = "base58check")]
+        if let Some(sha256) = self.sha256.as_mut() {
+            sha256.update(&buf[..c]);
+        }
+
+        self.written += c as u64;
+
+        Ok(c)
+    }
+
+    /// Flushes the inner writer. Nothing is encoded before [`finish`](Self::finish).
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> From<W> for ToBase58Writer<W> {
+    #[inline]
+    fn from(writer: W) -> Self {
+        ToBase58Writer::new(writer)
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{self, IoSlice, Read, Write},
+};
+
+use crate::ToBase64Writer;
+
+/// Passes base64 bytes on to a `fmt::Write` as `str`.
+struct FmtSink<W: fmt::Write>(W);
+
+impl<W: fmt::Write> Write for FmtSink<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        // base64 data are always ASCII
+        let s = std::str::from_utf8(buf).map_err(io::Error::other)?;
+
+        self.0.write_str(s).map_err(io::Error::other)?;
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+}
+
+/// Write plain data and encode them to base64 text on a `fmt::Write`, such as a `String` or a `Formatter`.
+pub struct ToBase64FmtWriter<W: fmt::Write, const N: usize = 4096> {
+    inner: ToBase64Writer<FmtSink<W>, N>,
+}
+
+impl<W: fmt::Write, const N: usize> fmt::Debug for ToBase64FmtWriter<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToBase64FmtWriter").field("inner", &self.inner).finish()
+    }
+}
+
+impl<W: fmt::Write> ToBase64FmtWriter<W> {
+    #[inline]
+    pub fn new(writer: W) -> ToBase64FmtWriter<W> {
+        Self::new2(writer)
+    }
+}
+
+impl<W: fmt::Write, const N: usize> ToBase64FmtWriter<W, N> {
+    #[inline]
+    pub fn new2(writer: W) -> ToBase64FmtWriter<W, N> {
+        ToBase64FmtWriter {
+            inner: ToBase64Writer::new2(FmtSink(writer))
+        }
+    }
+
+    /// Finishes encoding buffered data and returns the inner writer.
+    ///
+    /// An error of the inner writer is returned as an [`io::Error`] which wraps [`fmt::Error`].
+    #[inline]
+    pub fn finish(self) -> Result<W, io::Error> {
+        self.inner.finish().map(|sink| sink.0)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner.get_ref().0
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner.get_mut().0
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to encode the final buffered data.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner().0
+    }
+}
+
+impl<W: fmt::Write, const N: usize> Write for ToBase64FmtWriter<W, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.inner.write(buf)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, io::Error> {
+        self.inner.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
+
+impl<W: fmt::Write> From<W> for ToBase64FmtWriter<W> {
+    #[inline]
+    fn from(writer: W) -> Self {
+        ToBase64FmtWriter::new(writer)
+    }
+}
+
+/// Displays the data of a reader as base64 without collecting them first.
+///
+/// The reader is consumed by the first formatting, so formatting again only shows what is left in it. A read error is reported as [`fmt::Error`].
+pub struct Base64Display<R: Read> {
+    reader: RefCell<R>,
+}
+
+impl<R: Read> fmt::Debug for Base64Display<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Base64Display").finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> Base64Display<R> {
+    #[inline]
+    pub fn new(reader: R) -> Base64Display<R> {
+        Base64Display {
+            reader: RefCell::new(reader)
+        }
+    }
+
+    /// Returns the inner reader, consuming this wrapper.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+}
+
+impl<R: Read> fmt::Display for Base64Display<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut reader = self.reader.borrow_mut();
+        let mut writer = ToBase64FmtWriter::new(f);
+
+        io::copy(&mut *reader, &mut writer).map_err(|_| fmt::Error)?;
+
+        writer.finish().map_err(|_| fmt::Error)?;
+
+        Ok(())
+    }
+}
+
+/// Displays byte chunks as base64 without joining them first.
+///
+/// The chunks are iterated again every time this is formatted.
+#[derive(Debug, Clone)]
+pub struct Base64ChunksDisplay<I> {
+    chunks: I,
+}
+
+impl<I> Base64ChunksDisplay<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: AsRef<[u8]>,
+{
+    #[inline]
+    pub fn new(chunks: I) -> Base64ChunksDisplay<I> {
+        Base64ChunksDisplay {
+            chunks,
+        }
+    }
+}
+
+impl<I> fmt::Display for Base64ChunksDisplay<I>
+where
+    I: IntoIterator + Clone,
+    I::Item: AsRef<[u8]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = ToBase64FmtWriter::new(f);
+
+        for chunk in self.chunks.clone() {
+            writer.write_all(chunk.as_ref()).map_err(|_| fmt::Error)?;
+        }
+
+        writer.finish().map_err(|_| fmt::Error)?;
+
+        Ok(())
+    }
+}
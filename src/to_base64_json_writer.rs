@@ -0,0 +1,122 @@
+use std::{
+    fmt,
+    io::{self, IoSlice, Write},
+};
+
+use crate::{Base64Variant, ToBase64Writer};
+
+/// Write plain data and encode them to a JSON string of base64 data.
+///
+/// The opening quote is written along with the first data, and the closing quote by [`finish`](Self::finish). Base64 data never need escaping in JSON.
+pub struct ToBase64JsonWriter<W: Write, const N: usize = 4096> {
+    inner:  ToBase64Writer<W, N>,
+    opened: bool,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for ToBase64JsonWriter<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToBase64JsonWriter")
+            .field("inner", &self.inner)
+            .field("opened", &self.opened)
+            .finish()
+    }
+}
+
+impl<W: Write> ToBase64JsonWriter<W> {
+    #[inline]
+    pub fn new(writer: W) -> ToBase64JsonWriter<W> {
+        Self::new2(writer)
+    }
+}
+
+impl<W: Write, const N: usize> ToBase64JsonWriter<W, N> {
+    #[inline]
+    pub fn new2(writer: W) -> ToBase64JsonWriter<W, N> {
+        ToBase64JsonWriter {
+            inner: ToBase64Writer::new2(writer), opened: false
+        }
+    }
+
+    /// Encodes the plain data to `variant` instead of standard base64.
+    #[inline]
+    pub fn with_variant(mut self, variant: Base64Variant) -> Self {
+        self.inner = self.inner.with_variant(variant);
+
+        self
+    }
+
+    /// Writes the opening quote if it has not been written yet.
+    #[inline]
+    fn open(&mut self) -> Result<(), io::Error> {
+        if !self.opened {
+            self.inner.get_mut().write_all(b"\"")?;
+
+            self.opened = true;
+        }
+
+        Ok(())
+    }
+
+    /// Finishes encoding buffered data, writes the closing quote and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.open()?;
+
+        let mut inner = self.inner.finish()?;
+
+        inner.write_all(b"\"")?;
+        inner.flush()?;
+
+        Ok(inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        self.inner.get_ref()
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        self.inner.get_mut()
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to encode the final buffered data and close the string.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner.into_inner()
+    }
+}
+
+impl<W: Write, const N: usize> Write for ToBase64JsonWriter<W, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.open()?;
+
+        self.inner.write(buf)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, io::Error> {
+        self.open()?;
+
+        self.inner.write_vectored(bufs)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> From<W> for ToBase64JsonWriter<W> {
+    #[inline]
+    fn from(writer: W) -> Self {
+        ToBase64JsonWriter::new(writer)
+    }
+}
@@ -1,13 +1,18 @@
 use std::{
     fmt,
-    io::{self, ErrorKind, Read},
+    io::{self, ErrorKind, IoSliceMut, Read},
 };
 
-use base64::{
-    Engine,
-    engine::{GeneralPurpose, general_purpose::STANDARD},
+use base64::engine::general_purpose::STANDARD;
+
+use crate::{
+    Base64Alphabet, Base64Variant, PendingBytes, Progress, base64_alphabet::Base64Codec,
+    plain_digest::PlainDigest, progress::ProgressCounter,
 };
 
+/// Plain bytes encoded per step of [`encode_in_place`]. Must be a multiple of 3.
+const IN_PLACE_CHUNK_SIZE: usize = 768;
+
 /// Read any data and encode them to base64 data.
 pub struct ToBase64Reader<R: Read, const N: usize = 4096> {
     inner:       R,
@@ -16,7 +21,9 @@ pub struct ToBase64Reader<R: Read, const N: usize = 4096> {
     buf_offset:  usize,
     temp:        [u8; 3],
     temp_length: usize,
-    engine:      &'static GeneralPurpose,
+    engine:      Base64Codec,
+    digest:      PlainDigest,
+    progress:    ProgressCounter,
 }
 
 impl<R: Read, const N: usize> fmt::Debug for ToBase64Reader<R, N> {
@@ -26,6 +33,7 @@ impl<R: Read, const N: usize> fmt::Debug for ToBase64Reader<R, N> {
             .field("buf_offset", &self.buf_offset)
             .field("temp", &&self.temp[..self.temp_length])
             .field("temp_length", &self.temp_length)
+            .field("progress", &self.progress.progress())
             .finish_non_exhaustive()
     }
 }
@@ -48,9 +56,52 @@ impl<R: Read, const N: usize> ToBase64Reader<R, N> {
             buf_offset:  0,
             temp:        [0; 3],
             temp_length: 0,
-            engine:      &STANDARD,
+            engine:      Base64Codec::new(&STANDARD),
+            digest:      PlainDigest::default(),
+            progress:    ProgressCounter::default(),
         }
     }
+
+    /// Encodes the plain data to `variant` instead of standard base64.
+    #[inline]
+    pub fn with_variant(mut self, variant: Base64Variant) -> Self {
+        self.engine = Base64Codec::new(variant.engine());
+
+        self
+    }
+
+    /// Encodes the plain data to the custom `alphabet` instead of standard base64.
+    #[inline]
+    pub fn with_alphabet(mut self, alphabet: &'static Base64Alphabet) -> Self {
+        self.engine = Base64Codec::from_alphabet(alphabet);
+
+        self
+    }
+
+    /// Calls `callback` with the byte counts every time another `interval` plain bytes have been read from the inner reader.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[inline]
+    pub fn with_progress<F: FnMut(Progress) + Send + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress.set_callback(interval, callback);
+
+        self
+    }
+
+    /// Feeds the plain data into `digest` as it is read from the inner reader.
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn with_digest<D: digest::DynDigest + Send + Sync + 'static>(mut self, digest: D) -> Self {
+        self.digest = PlainDigest::new(digest);
+
+        self
+    }
 }
 
 impl<R: Read, const N: usize> ToBase64Reader<R, N> {
@@ -164,15 +215,92 @@ impl<R: Read, const N: usize> ToBase64Reader<R, N> {
         if !buf.is_empty() && self.buf_length > 0 { self.drain_block(buf) } else { buf }
     }
 
-    /// Returns the inner reader, consuming this wrapper.
+    /// Drains already buffered data into `buf` without reading the inner reader.
     #[inline]
-    pub fn into_inner(self) -> R {
-        self.inner
+    fn drain_buffered<'a>(&mut self, mut buf: &'a mut [u8]) -> &'a mut [u8] {
+        if self.temp_length > 0 {
+            buf = self.drain_temp(buf);
+        }
+
+        if !buf.is_empty() && self.buf_length >= 3 { self.drain(buf) } else { buf }
     }
-}
 
-impl<R: Read, const N: usize> Read for ToBase64Reader<R, N> {
-    fn read(&mut self, mut buf: &mut [u8]) -> Result<usize, io::Error> {
+    /// Reads plain data straight into the tail of a large `buf` and encodes it in place, front to back, without staging it in `self.buf`.
+    ///
+    /// The plain bytes occupy the last three quarters of the usable output region, so the write cursor of each encoded chunk never overtakes the first unread plain byte.
+    fn read_direct(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        debug_assert!(self.temp_length == 0);
+        debug_assert!(self.buf_length < 3);
+
+        let blocks = buf.len() >> 2; // buf.len() / 4
+        let output_end = blocks << 2; // blocks * 4
+        let input_start = blocks; // output_end - blocks * 3
+
+        let carried_length = self.buf_length;
+
+        buf[input_start..input_start + carried_length]
+            .copy_from_slice(&self.buf[self.buf_offset..self.buf_offset + carried_length]);
+
+        let mut input_length = carried_length;
+        let mut eof = false;
+
+        while input_length < 3 {
+            match self.inner.read(&mut buf[(input_start + input_length)..output_end]) {
+                Ok(0) => {
+                    eof = true;
+
+                    break;
+                },
+                Ok(c) => {
+                    let start = input_start + input_length;
+
+                    self.digest.update(&buf[start..(start + c)]);
+                    self.progress.add_plain(c);
+
+                    input_length += c;
+                },
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {},
+                Err(e) => {
+                    // keep what has been read so far, it is less than one block
+                    self.buf[..input_length]
+                        .copy_from_slice(&buf[input_start..input_start + input_length]);
+                    self.buf_offset = 0;
+                    self.buf_length = input_length;
+
+                    return Err(e);
+                },
+            }
+        }
+
+        let encode_length = input_length - (input_length % 3);
+        let remainder_length = input_length - encode_length;
+
+        let mut remainder = [0; 3];
+        remainder[..remainder_length]
+            .copy_from_slice(&buf[(input_start + encode_length)..(input_start + input_length)]);
+
+        let mut output_length = encode_in_place(&self.engine, buf, input_start, encode_length);
+
+        if eof {
+            self.buf_offset = 0;
+            self.buf_length = 0;
+
+            if remainder_length > 0 {
+                output_length += self
+                    .engine
+                    .encode_slice(&remainder[..remainder_length], &mut buf[output_length..])
+                    .unwrap();
+            }
+        } else {
+            self.buf[..remainder_length].copy_from_slice(&remainder[..remainder_length]);
+            self.buf_offset = 0;
+            self.buf_length = remainder_length;
+        }
+
+        Ok(output_length)
+    }
+
+    fn read_encoded(&mut self, mut buf: &mut [u8]) -> Result<usize, io::Error> {
         let original_buf_length = buf.len();
 
         if buf.is_empty() {
@@ -191,6 +319,10 @@ impl<R: Read, const N: usize> Read for ToBase64Reader<R, N> {
             return Ok(original_buf_length - buf.len());
         }
 
+        if original_buf_length >= N {
+            return self.read_direct(buf);
+        }
+
         while self.buf_length < 3 {
             debug_assert!(self.buf_offset + self.buf_length <= N);
 
@@ -200,7 +332,14 @@ impl<R: Read, const N: usize> Read for ToBase64Reader<R, N> {
 
                     return Ok(original_buf_length - buf.len());
                 },
-                Ok(c) => self.buf_length += c,
+                Ok(c) => {
+                    let start = self.buf_offset + self.buf_length;
+
+                    self.digest.update(&self.buf[start..(start + c)]);
+                    self.progress.add_plain(c);
+
+                    self.buf_length += c;
+                },
                 Err(ref e) if e.kind() == ErrorKind::Interrupted => {},
                 Err(e) => return Err(e),
             }
@@ -210,6 +349,117 @@ impl<R: Read, const N: usize> Read for ToBase64Reader<R, N> {
 
         Ok(original_buf_length - buf.len())
     }
+
+    /// Returns the numbers of plain and base64 bytes which have been read or written so far.
+    #[inline]
+    pub fn progress(&self) -> Progress {
+        self.progress.progress()
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader along with the bytes taken from it but not returned yet, consuming this wrapper.
+    ///
+    /// The plain bytes have been read ahead and not encoded. The base64 bytes are encoded bytes which did not fit into the last read.
+    #[inline]
+    pub fn into_parts(self) -> (R, PendingBytes) {
+        let pending = PendingBytes::new(
+            self.buf[self.buf_offset..(self.buf_offset + self.buf_length)].to_vec(),
+            self.temp[..self.temp_length].to_vec(),
+            self.digest,
+        );
+
+        (self.inner, pending)
+    }
+
+    /// Returns the inner reader, consuming this wrapper.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for ToBase64Reader<R, N> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        let c = self.read_encoded(buf)?;
+
+        self.progress.add_encoded(c);
+
+        Ok(c)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize, io::Error> {
+        let mut total_length = 0;
+
+        for buf in bufs.iter_mut().filter(|buf| !buf.is_empty()) {
+            let buf_length = buf.len();
+
+            // only the first slice may wait for the inner reader, the others take what is buffered
+            let read_length = if total_length == 0 {
+                self.read(buf)?
+            } else {
+                let c = buf_length - self.drain_buffered(buf).len();
+
+                self.progress.add_encoded(c);
+
+                c
+            };
+
+            total_length += read_length;
+
+            if read_length < buf_length {
+                break;
+            }
+        }
+
+        Ok(total_length)
+    }
+}
+
+/// Encodes `buf[input_start..input_start + input_length]` (a whole number of 3-byte blocks) into `buf[..]` and returns the encoded length.
+///
+/// The input must not start before `input_length / 3`, i.e. it has to sit in the tail of the region its encoded form will occupy.
+fn encode_in_place(
+    engine: &Base64Codec,
+    buf: &mut [u8],
+    input_start: usize,
+    input_length: usize,
+) -> usize {
+    debug_assert!(input_length % 3 == 0);
+    debug_assert!(input_start >= input_length / 3);
+
+    let mut chunk = [0; IN_PLACE_CHUNK_SIZE];
+
+    let input_end = input_start + input_length;
+
+    let mut read_index = input_start;
+    let mut write_index = 0;
+
+    while read_index < input_end {
+        let chunk_length = (input_end - read_index).min(IN_PLACE_CHUNK_SIZE);
+
+        chunk[..chunk_length].copy_from_slice(&buf[read_index..(read_index + chunk_length)]);
+
+        read_index += chunk_length;
+
+        write_index +=
+            engine.encode_slice(&chunk[..chunk_length], &mut buf[write_index..read_index]).unwrap();
+    }
+
+    write_index
 }
 
 impl<R: Read> From<R> for ToBase64Reader<R> {
@@ -1,20 +1,29 @@
 use std::{
     fmt,
-    io::{self, Write},
+    io::{self, IoSlice, Write},
 };
 
-use base64::{
-    Engine,
-    engine::{GeneralPurpose, general_purpose::STANDARD},
+use base64::engine::general_purpose::STANDARD;
+
+#[cfg(feature = "digest")]
+use crate::plain_digest::DigestOutput;
+use crate::{
+    Base64Alphabet, Base64Variant, LineEnding, PendingBytes, Progress,
+    base64_alphabet::Base64Codec, plain_digest::PlainDigest, progress::ProgressCounter,
 };
 
 /// Write base64 data and encode them to plain data.
 pub struct ToBase64Writer<W: Write, const N: usize = 4096> {
-    inner:      W,
-    buf:        [u8; 3],
-    buf_length: usize,
-    temp:       [u8; N],
-    engine:     &'static GeneralPurpose,
+    inner:       W,
+    buf:         [u8; 3],
+    buf_length:  usize,
+    temp:        [u8; N],
+    engine:      Base64Codec,
+    digest:      PlainDigest,
+    progress:    ProgressCounter,
+    line_length: usize,
+    line_ending: LineEnding,
+    column:      usize,
 }
 
 impl<W: Write, const N: usize> fmt::Debug for ToBase64Writer<W, N> {
@@ -22,6 +31,7 @@ impl<W: Write, const N: usize> fmt::Debug for ToBase64Writer<W, N> {
         f.debug_struct("ToBase64Writer")
             .field("buf", &&self.buf[..self.buf_length])
             .field("buf_length", &self.buf_length)
+            .field("progress", &self.progress.progress())
             .finish_non_exhaustive()
     }
 }
@@ -38,41 +48,180 @@ impl<W: Write, const N: usize> ToBase64Writer<W, N> {
     pub fn new2(writer: W) -> ToBase64Writer<W, N> {
         const { assert!(N >= 4, "buffer size N must be at least 4") };
         ToBase64Writer {
-            inner:      writer,
-            buf:        [0; 3],
-            buf_length: 0,
-            temp:       [0u8; N],
-            engine:     &STANDARD,
+            inner:       writer,
+            buf:         [0; 3],
+            buf_length:  0,
+            temp:        [0u8; N],
+            engine:      Base64Codec::new(&STANDARD),
+            digest:      PlainDigest::default(),
+            progress:    ProgressCounter::default(),
+            line_length: 0,
+            line_ending: LineEnding::default(),
+            column:      0,
         }
     }
+
+    /// Encodes the plain data to `variant` instead of standard base64.
+    #[inline]
+    pub fn with_variant(mut self, variant: Base64Variant) -> Self {
+        self.engine = Base64Codec::new(variant.engine());
+
+        self
+    }
+
+    /// Encodes the plain data to the custom `alphabet` instead of standard base64.
+    #[inline]
+    pub fn with_alphabet(mut self, alphabet: &'static Base64Alphabet) -> Self {
+        self.engine = Base64Codec::from_alphabet(alphabet);
+
+        self
+    }
+
+    /// Wraps the output every `line_length` symbols with `line_ending`. No line ending is added after the last line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line_length` is 0.
+    #[inline]
+    pub fn with_line_wrap(mut self, line_length: usize, line_ending: LineEnding) -> Self {
+        assert!(line_length > 0, "line_length must be greater than 0");
+
+        self.line_length = line_length;
+        self.line_ending = line_ending;
+
+        self
+    }
+
+    /// Calls `callback` with the byte counts every time another `interval` plain bytes have been written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[inline]
+    pub fn with_progress<F: FnMut(Progress) + Send + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress.set_callback(interval, callback);
+
+        self
+    }
+
+    /// Feeds the plain data into `digest` as it is written.
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn with_digest<D: digest::DynDigest + Send + Sync + 'static>(mut self, digest: D) -> Self {
+        self.digest = PlainDigest::new(digest);
+
+        self
+    }
 }
 
 impl<W: Write, const N: usize> ToBase64Writer<W, N> {
+    /// Writes `self.temp[..length]` to the inner writer.
+    #[inline]
+    fn write_temp(&mut self, length: usize) -> Result<(), io::Error> {
+        if self.line_length == 0 {
+            self.inner.write_all(&self.temp[..length])?;
+        } else {
+            let mut data = &self.temp[..length];
+
+            while !data.is_empty() {
+                // the line ending is written lazily so that none follows the last line
+                if self.column == self.line_length {
+                    self.inner.write_all(self.line_ending.as_bytes())?;
+
+                    self.column = 0;
+                }
+
+                let c = (self.line_length - self.column).min(data.len());
+
+                self.inner.write_all(&data[..c])?;
+
+                self.column += c;
+
+                data = &data[c..];
+            }
+        }
+
+        self.progress.add_encoded(length);
+
+        Ok(())
+    }
+
     fn drain_block(&mut self) -> Result<(), io::Error> {
         debug_assert!(self.buf_length > 0);
 
         let encode_length =
             self.engine.encode_slice(&self.buf[..self.buf_length], &mut self.temp).unwrap();
 
-        self.inner.write_all(&self.temp[..encode_length])?;
+        self.write_temp(encode_length)?;
 
         self.buf_length = 0;
 
         Ok(())
     }
 
-    /// Finishes encoding buffered data and returns the inner writer.
-    #[inline]
-    pub fn finish(mut self) -> Result<W, io::Error> {
+    /// Encodes the final buffered data and flushes the inner writer.
+    fn drain_end(&mut self) -> Result<(), io::Error> {
         if self.buf_length > 0 {
             self.drain_block()?;
         }
 
-        self.inner.flush()?;
+        self.inner.flush()
+    }
+
+    /// Finishes encoding buffered data and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.drain_end()?;
 
         Ok(self.inner)
     }
 
+    /// Finishes encoding buffered data and returns the inner writer along with the digest of the plain data, or `None` if [`with_digest`](Self::with_digest) has not been called.
+    #[cfg(feature = "digest")]
+    #[inline]
+    pub fn finish_with_digest(mut self) -> Result<(W, Option<DigestOutput>), io::Error> {
+        self.drain_end()?;
+
+        let digest = self.digest.finalize();
+
+        Ok((self.inner, digest))
+    }
+
+    /// Returns the numbers of plain and base64 bytes which have been read or written so far.
+    #[inline]
+    pub fn progress(&self) -> Progress {
+        self.progress.progress()
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer along with the written bytes which have not reached it yet, consuming this wrapper without finishing it.
+    ///
+    /// The plain bytes are an incomplete block waiting for the rest of it. There are never pending base64 bytes because encoded data are written immediately.
+    #[inline]
+    pub fn into_parts(self) -> (W, PendingBytes) {
+        let pending =
+            PendingBytes::new(self.buf[..self.buf_length].to_vec(), Vec::new(), self.digest);
+
+        (self.inner, pending)
+    }
+
     /// Returns the inner writer, consuming this wrapper without finishing it.
     ///
     /// Call [`finish`](Self::finish) instead to encode the final buffered data and flush the inner writer.
@@ -83,56 +232,85 @@ impl<W: Write, const N: usize> ToBase64Writer<W, N> {
 }
 
 impl<W: Write, const N: usize> Write for ToBase64Writer<W, N> {
-    fn write(&mut self, mut buf: &[u8]) -> Result<usize, io::Error> {
-        let original_buf_length = buf.len();
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.write_vectored(&[IoSlice::new(buf)])
+    }
 
-        if self.buf_length == 0 {
-            while buf.len() >= 3 {
-                let max_available_buf_length = (buf.len() - (buf.len() % 3)).min((N >> 2) * 3); // (N / 4) * 3
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize, io::Error> {
+        let mut temp_length = 0;
+        let mut total_length = 0;
 
-                let encode_length = self
-                    .engine
-                    .encode_slice(&buf[..max_available_buf_length], &mut self.temp)
-                    .unwrap();
+        for buf in bufs {
+            let mut buf: &[u8] = buf;
 
-                buf = &buf[max_available_buf_length..];
+            total_length += buf.len();
 
-                self.inner.write_all(&self.temp[..encode_length])?;
-            }
+            if self.buf_length > 0 {
+                debug_assert!(self.buf_length < 3);
 
-            let buf_length = buf.len();
+                let drain_length = (3 - self.buf_length).min(buf.len());
 
-            if buf_length > 0 {
-                self.buf[..buf_length].copy_from_slice(&buf[..buf_length]);
+                self.buf[self.buf_length..self.buf_length + drain_length]
+                    .copy_from_slice(&buf[..drain_length]);
 
-                self.buf_length = buf_length;
-            }
-        } else {
-            debug_assert!(self.buf_length < 3);
+                buf = &buf[drain_length..];
 
-            let r = 3 - self.buf_length;
+                self.buf_length += drain_length;
 
-            let buf_length = buf.len();
+                if self.buf_length < 3 {
+                    continue;
+                }
 
-            let drain_length = r.min(buf_length);
+                if N - temp_length < 4 {
+                    self.write_temp(temp_length)?;
 
-            self.buf[self.buf_length..self.buf_length + drain_length]
-                .copy_from_slice(&buf[..drain_length]);
+                    temp_length = 0;
+                }
 
-            buf = &buf[drain_length..];
+                temp_length +=
+                    self.engine.encode_slice(&self.buf, &mut self.temp[temp_length..]).unwrap();
 
-            self.buf_length += drain_length;
+                self.buf_length = 0;
+            }
 
-            if self.buf_length == 3 {
-                self.drain_block()?;
+            while buf.len() >= 3 {
+                if N - temp_length < 4 {
+                    self.write_temp(temp_length)?;
 
-                if buf_length > r {
-                    self.write_all(buf)?;
+                    temp_length = 0;
                 }
+
+                let max_available_buf_length =
+                    (buf.len() - (buf.len() % 3)).min(((N - temp_length) >> 2) * 3); // ((N - temp_length) / 4) * 3
+
+                temp_length += self
+                    .engine
+                    .encode_slice(&buf[..max_available_buf_length], &mut self.temp[temp_length..])
+                    .unwrap();
+
+                buf = &buf[max_available_buf_length..];
             }
+
+            let buf_length = buf.len();
+
+            self.buf[..buf_length].copy_from_slice(buf);
+
+            self.buf_length = buf_length;
+        }
+
+        if temp_length > 0 {
+            self.write_temp(temp_length)?;
         }
 
-        Ok(original_buf_length)
+        // counted only once the call succeeds, so that a retry after an error is not counted twice
+        for buf in bufs {
+            self.digest.update(buf);
+        }
+
+        self.progress.add_plain(total_length);
+
+        Ok(total_length)
     }
 
     #[inline]
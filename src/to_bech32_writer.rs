@@ -0,0 +1,205 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+};
+
+use crate::{
+    Bech32Variant,
+    bech32::{CHARSET, CHECKSUM_LENGTH, MAX_HRP_LENGTH, hrp_checksum, is_hrp_char, polymod_step},
+    bit_regrouper::BitRegrouper,
+};
+
+/// Write plain data and encode them to a Bech32 or Bech32m string, i.e. a human-readable part, the separator `1`, the data regrouped into 5-bit characters and a 6-character checksum.
+///
+/// The data are encoded and written as they come, and only the checksum waits for [`finish`](Self::finish). The string is written in lowercase. The 90-character limit of Bitcoin addresses is not enforced, so that longer identifiers can be encoded, but the checksum only guarantees to detect errors in strings of up to 90 characters.
+pub struct ToBech32Writer<W: Write, const N: usize = 4096> {
+    inner:       W,
+    hrp:         Vec<u8>,
+    variant:     Bech32Variant,
+    began:       bool,
+    regrouper:   BitRegrouper,
+    chk:         u32,
+    values:      Vec<u8>,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for ToBech32Writer<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToBech32Writer")
+            .field("hrp", &String::from_utf8_lossy(&self.hrp))
+            .field("variant", &self.variant)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> ToBech32Writer<W> {
+    /// Creates a writer of a string with the human-readable part `hrp`, which is written in lowercase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hrp` is empty, longer than 83 characters or contains a character outside `!` to `~`.
+    #[inline]
+    pub fn new(writer: W, hrp: &str) -> ToBech32Writer<W> {
+        Self::new2(writer, hrp)
+    }
+}
+
+impl<W: Write, const N: usize> ToBech32Writer<W, N> {
+    /// Creates a writer of a string with the human-readable part `hrp`, which is written in lowercase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hrp` is empty, longer than 83 characters or contains a character outside `!` to `~`.
+    #[inline]
+    pub fn new2(writer: W, hrp: &str) -> ToBech32Writer<W, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+
+        assert!(
+            (1..=MAX_HRP_LENGTH).contains(&hrp.len()) && hrp.bytes().all(is_hrp_char),
+            "invalid human-readable part {hrp:?}"
+        );
+
+        let hrp = hrp.to_ascii_lowercase().into_bytes();
+
+        ToBech32Writer {
+            inner: writer,
+            chk: hrp_checksum(&hrp),
+            hrp,
+            variant: Bech32Variant::default(),
+            began: false,
+            regrouper: BitRegrouper::new(8, 5),
+            values: Vec::new(),
+            temp: Vec::with_capacity(N),
+            temp_offset: 0,
+        }
+    }
+
+    /// Computes the checksum of `variant` instead of Bech32.
+    #[inline]
+    pub fn with_variant(mut self, variant: Bech32Variant) -> Self {
+        self.variant = variant;
+
+        self
+    }
+}
+
+impl<W: Write, const N: usize> ToBech32Writer<W, N> {
+    /// Writes the encoded bytes which have not reached the inner writer yet.
+    fn drain_temp(&mut self) -> Result<(), io::Error> {
+        while self.temp_offset < self.temp.len() {
+            match self.inner.write(&self.temp[self.temp_offset..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(c) => self.temp_offset += c,
+                Err(error) if error.kind() == ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        self.temp.clear();
+        self.temp_offset = 0;
+
+        Ok(())
+    }
+
+    /// Puts the human-readable part and the separator in front of the data, once.
+    #[inline]
+    fn begin(&mut self) {
+        if !self.began {
+            self.temp.extend_from_slice(&self.hrp);
+            self.temp.push(b'1');
+
+            self.began = true;
+        }
+    }
+
+    /// Feeds the 5-bit values in `self.values` into the checksum and appends their characters to `self.temp`.
+    fn push_values(&mut self) {
+        for &value in &self.values {
+            self.chk = polymod_step(self.chk, value);
+            self.temp.push(CHARSET[usize::from(value)]);
+        }
+
+        self.values.clear();
+    }
+
+    /// Finishes encoding buffered data, writes the checksum and returns the inner writer.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.drain_temp()?;
+
+        self.begin();
+
+        self.regrouper.finish_padded(&mut self.values);
+        self.push_values();
+
+        let mut chk = self.chk;
+
+        for _ in 0..CHECKSUM_LENGTH {
+            chk = polymod_step(chk, 0);
+        }
+
+        chk ^= self.variant.constant();
+
+        for i in (0..CHECKSUM_LENGTH).rev() {
+            self.temp.push(CHARSET[(chk >> (5 * i) & 31) as usize]);
+        }
+
+        self.drain_temp()?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to encode the final buffered data and write the checksum.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, const N: usize> Write for ToBech32Writer<W, N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        // the bytes left by a failed call are written first
+        self.drain_temp()?;
+
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.begin();
+
+        let c = buf.len().min(N);
+
+        self.regrouper.push(&buf[..c], &mut self.values);
+        self.push_values();
+
+        // a failure is left to the next call along with the unwritten bytes, so that no error is returned after bytes have been taken
+        let _ = self.drain_temp();
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.drain_temp()?;
+
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+};
+
+use crate::{LineEnding, ToBase64Writer};
+
+/// The maximum length of a line of folded encoded-words.
+const MAX_LINE_LENGTH: usize = 75;
+const WORD_PREFIX: &[u8] = b"=?UTF-8?B?";
+const WORD_SUFFIX: &[u8] = b"?=";
+
+/// Returns how many plain bytes fit into an encoded-word of at most `length` characters.
+#[inline]
+const fn word_capacity(length: usize) -> usize {
+    (length.saturating_sub(WORD_PREFIX.len() + WORD_SUFFIX.len()) >> 2) * 3
+}
+
+/// Returns the length of the UTF-8 sequence started by `b`.
+#[inline]
+fn utf8_length(b: u8) -> Option<usize> {
+    match b {
+        0x00..=0x7F => Some(1),
+        0xC2..=0xDF => Some(2),
+        0xE0..=0xEF => Some(3),
+        0xF0..=0xF4 => Some(4),
+        _ => None,
+    }
+}
+
+/// Write a UTF-8 header value and encode it to folded RFC 2047 "B" encoded-words.
+///
+/// No line exceeds 75 characters, and no character is split across encoded-words. The words are folded with a line ending followed by a space.
+pub struct ToEncodedWordWriter<W: Write> {
+    inner:          W,
+    line_ending:    LineEnding,
+    word:           Vec<u8>,
+    word_length:    usize,
+    fold:           bool,
+    char_buf:       [u8; 4],
+    char_length:    usize,
+    char_remaining: usize,
+}
+
+impl<W: Write> fmt::Debug for ToEncodedWordWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToEncodedWordWriter")
+            .field("word", &self.word)
+            .field("word_length", &self.word_length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> ToEncodedWordWriter<W> {
+    #[inline]
+    pub fn new(writer: W) -> ToEncodedWordWriter<W> {
+        ToEncodedWordWriter {
+            inner:          writer,
+            line_ending:    LineEnding::CRLF,
+            word:           Vec::with_capacity(word_capacity(MAX_LINE_LENGTH)),
+            word_length:    MAX_LINE_LENGTH,
+            fold:           false,
+            char_buf:       [0; 4],
+            char_length:    0,
+            char_remaining: 0,
+        }
+    }
+
+    /// Folds the lines with `line_ending` instead of CRLF.
+    #[inline]
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+
+        self
+    }
+
+    /// Leaves room for `offset` characters, such as `Subject: `, before the first encoded-word. The first word goes to the next line if there is too little room left.
+    #[inline]
+    pub fn with_first_line_offset(mut self, offset: usize) -> Self {
+        self.word_length = MAX_LINE_LENGTH.saturating_sub(offset);
+
+        self
+    }
+
+    /// Writes the buffered bytes as an encoded-word.
+    fn write_word(&mut self) -> Result<(), io::Error> {
+        if self.fold {
+            self.inner.write_all(self.line_ending.as_bytes())?;
+            self.inner.write_all(b" ")?;
+        }
+
+        self.inner.write_all(WORD_PREFIX)?;
+
+        let mut writer = ToBase64Writer::<_, 64>::new2(&mut self.inner);
+
+        writer.write_all(&self.word)?;
+        writer.finish()?;
+
+        self.inner.write_all(WORD_SUFFIX)?;
+
+        self.word.clear();
+
+        // every later word starts a line after a space
+        self.fold = true;
+        self.word_length = MAX_LINE_LENGTH - 1;
+
+        Ok(())
+    }
+
+    /// Adds the complete character in `self.char_buf` to the current word.
+    fn push_char(&mut self) -> Result<(), io::Error> {
+        let c = &self.char_buf[..self.char_length];
+
+        if std::str::from_utf8(c).is_err() {
+            return Err(io::Error::new(ErrorKind::InvalidData, "invalid UTF-8 in a header value"));
+        }
+
+        if self.word.len() + c.len() > word_capacity(self.word_length) {
+            if self.word.is_empty() {
+                // the first line has too little room for even one character
+                self.fold = true;
+                self.word_length = MAX_LINE_LENGTH - 1;
+            } else {
+                self.write_word()?;
+            }
+        }
+
+        self.word.extend_from_slice(&self.char_buf[..self.char_length]);
+
+        self.char_length = 0;
+
+        Ok(())
+    }
+
+    /// Writes the last encoded-word and returns the inner writer.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        if self.char_remaining > 0 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "an incomplete UTF-8 character at the end of a header value",
+            ));
+        }
+
+        if !self.word.is_empty() {
+            self.write_word()?;
+        }
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ToEncodedWordWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        for &b in buf {
+            if self.char_remaining == 0 {
+                self.char_remaining = utf8_length(b).ok_or_else(|| {
+                    io::Error::new(ErrorKind::InvalidData, "invalid UTF-8 in a header value")
+                })?;
+            }
+
+            self.char_buf[self.char_length] = b;
+            self.char_length += 1;
+            self.char_remaining -= 1;
+
+            if self.char_remaining == 0 {
+                self.push_char()?;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> From<W> for ToEncodedWordWriter<W> {
+    #[inline]
+    fn from(writer: W) -> Self {
+        ToEncodedWordWriter::new(writer)
+    }
+}
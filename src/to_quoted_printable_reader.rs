@@ -0,0 +1,134 @@
+use std::{
+    fmt,
+    io::{self, Read},
+};
+
+use crate::{LineEnding, quoted_printable::QuotedPrintableEncoder};
+
+/// Read any data and encode them to quoted-printable data.
+///
+/// Line breaks in the plain data become CRLF hard line breaks, and longer lines are split with soft line breaks so that no line exceeds 76 characters.
+pub struct ToQuotedPrintableReader<R: Read, const N: usize = 4096> {
+    inner:       R,
+    buf:         [u8; N],
+    encoder:     QuotedPrintableEncoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    eof:         bool,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for ToQuotedPrintableReader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToQuotedPrintableReader")
+            .field("encoder", &self.encoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .field("eof", &self.eof)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> ToQuotedPrintableReader<R> {
+    #[inline]
+    pub fn new(reader: R) -> ToQuotedPrintableReader<R> {
+        Self::new2(reader)
+    }
+}
+
+impl<R: Read, const N: usize> ToQuotedPrintableReader<R, N> {
+    #[inline]
+    pub fn new2(reader: R) -> ToQuotedPrintableReader<R, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        ToQuotedPrintableReader {
+            inner:       reader,
+            buf:         [0u8; N],
+            encoder:     QuotedPrintableEncoder::new(),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+            eof:         false,
+        }
+    }
+
+    /// Ends the encoded lines with `line_ending` instead of CRLF.
+    #[inline]
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.encoder.set_line_ending(line_ending);
+
+        self
+    }
+
+    /// Encodes CR and LF like other bytes instead of turning them into hard line breaks, so that binary data survive.
+    #[inline]
+    pub fn with_binary(mut self) -> Self {
+        self.encoder.set_binary();
+
+        self
+    }
+}
+
+impl<R: Read, const N: usize> ToQuotedPrintableReader<R, N> {
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper and dropping the data buffered by it.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for ToQuotedPrintableReader<R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            let temp = &self.temp[self.temp_offset..];
+
+            if !temp.is_empty() {
+                let c = temp.len().min(buf.len());
+
+                buf[..c].copy_from_slice(&temp[..c]);
+
+                self.temp_offset += c;
+
+                return Ok(c);
+            }
+
+            if self.eof {
+                return Ok(0);
+            }
+
+            self.temp.clear();
+            self.temp_offset = 0;
+
+            let c = self.inner.read(&mut self.buf)?;
+
+            if c == 0 {
+                self.eof = true;
+
+                self.encoder.finish(&mut self.temp);
+            } else {
+                self.encoder.encode(&self.buf[..c], &mut self.temp);
+            }
+        }
+    }
+}
+
+impl<R: Read> From<R> for ToQuotedPrintableReader<R> {
+    #[inline]
+    fn from(reader: R) -> Self {
+        ToQuotedPrintableReader::new(reader)
+    }
+}
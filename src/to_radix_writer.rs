@@ -0,0 +1,153 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+};
+
+use crate::{Progress, RadixEngine, progress::ProgressCounter, radix::RadixEncoder};
+
+/// Write plain data and encode them with a [`RadixEngine`], such as base32.
+///
+/// The bits of an incomplete symbol are carried over to the next write, and are padded with zeros, along with the padding of the last block if any, at [`finish`](Self::finish).
+pub struct ToRadixWriter<W: Write, const N: usize = 4096> {
+    inner:       W,
+    encoder:     RadixEncoder,
+    temp:        Vec<u8>,
+    temp_offset: usize,
+    progress:    ProgressCounter,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for ToRadixWriter<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToRadixWriter")
+            .field("encoder", &self.encoder)
+            .field("temp", &&self.temp[self.temp_offset..])
+            .field("progress", &self.progress.progress())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> ToRadixWriter<W> {
+    #[inline]
+    pub fn new(writer: W, engine: &'static RadixEngine) -> ToRadixWriter<W> {
+        Self::new2(writer, engine)
+    }
+}
+
+impl<W: Write, const N: usize> ToRadixWriter<W, N> {
+    #[inline]
+    pub fn new2(writer: W, engine: &'static RadixEngine) -> ToRadixWriter<W, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        ToRadixWriter {
+            inner:       writer,
+            encoder:     RadixEncoder::new(engine),
+            temp:        Vec::with_capacity(N),
+            temp_offset: 0,
+            progress:    ProgressCounter::default(),
+        }
+    }
+
+    /// Calls `callback` with the byte counts every time another `interval` plain bytes have been written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    #[inline]
+    pub fn with_progress<F: FnMut(Progress) + Send + 'static>(
+        mut self,
+        interval: u64,
+        callback: F,
+    ) -> Self {
+        self.progress.set_callback(interval, callback);
+
+        self
+    }
+}
+
+impl<W: Write, const N: usize> ToRadixWriter<W, N> {
+    /// Writes the encoded bytes which have not reached the inner writer yet.
+    fn drain_temp(&mut self) -> Result<(), io::Error> {
+        while self.temp_offset < self.temp.len() {
+            match self.inner.write(&self.temp[self.temp_offset..]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(c) => {
+                    self.temp_offset += c;
+
+                    self.progress.add_encoded(c);
+                },
+                Err(error) if error.kind() == ErrorKind::Interrupted => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        self.temp.clear();
+        self.temp_offset = 0;
+
+        Ok(())
+    }
+
+    /// Finishes encoding buffered data and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.drain_temp()?;
+
+        self.encoder.finish(&mut self.temp);
+
+        self.drain_temp()?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Returns the numbers of plain and encoded bytes which have been written so far.
+    #[inline]
+    pub fn progress(&self) -> Progress {
+        self.progress.progress()
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to encode the final buffered data and flush the inner writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, const N: usize> Write for ToRadixWriter<W, N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        // the bytes left by a failed call are written first
+        self.drain_temp()?;
+
+        let c = buf.len().min(N);
+
+        self.encoder.encode(&buf[..c], &mut self.temp);
+
+        self.progress.add_plain(c);
+
+        // a failure is left to the next call along with the unwritten bytes, so that no error is returned after bytes have been taken
+        let _ = self.drain_temp();
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.drain_temp()?;
+
+        self.inner.flush()
+    }
+}
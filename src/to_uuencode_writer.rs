@@ -0,0 +1,177 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use crate::{LineEnding, UuencodeVariant, uuencode_variant::LINE_LENGTH};
+
+/// Write plain data and encode them to uuencoded data, framed by a `begin <mode> <name>` line and an `end` line.
+///
+/// Every full line holds 45 plain bytes. The `begin` line is written along with the first data.
+pub struct ToUuencodeWriter<W: Write> {
+    inner:       W,
+    variant:     UuencodeVariant,
+    line_ending: LineEnding,
+    mode:        u32,
+    name:        String,
+    began:       bool,
+    buf:         [u8; LINE_LENGTH],
+    buf_length:  usize,
+    temp:        Vec<u8>,
+}
+
+impl<W: Write> fmt::Debug for ToUuencodeWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToUuencodeWriter")
+            .field("variant", &self.variant)
+            .field("mode", &format_args!("{:03o}", self.mode))
+            .field("name", &self.name)
+            .field("buf", &&self.buf[..self.buf_length])
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> ToUuencodeWriter<W> {
+    /// Creates a writer of a file named `name` with the permission bits `mode`, such as `0o644`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty or contains a line break.
+    #[inline]
+    pub fn new<S: Into<String>>(writer: W, mode: u32, name: S) -> ToUuencodeWriter<W> {
+        let name = name.into();
+
+        assert!(
+            !name.is_empty() && !name.contains(['\r', '\n']),
+            "invalid uuencode file name {name:?}"
+        );
+
+        ToUuencodeWriter {
+            inner: writer,
+            variant: UuencodeVariant::Uuencode,
+            line_ending: LineEnding::LF,
+            mode,
+            name,
+            began: false,
+            buf: [0; LINE_LENGTH],
+            buf_length: 0,
+            temp: Vec::new(),
+        }
+    }
+
+    /// Encodes the plain data to `variant` instead of uuencode.
+    #[inline]
+    pub fn with_variant(mut self, variant: UuencodeVariant) -> Self {
+        self.variant = variant;
+
+        self
+    }
+
+    /// Ends the lines with `line_ending` instead of LF.
+    #[inline]
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+
+        self
+    }
+
+    /// Writes the `begin` line if it has not been written yet.
+    fn begin(&mut self) -> Result<(), io::Error> {
+        if !self.began {
+            write!(self.inner, "begin {:03o} {}", self.mode, self.name)?;
+
+            self.inner.write_all(self.line_ending.as_bytes())?;
+
+            self.began = true;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes `plain` to a line and writes it.
+    fn write_line(&mut self, plain: &[u8]) -> Result<(), io::Error> {
+        self.temp.clear();
+
+        self.variant.encode_line(plain, &mut self.temp);
+
+        self.temp.extend_from_slice(self.line_ending.as_bytes());
+
+        self.inner.write_all(&self.temp)
+    }
+
+    /// Finishes encoding buffered data, writes the `end` line and returns the inner writer.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.begin()?;
+
+        if self.buf_length > 0 {
+            let buf = self.buf;
+
+            self.write_line(&buf[..self.buf_length])?;
+
+            self.buf_length = 0;
+        }
+
+        // a zero-length line ends the data
+        self.write_line(&[])?;
+
+        self.inner.write_all(b"end")?;
+        self.inner.write_all(self.line_ending.as_bytes())?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to encode the final buffered data and write the `end` line.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ToUuencodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.begin()?;
+
+        if self.buf_length == LINE_LENGTH {
+            let line = self.buf;
+
+            self.write_line(&line)?;
+
+            self.buf_length = 0;
+        }
+
+        // a full line is written by the next call, so that no error is returned after bytes have been taken
+        let c = (LINE_LENGTH - self.buf_length).min(buf.len());
+
+        self.buf[self.buf_length..self.buf_length + c].copy_from_slice(&buf[..c]);
+
+        self.buf_length += c;
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
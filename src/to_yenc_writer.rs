@@ -0,0 +1,347 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+};
+
+use crate::{LineEnding, YencPart, crc32::Crc32};
+
+/// The default line length of yEnc encoders.
+const DEFAULT_LINE_LENGTH: usize = 128;
+
+/// Write plain data and encode them to yEnc data, framed by `=ybegin` and `=yend` lines with the CRC-32 of the data.
+///
+/// The size of the file has to be known in advance because it is declared in the `=ybegin` line. Writing more bytes than declared fails with [`InvalidInput`](ErrorKind::InvalidInput). The header is written along with the first data.
+pub struct ToYencWriter<W: Write, const N: usize = 4096> {
+    inner:       W,
+    temp:        [u8; N],
+    temp_length: usize,
+    name:        String,
+    size:        u64,
+    part:        Option<YencPart>,
+    file_crc32:  Option<u32>,
+    line_length: usize,
+    line_ending: LineEnding,
+    began:       bool,
+    column:      usize,
+    written:     u64,
+    crc32:       Crc32,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for ToYencWriter<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToYencWriter")
+            .field("name", &self.name)
+            .field("size", &self.size)
+            .field("part", &self.part)
+            .field("written", &self.written)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> ToYencWriter<W> {
+    /// Creates a writer of a file named `name` which is `size` bytes long.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty or contains a line break.
+    #[inline]
+    pub fn new<S: Into<String>>(writer: W, name: S, size: u64) -> ToYencWriter<W> {
+        Self::new2(writer, name, size)
+    }
+}
+
+impl<W: Write, const N: usize> ToYencWriter<W, N> {
+    /// Creates a writer of a file named `name` which is `size` bytes long.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty or contains a line break.
+    #[inline]
+    pub fn new2<S: Into<String>>(writer: W, name: S, size: u64) -> ToYencWriter<W, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+
+        let name = name.into();
+
+        assert!(
+            !name.is_empty() && !name.contains(['\r', '\n']),
+            "invalid yEnc file name {name:?}"
+        );
+
+        ToYencWriter {
+            inner: writer,
+            temp: [0; N],
+            temp_length: 0,
+            name,
+            size,
+            part: None,
+            file_crc32: None,
+            line_length: DEFAULT_LINE_LENGTH,
+            line_ending: LineEnding::CRLF,
+            began: false,
+            column: 0,
+            written: 0,
+            crc32: Crc32::default(),
+        }
+    }
+
+    /// Writes the segment `part` of the file instead of the whole file. Only the bytes of the part are written to this writer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `part` does not lie within the file.
+    #[inline]
+    pub fn with_part(mut self, part: YencPart) -> Self {
+        assert!(
+            part.begin >= 1 && part.begin <= part.end && part.end <= self.size,
+            "the part {}-{} does not lie within {} bytes",
+            part.begin,
+            part.end,
+            self.size
+        );
+
+        self.part = Some(part);
+
+        self
+    }
+
+    /// Declares `crc32` as the CRC-32 of the whole file in the `=yend` line of a part, which is usually done in the last part.
+    #[inline]
+    pub fn with_file_crc32(mut self, crc32: u32) -> Self {
+        self.file_crc32 = Some(crc32);
+
+        self
+    }
+
+    /// Wraps the lines after `line_length` characters instead of 128. A line may be one character longer when it ends with an escape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line_length` is 0.
+    #[inline]
+    pub fn with_line_length(mut self, line_length: usize) -> Self {
+        assert!(line_length > 0, "line_length must be greater than 0");
+
+        self.line_length = line_length;
+
+        self
+    }
+
+    /// Ends the lines with `line_ending` instead of CRLF.
+    #[inline]
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+
+        self
+    }
+}
+
+impl<W: Write, const N: usize> ToYencWriter<W, N> {
+    /// Returns the number of plain bytes to be written.
+    #[inline]
+    fn data_size(&self) -> u64 {
+        self.part.map_or(self.size, |part| part.size())
+    }
+
+    /// Writes the `=ybegin` line, and the `=ypart` line of a part, if they have not been written yet.
+    fn begin(&mut self) -> Result<(), io::Error> {
+        if self.began {
+            return Ok(());
+        }
+
+        let line_ending = self.line_ending.as_bytes();
+
+        match self.part {
+            Some(part) => {
+                write!(self.inner, "=ybegin part={}", part.number)?;
+
+                if let Some(total) = part.total {
+                    write!(self.inner, " total={total}")?;
+                }
+
+                write!(
+                    self.inner,
+                    " line={} size={} name={}",
+                    self.line_length, self.size, self.name
+                )?;
+                self.inner.write_all(line_ending)?;
+                write!(self.inner, "=ypart begin={} end={}", part.begin, part.end)?;
+            },
+            None => {
+                write!(
+                    self.inner,
+                    "=ybegin line={} size={} name={}",
+                    self.line_length, self.size, self.name
+                )?;
+            },
+        }
+
+        self.inner.write_all(line_ending)?;
+
+        self.began = true;
+
+        Ok(())
+    }
+
+    fn flush_temp(&mut self) -> Result<(), io::Error> {
+        self.inner.write_all(&self.temp[..self.temp_length])?;
+
+        self.temp_length = 0;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn push(&mut self, bytes: &[u8]) {
+        self.temp[self.temp_length..self.temp_length + bytes.len()].copy_from_slice(bytes);
+
+        self.temp_length += bytes.len();
+    }
+
+    /// Encodes `b` into `self.temp`, which must have room for at least 4 bytes.
+    fn encode_byte(&mut self, b: u8, last: bool) {
+        let e = b.wrapping_add(42);
+
+        let line_end = last || self.column + 1 >= self.line_length;
+
+        let escape = match e {
+            0 | b'\n' | b'\r' | b'=' => true,
+            // whitespace at either end of a line may be stripped in transport
+            b'\t' | b' ' => self.column == 0 || line_end,
+            // a dot at the start of a line is special in NNTP
+            b'.' => self.column == 0,
+            _ => false,
+        };
+
+        if escape {
+            self.push(&[b'=', e.wrapping_add(64)]);
+
+            self.column += 2;
+        } else {
+            self.push(&[e]);
+
+            self.column += 1;
+        }
+
+        if self.column >= self.line_length {
+            self.push(self.line_ending.as_bytes());
+
+            self.column = 0;
+        }
+    }
+
+    /// Finishes encoding buffered data, writes the `=yend` line and returns the inner writer.
+    ///
+    /// An error of the kind [`InvalidInput`](ErrorKind::InvalidInput) is returned if fewer bytes than declared have been written.
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        self.begin()?;
+
+        let data_size = self.data_size();
+
+        if self.written != data_size {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} bytes are written while {data_size} bytes are declared", self.written),
+            ));
+        }
+
+        self.flush_temp()?;
+
+        if self.column > 0 {
+            self.inner.write_all(self.line_ending.as_bytes())?;
+        }
+
+        let crc32 = self.crc32.value();
+
+        match self.part {
+            Some(part) => {
+                write!(
+                    self.inner,
+                    "=yend size={data_size} part={} pcrc32={crc32:08x}",
+                    part.number
+                )?;
+
+                if let Some(file_crc32) = self.file_crc32 {
+                    write!(self.inner, " crc32={file_crc32:08x}")?;
+                }
+            },
+            None => write!(self.inner, "=yend size={data_size} crc32={crc32:08x}")?,
+        }
+
+        self.inner.write_all(self.line_ending.as_bytes())?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to encode the final buffered data and write the `=yend` line.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, const N: usize> Write for ToYencWriter<W, N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.begin()?;
+
+        let data_size = self.data_size();
+
+        if self.written == data_size {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!("more than the declared {data_size} bytes are written"),
+            ));
+        }
+
+        if N - self.temp_length < 4 {
+            self.flush_temp()?;
+        }
+
+        let remaining = usize::try_from(data_size - self.written).unwrap_or(usize::MAX);
+
+        let mut c = 0;
+
+        // stop once `self.temp` is full, which is written by the next call, so that no error is returned after bytes have been taken
+        for &b in &buf[..buf.len().min(remaining)] {
+            if N - self.temp_length < 4 {
+                break;
+            }
+
+            c += 1;
+
+            self.encode_byte(b, c == remaining);
+        }
+
+        self.crc32.update(&buf[..c]);
+        self.written += c as u64;
+
+        Ok(c)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.flush_temp()?;
+
+        self.inner.flush()
+    }
+}
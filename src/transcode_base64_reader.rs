@@ -0,0 +1,192 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Read},
+};
+
+use base64::engine::GeneralPurpose;
+
+use crate::transcoder::{LineEnding, MAX_BLOCK_OUTPUT_LENGTH, Transcoder};
+
+/// Read base64 data and transcode them to base64 data of another engine or line wrapping.
+pub struct TranscodeBase64Reader<R: Read, const N: usize = 4096> {
+    inner:       R,
+    buf:         [u8; N],
+    buf_length:  usize,
+    buf_offset:  usize,
+    temp:        [u8; MAX_BLOCK_OUTPUT_LENGTH],
+    temp_length: usize,
+    temp_offset: usize,
+    transcoder:  Transcoder,
+    finished:    bool,
+}
+
+impl<R: Read, const N: usize> fmt::Debug for TranscodeBase64Reader<R, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TranscodeBase64Reader")
+            .field("buf_length", &self.buf_length)
+            .field("buf_offset", &self.buf_offset)
+            .field("temp", &&self.temp[self.temp_offset..(self.temp_offset + self.temp_length)])
+            .field("temp_length", &self.temp_length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read> TranscodeBase64Reader<R> {
+    /// Creates a reader which decodes `reader` with `decode_engine` and encodes the result with `encode_engine`.
+    #[inline]
+    pub fn new(
+        reader: R,
+        decode_engine: &'static GeneralPurpose,
+        encode_engine: &'static GeneralPurpose,
+    ) -> TranscodeBase64Reader<R> {
+        Self::new2(reader, decode_engine, encode_engine)
+    }
+}
+
+impl<R: Read, const N: usize> TranscodeBase64Reader<R, N> {
+    /// Creates a reader which decodes `reader` with `decode_engine` and encodes the result with `encode_engine`.
+    #[inline]
+    pub fn new2(
+        reader: R,
+        decode_engine: &'static GeneralPurpose,
+        encode_engine: &'static GeneralPurpose,
+    ) -> TranscodeBase64Reader<R, N> {
+        const { assert!(N >= 4, "buffer size N must be at least 4") };
+        TranscodeBase64Reader {
+            inner:       reader,
+            buf:         [0u8; N],
+            buf_length:  0,
+            buf_offset:  0,
+            temp:        [0; MAX_BLOCK_OUTPUT_LENGTH],
+            temp_length: 0,
+            temp_offset: 0,
+            transcoder:  Transcoder::new(decode_engine, encode_engine),
+            finished:    false,
+        }
+    }
+
+    /// Wraps the output every `line_length` symbols with `line_ending`. No line ending is added after the last line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line_length` is 0.
+    #[inline]
+    pub fn with_line_wrap(mut self, line_length: usize, line_ending: LineEnding) -> Self {
+        assert!(line_length > 0, "line_length must be greater than 0");
+
+        self.transcoder.set_line_wrap(line_length, line_ending);
+
+        self
+    }
+}
+
+impl<R: Read, const N: usize> TranscodeBase64Reader<R, N> {
+    #[inline]
+    fn drain_temp(&mut self, buf: &mut [u8]) -> usize {
+        debug_assert!(self.temp_length > 0);
+
+        let drain_length = buf.len().min(self.temp_length);
+
+        buf[..drain_length]
+            .copy_from_slice(&self.temp[self.temp_offset..(self.temp_offset + drain_length)]);
+
+        self.temp_offset += drain_length;
+        self.temp_length -= drain_length;
+
+        drain_length
+    }
+
+    /// Transcodes buffered data into `buf`, staging it in `self.temp` if `buf` is too short to hold a whole block.
+    fn drain(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        let input = &self.buf[self.buf_offset..(self.buf_offset + self.buf_length)];
+
+        let (consumed_length, output_length) = if buf.len() >= MAX_BLOCK_OUTPUT_LENGTH {
+            self.transcoder.transcode(input, buf)?
+        } else {
+            let (consumed_length, output_length) =
+                self.transcoder.transcode(input, &mut self.temp)?;
+
+            self.temp_offset = 0;
+            self.temp_length = output_length;
+
+            (consumed_length, 0)
+        };
+
+        self.buf_offset += consumed_length;
+        self.buf_length -= consumed_length;
+
+        if self.temp_length > 0 { Ok(self.drain_temp(buf)) } else { Ok(output_length) }
+    }
+
+    /// Transcodes the final block into `buf` once the inner reader reaches EOF.
+    fn drain_end(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        self.finished = true;
+
+        if buf.len() >= MAX_BLOCK_OUTPUT_LENGTH {
+            self.transcoder.finish(buf)
+        } else {
+            self.temp_offset = 0;
+            self.temp_length = self.transcoder.finish(&mut self.temp)?;
+
+            if self.temp_length > 0 { Ok(self.drain_temp(buf)) } else { Ok(0) }
+        }
+    }
+
+    /// Gets a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner reader.
+    ///
+    /// Reading from it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader, consuming this wrapper.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, const N: usize> Read for TranscodeBase64Reader<R, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.temp_length > 0 {
+            return Ok(self.drain_temp(buf));
+        }
+
+        loop {
+            if self.buf_length > 0 {
+                let c = self.drain(buf)?;
+
+                if c > 0 {
+                    return Ok(c);
+                }
+
+                // what was buffered is an incomplete block or line breaks
+                debug_assert!(self.buf_length == 0);
+            }
+
+            if self.finished {
+                return Ok(0);
+            }
+
+            self.buf_offset = 0;
+
+            match self.inner.read(&mut self.buf) {
+                Ok(0) => return self.drain_end(buf),
+                Ok(c) => self.buf_length = c,
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {},
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use base64::engine::GeneralPurpose;
+
+use crate::transcoder::{LineEnding, MAX_BLOCK_OUTPUT_LENGTH, Transcoder};
+
+/// Write base64 data and transcode them to base64 data of another engine or line wrapping.
+pub struct TranscodeBase64Writer<W: Write, const N: usize = 4096> {
+    inner:      W,
+    temp:       [u8; N],
+    transcoder: Transcoder,
+}
+
+impl<W: Write, const N: usize> fmt::Debug for TranscodeBase64Writer<W, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TranscodeBase64Writer").finish_non_exhaustive()
+    }
+}
+
+impl<W: Write> TranscodeBase64Writer<W> {
+    /// Creates a writer which decodes written data with `decode_engine` and encodes the result into `writer` with `encode_engine`.
+    #[inline]
+    pub fn new(
+        writer: W,
+        decode_engine: &'static GeneralPurpose,
+        encode_engine: &'static GeneralPurpose,
+    ) -> TranscodeBase64Writer<W> {
+        Self::new2(writer, decode_engine, encode_engine)
+    }
+}
+
+impl<W: Write, const N: usize> TranscodeBase64Writer<W, N> {
+    /// Creates a writer which decodes written data with `decode_engine` and encodes the result into `writer` with `encode_engine`.
+    #[inline]
+    pub fn new2(
+        writer: W,
+        decode_engine: &'static GeneralPurpose,
+        encode_engine: &'static GeneralPurpose,
+    ) -> TranscodeBase64Writer<W, N> {
+        const { assert!(N >= MAX_BLOCK_OUTPUT_LENGTH, "buffer size N must be at least 12") };
+        TranscodeBase64Writer {
+            inner:      writer,
+            temp:       [0u8; N],
+            transcoder: Transcoder::new(decode_engine, encode_engine),
+        }
+    }
+
+    /// Wraps the output every `line_length` symbols with `line_ending`. No line ending is added after the last line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line_length` is 0.
+    #[inline]
+    pub fn with_line_wrap(mut self, line_length: usize, line_ending: LineEnding) -> Self {
+        assert!(line_length > 0, "line_length must be greater than 0");
+
+        self.transcoder.set_line_wrap(line_length, line_ending);
+
+        self
+    }
+}
+
+impl<W: Write, const N: usize> TranscodeBase64Writer<W, N> {
+    /// Finishes transcoding buffered data and returns the inner writer.
+    #[inline]
+    pub fn finish(mut self) -> Result<W, io::Error> {
+        let temp_length = self.transcoder.finish(&mut self.temp)?;
+
+        if temp_length > 0 {
+            self.inner.write_all(&self.temp[..temp_length])?;
+        }
+
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+
+    /// Gets a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer.
+    ///
+    /// Writing to it directly skips the data buffered by this wrapper.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the inner writer, consuming this wrapper without finishing it.
+    ///
+    /// Call [`finish`](Self::finish) instead to transcode the final buffered data and flush the inner writer.
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, const N: usize> Write for TranscodeBase64Writer<W, N> {
+    fn write(&mut self, mut buf: &[u8]) -> Result<usize, io::Error> {
+        let original_buf_length = buf.len();
+
+        while !buf.is_empty() {
+            let (consumed_length, temp_length) = self.transcoder.transcode(buf, &mut self.temp)?;
+
+            if temp_length > 0 {
+                self.inner.write_all(&self.temp[..temp_length])?;
+            }
+
+            buf = &buf[consumed_length..];
+        }
+
+        Ok(original_buf_length)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,318 @@
+use std::io::{self, ErrorKind};
+
+use base64::{DecodeError, DecodeSliceError, Engine, engine::GeneralPurpose};
+
+/// Base64 bytes transcoded per step of [`Transcoder::transcode_run`]. Must be a multiple of 4.
+const CHUNK_SIZE: usize = 1024;
+
+/// The longest output of one 4-byte block, i.e. four symbols each preceded by a two-byte line ending.
+pub(crate) const MAX_BLOCK_OUTPUT_LENGTH: usize = 12;
+
+/// The line ending inserted between wrapped lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    LF,
+    /// `\r\n`
+    CRLF,
+}
+
+impl LineEnding {
+    /// Returns the bytes of this line ending.
+    #[inline]
+    pub const fn as_bytes(self) -> &'static [u8] {
+        match self {
+            LineEnding::LF => b"\n",
+            LineEnding::CRLF => b"\r\n",
+        }
+    }
+}
+
+#[inline]
+fn is_line_break(byte: &u8) -> bool {
+    matches!(byte, b'\r' | b'\n')
+}
+
+/// Decodes base64 data with one engine and encodes it again with another, block by block.
+///
+/// Line breaks in the source are skipped. Errors are reported at their offsets in the source, line breaks included.
+#[derive(Debug)]
+pub(crate) struct Transcoder {
+    decode_engine: &'static GeneralPurpose,
+    encode_engine: &'static GeneralPurpose,
+    line_length:   usize,
+    line_ending:   LineEnding,
+    line_position: usize,
+    block:         [u8; 4],
+    block_offsets: [usize; 4],
+    block_length:  usize,
+    offset:        usize,
+    symbol_count:  usize,
+    ended:         bool,
+}
+
+impl Transcoder {
+    #[inline]
+    pub(crate) fn new(
+        decode_engine: &'static GeneralPurpose,
+        encode_engine: &'static GeneralPurpose,
+    ) -> Transcoder {
+        Transcoder {
+            decode_engine,
+            encode_engine,
+            line_length: 0,
+            line_ending: LineEnding::LF,
+            line_position: 0,
+            block: [0; 4],
+            block_offsets: [0; 4],
+            block_length: 0,
+            offset: 0,
+            symbol_count: 0,
+            ended: false,
+        }
+    }
+
+    /// Wraps the output every `line_length` symbols. A `line_length` of 0 disables wrapping.
+    #[inline]
+    pub(crate) fn set_line_wrap(&mut self, line_length: usize, line_ending: LineEnding) {
+        self.line_length = line_length;
+        self.line_ending = line_ending;
+    }
+
+    /// Returns the length of `symbol_length` symbols once line endings have been inserted.
+    fn wrapped_length(&self, symbol_length: usize) -> usize {
+        if self.line_length == 0 || symbol_length == 0 {
+            symbol_length
+        } else {
+            let breaks = (self.line_position + symbol_length - 1) / self.line_length;
+
+            symbol_length + breaks * self.line_ending.as_bytes().len()
+        }
+    }
+
+    /// Returns how many whole blocks, at most `max_blocks`, can be written into `room` bytes.
+    fn fitting_blocks(&self, room: usize, max_blocks: usize) -> usize {
+        let mut blocks = max_blocks.min(room >> 2);
+
+        if self.line_length > 0 {
+            let ending_length = self.line_ending.as_bytes().len();
+
+            // an estimate from the average cost of a symbol, corrected below
+            blocks = blocks
+                .min(((room * self.line_length / (self.line_length + ending_length)) >> 2) + 1);
+
+            while blocks > 0 && self.wrapped_length(blocks << 2) > room {
+                blocks -= 1;
+            }
+        }
+
+        blocks
+    }
+
+    /// Copies `symbols` into `output`, inserting line endings where needed, and returns the written length.
+    fn wrap(&mut self, mut symbols: &[u8], output: &mut [u8]) -> usize {
+        if self.line_length == 0 {
+            output[..symbols.len()].copy_from_slice(symbols);
+
+            return symbols.len();
+        }
+
+        let ending = self.line_ending.as_bytes();
+
+        let mut output_index = 0;
+
+        while !symbols.is_empty() {
+            if self.line_position == self.line_length {
+                output[output_index..(output_index + ending.len())].copy_from_slice(ending);
+
+                output_index += ending.len();
+
+                self.line_position = 0;
+            }
+
+            let length = (self.line_length - self.line_position).min(symbols.len());
+
+            output[output_index..(output_index + length)].copy_from_slice(&symbols[..length]);
+
+            output_index += length;
+            symbols = &symbols[length..];
+
+            self.line_position += length;
+        }
+
+        output_index
+    }
+
+    /// Turns an error of decoding a slice into one positioned in the source.
+    ///
+    /// `offset_of` maps an index in the decoded slice to its offset in the source, and `symbol_start` is the number of symbols before the slice.
+    fn source_error(
+        error: DecodeSliceError,
+        offset_of: impl Fn(usize) -> usize,
+        symbol_start: usize,
+    ) -> io::Error {
+        let error = match error {
+            DecodeSliceError::DecodeError(DecodeError::InvalidByte(index, byte)) => {
+                DecodeError::InvalidByte(offset_of(index), byte)
+            },
+            DecodeSliceError::DecodeError(DecodeError::InvalidLastSymbol(index, byte)) => {
+                DecodeError::InvalidLastSymbol(offset_of(index), byte)
+            },
+            DecodeSliceError::DecodeError(DecodeError::InvalidLength(length)) => {
+                DecodeError::InvalidLength(symbol_start + length)
+            },
+            DecodeSliceError::DecodeError(error) => error,
+            DecodeSliceError::OutputSliceTooSmall => unreachable!(),
+        };
+
+        io::Error::new(ErrorKind::InvalidData, DecodeSliceError::DecodeError(error))
+    }
+
+    /// Transcodes the buffered block, which may be partial only at the end of the source.
+    fn transcode_block(&mut self, output: &mut [u8]) -> Result<usize, io::Error> {
+        debug_assert!(self.block_length > 0);
+
+        let mut decoded = [0; 3];
+
+        let decode_length = self
+            .decode_engine
+            .decode_slice(&self.block[..self.block_length], &mut decoded)
+            .map_err(|error| {
+                Self::source_error(
+                    error,
+                    |index| self.block_offsets[index],
+                    self.symbol_count - self.block_length,
+                )
+            })?;
+
+        if decode_length < 3 {
+            self.ended = true;
+        }
+
+        self.block_length = 0;
+
+        let mut encoded = [0; 4];
+
+        let encode_length =
+            self.encode_engine.encode_slice(&decoded[..decode_length], &mut encoded).unwrap();
+
+        Ok(self.wrap(&encoded[..encode_length], output))
+    }
+
+    /// Transcodes `run`, whole blocks without line breaks that start at the current offset.
+    fn transcode_run(&mut self, run: &[u8], output: &mut [u8]) -> Result<usize, io::Error> {
+        debug_assert!(run.len() & 0b11 == 0 && run.len() <= CHUNK_SIZE);
+        debug_assert!(self.block_length == 0);
+
+        let mut decoded = [0; (CHUNK_SIZE >> 2) * 3];
+
+        let offset = self.offset;
+
+        let decode_length =
+            self.decode_engine.decode_slice(run, &mut decoded).map_err(|error| {
+                Self::source_error(error, |index| offset + index, self.symbol_count)
+            })?;
+
+        if decode_length < (run.len() >> 2) * 3 {
+            self.ended = true;
+        }
+
+        self.offset += run.len();
+        self.symbol_count += run.len();
+
+        let mut encoded = [0; CHUNK_SIZE];
+
+        let encode_length =
+            self.encode_engine.encode_slice(&decoded[..decode_length], &mut encoded).unwrap();
+
+        Ok(self.wrap(&encoded[..encode_length], output))
+    }
+
+    /// Transcodes as much of `input` as fits into `output` and returns the consumed and the written lengths.
+    ///
+    /// An incomplete block is kept until more input arrives or [`finish`](Self::finish) is called. Nothing is consumed if `output` is shorter than [`MAX_BLOCK_OUTPUT_LENGTH`] and cannot hold the next block.
+    pub(crate) fn transcode(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(usize, usize), io::Error> {
+        let mut input_index = 0;
+        let mut output_index = 0;
+
+        if self.block_length == 4 {
+            if output.len() < self.wrapped_length(4) {
+                return Ok((0, 0));
+            }
+
+            output_index += self.transcode_block(output)?;
+        }
+
+        while input_index < input.len() {
+            let byte = input[input_index];
+
+            if is_line_break(&byte) {
+                input_index += 1;
+                self.offset += 1;
+
+                continue;
+            }
+
+            if self.block_length == 4 {
+                // the output has no room for the pending block
+                break;
+            }
+
+            if self.ended {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    DecodeSliceError::DecodeError(DecodeError::InvalidByte(self.offset, byte)),
+                ));
+            }
+
+            if self.block_length == 0 {
+                let run_length = input[input_index..]
+                    .iter()
+                    .position(is_line_break)
+                    .unwrap_or(input.len() - input_index);
+
+                let blocks = self.fitting_blocks(
+                    output.len() - output_index,
+                    (run_length >> 2).min(CHUNK_SIZE >> 2),
+                );
+
+                if blocks > 0 {
+                    let run_end = input_index + (blocks << 2);
+
+                    output_index += self
+                        .transcode_run(&input[input_index..run_end], &mut output[output_index..])?;
+
+                    input_index = run_end;
+
+                    continue;
+                }
+            }
+
+            self.block[self.block_length] = byte;
+            self.block_offsets[self.block_length] = self.offset;
+            self.block_length += 1;
+
+            input_index += 1;
+            self.offset += 1;
+            self.symbol_count += 1;
+
+            if self.block_length == 4 && output.len() - output_index >= self.wrapped_length(4) {
+                output_index += self.transcode_block(&mut output[output_index..])?;
+            }
+        }
+
+        Ok((input_index, output_index))
+    }
+
+    /// Transcodes the final, possibly partial, block and returns the written length. `output` must hold at least [`MAX_BLOCK_OUTPUT_LENGTH`] bytes.
+    pub(crate) fn finish(&mut self, output: &mut [u8]) -> Result<usize, io::Error> {
+        debug_assert!(output.len() >= MAX_BLOCK_OUTPUT_LENGTH);
+
+        if self.block_length > 0 { self.transcode_block(output) } else { Ok(0) }
+    }
+}
@@ -0,0 +1,107 @@
+/// The plain bytes on a full line of uuencoded or xxencoded data.
+pub(crate) const LINE_LENGTH: usize = 45;
+
+const XXENCODE_ALPHABET: &[u8; 64] =
+    b"+-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// The alphabet of the `begin`/`end` framed line encoding of the Unix `uuencode` tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UuencodeVariant {
+    /// `uuencode`, i.e. the characters from space to `_` where zero is written as a backtick.
+    #[default]
+    Uuencode,
+    /// `xxencode`, i.e. `+`, `-`, the digits and the letters.
+    Xxencode,
+}
+
+impl UuencodeVariant {
+    /// Returns the character of the 6-bit value `value`.
+    #[inline]
+    pub(crate) const fn encode_symbol(self, value: u8) -> u8 {
+        debug_assert!(value < 64);
+
+        match self {
+            // a space at the end of a line may be stripped in transport, so zero is a backtick
+            UuencodeVariant::Uuencode if value == 0 => b'`',
+            UuencodeVariant::Uuencode => value + b' ',
+            UuencodeVariant::Xxencode => XXENCODE_ALPHABET[value as usize],
+        }
+    }
+
+    /// Returns the 6-bit value of the character `symbol`. Both a space and a backtick are zero in uuencode.
+    #[inline]
+    pub(crate) const fn decode_symbol(self, symbol: u8) -> Option<u8> {
+        match self {
+            UuencodeVariant::Uuencode => match symbol {
+                b'`' => Some(0),
+                b' '..=b'_' => Some(symbol - b' '),
+                _ => None,
+            },
+            UuencodeVariant::Xxencode => match symbol {
+                b'+' => Some(0),
+                b'-' => Some(1),
+                b'0'..=b'9' => Some(symbol - b'0' + 2),
+                b'A'..=b'Z' => Some(symbol - b'A' + 12),
+                b'a'..=b'z' => Some(symbol - b'a' + 38),
+                _ => None,
+            },
+        }
+    }
+
+    /// Encodes up to 45 plain bytes to a line, without its line ending, and appends it to `output`.
+    pub(crate) fn encode_line(self, plain: &[u8], output: &mut Vec<u8>) {
+        debug_assert!(plain.len() <= LINE_LENGTH);
+
+        output.push(self.encode_symbol(plain.len() as u8));
+
+        for group in plain.chunks(3) {
+            let mut block = [0u8; 3];
+
+            block[..group.len()].copy_from_slice(group);
+
+            let [a, b, c] = block;
+
+            output.extend_from_slice(&[
+                self.encode_symbol(a >> 2),
+                self.encode_symbol((a & 0b11) << 4 | b >> 4),
+                self.encode_symbol((b & 0b1111) << 2 | c >> 6),
+                self.encode_symbol(c & 0b11_1111),
+            ]);
+        }
+    }
+
+    /// Decodes a line, without its line ending, and appends the plain bytes to `output`. Characters missing at the end of the line are taken as zeros.
+    ///
+    /// Returns `None` if the line contains an invalid character or its length is over 45.
+    pub(crate) fn decode_line(self, line: &[u8], output: &mut Vec<u8>) -> Option<()> {
+        let Some((&length, symbols)) = line.split_first() else {
+            // an empty line is a zero-length line whose length character has been stripped
+            return Some(());
+        };
+
+        let length = usize::from(self.decode_symbol(length)?);
+
+        if length > LINE_LENGTH {
+            return None;
+        }
+
+        let mut values = [0u8; 4];
+
+        for group in 0..length.div_ceil(3) {
+            for (i, value) in values.iter_mut().enumerate() {
+                *value = match symbols.get(group * 4 + i) {
+                    Some(&symbol) => self.decode_symbol(symbol)?,
+                    None => 0,
+                };
+            }
+
+            let [a, b, c, d] = values;
+
+            let block = [a << 2 | b >> 4, b << 4 | c >> 2, c << 6 | d];
+
+            output.extend_from_slice(&block[..(length - group * 3).min(3)]);
+        }
+
+        Some(())
+    }
+}
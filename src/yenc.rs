@@ -0,0 +1,65 @@
+/// The `=ypart` line of a segment of a multipart yEnc file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YencPart {
+    /// The 1-based number of this part.
+    pub number: u32,
+    /// The number of parts, if it is declared.
+    pub total:  Option<u32>,
+    /// The 1-based offset of the first byte of this part in the file.
+    pub begin:  u64,
+    /// The 1-based offset of the last byte of this part in the file, i.e. inclusive.
+    pub end:    u64,
+}
+
+impl YencPart {
+    /// Returns the number of bytes in this part.
+    #[inline]
+    pub const fn size(&self) -> u64 {
+        self.end + 1 - self.begin
+    }
+}
+
+/// The `=ybegin` line, and the `=ypart` line of a multipart file, of yEnc data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct YencHeader {
+    /// The name of the file.
+    pub name:        String,
+    /// The size of the whole file.
+    pub size:        u64,
+    /// The declared line length, if any.
+    pub line_length: Option<usize>,
+    /// The part, if the file is split into parts.
+    pub part:        Option<YencPart>,
+}
+
+/// The `=yend` line of yEnc data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YencTrailer {
+    /// The size of this part, or of the whole file if it is not split.
+    pub size:       u64,
+    /// The number of this part, if any.
+    pub part:       Option<u32>,
+    /// The CRC-32 of this part, if it is declared.
+    pub part_crc32: Option<u32>,
+    /// The CRC-32 of the whole file, if it is declared.
+    pub crc32:      Option<u32>,
+}
+
+/// Splits the parameters of a `=y` line into `key=value` pairs and the `name`, which is always the last one and may contain spaces.
+pub(crate) fn parse_params(s: &str) -> (Vec<(&str, &str)>, Option<&str>) {
+    let name_start =
+        s.match_indices("name=").map(|(i, _)| i).find(|&i| i == 0 || s.as_bytes()[i - 1] == b' ');
+
+    let (s, name) = match name_start {
+        Some(i) => (&s[..i], Some(s[i + 5..].trim_end())),
+        None => (s, None),
+    };
+
+    (s.split_ascii_whitespace().filter_map(|token| token.split_once('=')).collect(), name)
+}
+
+/// Finds the value of `key` among the pairs returned by [`parse_params`].
+#[inline]
+pub(crate) fn param<'a>(params: &[(&str, &'a str)], key: &str) -> Option<&'a str> {
+    params.iter().find(|(k, _)| *k == key).map(|(_, value)| *value)
+}
@@ -0,0 +1,45 @@
+use std::{error::Error, fmt};
+
+/// The error returned by [`FromYencReader`](crate::FromYencReader) when the decoded data do not match the `=yend` line. It is wrapped in an [`io::Error`](std::io::Error) of the kind [`InvalidData`](std::io::ErrorKind::InvalidData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YencError {
+    /// The number of decoded bytes differs from the declared size.
+    SizeMismatch {
+        /// The declared size.
+        expected: u64,
+        /// The number of decoded bytes.
+        actual:   u64,
+    },
+    /// The CRC-32 of the decoded bytes differs from the declared one.
+    Crc32Mismatch {
+        /// The declared CRC-32.
+        expected: u32,
+        /// The CRC-32 of the decoded bytes.
+        actual:   u32,
+    },
+}
+
+impl fmt::Display for YencError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YencError::SizeMismatch {
+                expected,
+                actual,
+            } => {
+                write!(f, "{actual} bytes are decoded while {expected} bytes are declared")
+            },
+            YencError::Crc32Mismatch {
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "the CRC-32 of the decoded data is {actual:08x} instead of {expected:08x}"
+                )
+            },
+        }
+    }
+}
+
+impl Error for YencError {}
@@ -0,0 +1,84 @@
+use base64_stream::{
+    DecodeBase64Ext, EncodeBase64Ext,
+    base64::{DecodeError, Engine, engine::general_purpose::STANDARD},
+};
+
+#[test]
+fn encode_iter() {
+    let test_data = b"Hi there, this is a simple sentence used for testing this crate. I hope all cases are correct.";
+
+    let base64: Vec<u8> = test_data.iter().copied().encode_base64().collect();
+
+    assert_eq!(STANDARD.encode(test_data).as_bytes(), base64.as_slice());
+
+    let base64: String = test_data.iter().copied().encode_base64_chars().collect();
+
+    assert_eq!(STANDARD.encode(test_data), base64);
+
+    assert_eq!(0, std::iter::empty().encode_base64().count());
+}
+
+#[test]
+fn encode_iter_chunks() {
+    let test_data = vec![0x5Au8; 1000];
+
+    let base64: Vec<u8> = test_data.chunks(7).encode_base64_chunks().collect();
+
+    assert_eq!(STANDARD.encode(&test_data).as_bytes(), base64.as_slice());
+
+    let base64: Vec<u8> =
+        [&b""[..], b"a", b"", b"bcd"].into_iter().encode_base64_chunks().collect();
+
+    assert_eq!(b"YWJjZA==", base64.as_slice());
+}
+
+#[test]
+fn decode_iter() {
+    let test_data: Vec<u8> = (0..=255).cycle().take(1000).collect();
+
+    let base64 = STANDARD.encode(&test_data);
+
+    let plain: Result<Vec<u8>, DecodeError> = base64.bytes().decode_base64().collect();
+
+    assert_eq!(test_data, plain.unwrap());
+
+    let plain: Result<Vec<u8>, DecodeError> =
+        base64.as_bytes().chunks(5).decode_base64_chunks().collect();
+
+    assert_eq!(test_data, plain.unwrap());
+}
+
+#[test]
+fn decode_iter_error() {
+    let mut iter = b"YWJj!A==".iter().copied().decode_base64();
+
+    assert!(matches!(iter.next(), Some(Err(DecodeError::InvalidByte(4, b'!')))));
+    assert!(iter.next().is_none());
+
+    let plain: Result<Vec<u8>, DecodeError> = b"YWJjZ".iter().copied().decode_base64().collect();
+
+    assert!(plain.is_err());
+}
+
+#[test]
+fn decode_iter_error_offset() {
+    // the invalid byte is beyond the buffers of the iterators
+    let valid = STANDARD.encode([0x5A; 150]).into_bytes();
+
+    let mut base64 = valid.clone();
+
+    base64[124] = b'!';
+
+    let plain: Result<Vec<u8>, DecodeError> = base64.iter().copied().decode_base64().collect();
+
+    assert_eq!(Err(DecodeError::InvalidByte(124, b'!')), plain);
+
+    let plain: Result<Vec<u8>, DecodeError> = base64.chunks(7).decode_base64_chunks().collect();
+
+    assert_eq!(Err(DecodeError::InvalidByte(124, b'!')), plain);
+
+    let plain: Result<Vec<u8>, DecodeError> =
+        valid[..197].iter().copied().decode_base64().collect();
+
+    assert_eq!(Err(DecodeError::InvalidLength(197)), plain);
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    io::Write,
+    path::Path,
+    process::{Command, Output, Stdio},
+    thread,
+};
+
+const DATA_FOLDER: &str = "data";
+const CLI_INPUT: &str = "cli_input.txt";
+
+fn run(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_base64-stream"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_vec();
+
+    let writer = thread::spawn(move || {
+        // the process may exit before reading everything
+        let _ = stdin.write_all(&input);
+    });
+
+    let output = child.wait_with_output().unwrap();
+
+    writer.join().unwrap();
+
+    output
+}
+
+fn assert_stdout(args: &[&str], input: &[u8], expected: &[u8]) {
+    let output = run(args, input);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(expected, output.stdout.as_slice());
+}
+
+fn assert_failure(args: &[&str], input: &[u8], message: &str) {
+    let output = run(args, input);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains(message));
+}
+
+#[test]
+fn encode_rfc4648() {
+    for (plain, base64) in [
+        ("", ""),
+        ("f", "Zg==\n"),
+        ("fo", "Zm8=\n"),
+        ("foo", "Zm9v\n"),
+        ("foob", "Zm9vYg==\n"),
+        ("fooba", "Zm9vYmE=\n"),
+        ("foobar", "Zm9vYmFy\n"),
+    ] {
+        assert_stdout(&[], plain.as_bytes(), base64.as_bytes());
+        assert_stdout(&["-d"], base64.as_bytes(), plain.as_bytes());
+    }
+}
+
+#[test]
+fn encode_wrap() {
+    let plain: Vec<u8> = (0..=255).collect();
+
+    assert_stdout(&[], &plain, b"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc4\nOTo7PD0+P0BBQkNERUZHSElKS0xNTk9QUVJTVFVWV1hZWltcXV5fYGFiY2RlZmdoaWprbG1ub3Bx\ncnN0dXZ3eHl6e3x9fn+AgYKDhIWGh4iJiouMjY6PkJGSk5SVlpeYmZqbnJ2en6ChoqOkpaanqKmq\nq6ytrq+wsbKztLW2t7i5uru8vb6/wMHCw8TFxsfIycrLzM3Oz9DR0tPU1dbX2Nna29zd3t/g4eLj\n5OXm5+jp6uvs7e7v8PHy8/T19vf4+fr7/P3+/w==\n");
+
+    assert_stdout(&["-w", "4"], b"foobar", b"Zm9v\nYmFy\n");
+    assert_stdout(&["-w4"], b"foobar", b"Zm9v\nYmFy\n");
+    assert_stdout(&["--wrap=5"], b"foobar", b"Zm9vY\nmFy\n");
+    assert_stdout(&["--wrap", "0"], b"foobar", b"Zm9vYmFy");
+    assert_stdout(&["-w", "0"], b"", b"");
+
+    assert_failure(&["-w", "x"], b"", "invalid wrap size");
+}
+
+#[test]
+fn encode_variants() {
+    assert_stdout(&[], b"\xfb\xff", b"+/8=\n");
+    assert_stdout(&["--url"], b"\xfb\xff", b"-_8=\n");
+    assert_stdout(&["--no-pad"], b"\xfb\xff", b"+/8\n");
+    assert_stdout(&["--url", "--no-pad"], b"\xfb\xff", b"-_8\n");
+
+    assert_stdout(&["-d", "--url"], b"-_8=\n", b"\xfb\xff");
+    assert_stdout(&["-d", "--url", "--no-pad"], b"-_8", b"\xfb\xff");
+
+    assert_failure(&["-d"], b"-_8=\n", "invalid input");
+    assert_failure(&["-d", "--no-pad"], b"+/8=", "invalid input");
+}
+
+#[test]
+fn decode_ignore_garbage() {
+    assert_stdout(&["-d"], b"Zm9v\nYmFy\n", b"foobar");
+
+    assert_failure(&["-d"], b"Zm9v*YmFy\n", "invalid input");
+
+    assert_stdout(&["-d", "-i"], b"Zm9v*Ym\r\nFy\x00\n", b"foobar");
+    assert_stdout(&["-di"], b"Zm9v*YmFy", b"foobar");
+    assert_stdout(&["--decode", "--ignore-garbage", "--url"], b"-_8=+/", b"\xfb\xff");
+}
+
+#[test]
+fn file_input() {
+    let file_path = Path::new("tests").join(DATA_FOLDER).join(CLI_INPUT);
+
+    std::fs::write(file_path.as_path(), b"foobar").unwrap();
+
+    let file_path = file_path.to_str().unwrap();
+
+    assert_stdout(&[file_path], b"", b"Zm9vYmFy\n");
+    assert_stdout(&["-"], b"foobar", b"Zm9vYmFy\n");
+
+    assert_failure(&["tests/data/nonexistent.txt"], b"", "nonexistent.txt");
+    assert_failure(&[file_path, "-"], b"", "extra operand");
+    assert_failure(&["-x"], b"", "invalid option");
+}
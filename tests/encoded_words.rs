@@ -0,0 +1,130 @@
+use std::io::Write;
+
+use base64_stream::{
+    EncodedWordError, EncodedWords, HeaderSegment, LineEnding, ToEncodedWordWriter,
+    decode_encoded_words,
+};
+
+fn encode(value: &str, offset: usize) -> String {
+    let mut writer = ToEncodedWordWriter::new(Vec::new()).with_first_line_offset(offset);
+
+    // stream the value in pieces which split characters
+    for chunk in value.as_bytes().chunks(5) {
+        writer.write_all(chunk).unwrap();
+    }
+
+    String::from_utf8(writer.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn encode_encoded_words() {
+    assert_eq!("=?UTF-8?B?SGkgdGhlcmU=?=", encode("Hi there", 0));
+    assert_eq!("", encode("", 0));
+
+    let value = "日本語のテキストと English text が混在する、とても長い件名の例です。🍣🍺";
+
+    for offset in [0, 9, 60, 70, 75] {
+        let encoded = encode(value, offset);
+        let lines: Vec<&str> = encoded.split("\r\n").collect();
+
+        assert!(lines.len() > 1);
+        assert!(offset + lines[0].len() <= 75, "{encoded}");
+
+        for (i, line) in lines.iter().enumerate() {
+            assert!(line.len() <= 75, "{encoded}");
+
+            if i > 0 {
+                assert!(line.starts_with(' '));
+            }
+
+            // every word holds whole characters on its own
+            for word in EncodedWords::new(line.trim_start()) {
+                match word.unwrap() {
+                    HeaderSegment::Word {
+                        charset,
+                        data,
+                        ..
+                    } => {
+                        assert_eq!("UTF-8", charset);
+                        assert!(std::str::from_utf8(&data).is_ok());
+                    },
+                    HeaderSegment::Text(text) => assert!(text.is_empty(), "{text}"),
+                }
+            }
+        }
+
+        assert_eq!(value, decode_encoded_words(encoded.trim_start()).unwrap());
+    }
+}
+
+#[test]
+fn encode_encoded_words_options() {
+    let mut writer = ToEncodedWordWriter::new(Vec::new()).with_line_ending(LineEnding::LF);
+
+    writer.write_all("a".repeat(100).as_bytes()).unwrap();
+
+    let encoded = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    assert!(encoded.contains("?=\n =?UTF-8?B?"));
+    assert!(!encoded.contains('\r'));
+
+    let mut writer = ToEncodedWordWriter::new(Vec::new());
+
+    assert!(writer.write_all(b"\xff").is_err());
+
+    let mut writer = ToEncodedWordWriter::new(Vec::new());
+
+    writer.write_all(b"\xe6\x97").unwrap();
+
+    assert!(writer.finish().is_err());
+}
+
+#[test]
+fn decode_encoded_words_rfc2047() {
+    for (encoded, decoded) in [
+        ("(=?ISO-8859-1?Q?a?=)", "(=?ISO-8859-1?Q?a?=)"),
+        ("(=?ISO-8859-1?B?YQ==?=)", "(a)"),
+        ("(=?ISO-8859-1?B?YQ==?= b)", "(a b)"),
+        ("(=?ISO-8859-1?B?YQ==?= =?ISO-8859-1?B?Yg==?=)", "(ab)"),
+        ("(=?ISO-8859-1?B?YQ==?=  \r\n  =?ISO-8859-1?B?Yg==?=)", "(ab)"),
+        ("=?iso-8859-1?b?SvZyZw==?= <jorg@example.com>", "Jörg <jorg@example.com>"),
+        ("=?US-ASCII*EN?B?S2VpdGggTW9vcmU=?=", "Keith Moore"),
+        ("=?utf-8?B?5pel5pys?= =?UTF-8?B?6Kqe?=", "日本語"),
+        // a character split across adjacent words
+        ("=?UTF-8?B?5pel5pw=?= =?UTF-8?B?rOiqng==?=", "日本語"),
+        ("=? broken ?= and =?UTF-8?B?SGk=?=", "=? broken ?= and Hi"),
+    ] {
+        assert_eq!(decoded, decode_encoded_words(encoded).unwrap(), "{encoded}");
+    }
+
+    let segments: Vec<HeaderSegment> =
+        EncodedWords::new("=?US-ASCII*EN?B?SGk=?= x").map(Result::unwrap).collect();
+
+    assert_eq!(
+        vec![
+            HeaderSegment::Word {
+                charset:  "US-ASCII",
+                language: Some("EN"),
+                data:     b"Hi".to_vec(),
+            },
+            HeaderSegment::Text(" x"),
+        ],
+        segments
+    );
+}
+
+#[test]
+fn decode_encoded_words_errors() {
+    assert_eq!(
+        Err(EncodedWordError::UnsupportedCharset(String::from("ISO-8859-2"))),
+        decode_encoded_words("=?ISO-8859-2?B?YQ==?=")
+    );
+    assert_eq!(
+        Err(EncodedWordError::InvalidText(String::from("US-ASCII"))),
+        decode_encoded_words("=?US-ASCII?B?/w==?=")
+    );
+    assert!(matches!(decode_encoded_words("=?UTF-8?B?S!k=?="), Err(EncodedWordError::Base64(_))));
+    // unpadded and URL-safe text are not allowed by RFC 2047
+    assert!(matches!(decode_encoded_words("=?UTF-8?B?YQ?="), Err(EncodedWordError::Base64(_))));
+    assert!(matches!(decode_encoded_words("=?UTF-8?B?-_8=?="), Err(EncodedWordError::Base64(_))));
+}
@@ -0,0 +1,49 @@
+use std::io::{self, ErrorKind, Read};
+
+use base64_stream::{Base45Error, FromBase45Reader};
+
+struct OneByteReader<'a>(&'a [u8]);
+
+impl Read for OneByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+
+        buf[0] = self.0[0];
+        self.0 = &self.0[1..];
+
+        Ok(1)
+    }
+}
+
+fn decode(base45: &[u8]) -> io::Result<Vec<u8>> {
+    let mut plain = Vec::new();
+
+    FromBase45Reader::new(OneByteReader(base45)).read_to_end(&mut plain)?;
+
+    Ok(plain)
+}
+
+#[test]
+fn decode_read() {
+    assert_eq!(b"".as_slice(), decode(b"").unwrap());
+    assert_eq!(b"AB".as_slice(), decode(b"BB8").unwrap());
+    assert_eq!(b"ietf!".as_slice(), decode(b"QED8WEX0").unwrap());
+    assert_eq!(b"Hello!!".as_slice(), decode(b"%69 VD92EX0").unwrap());
+}
+
+#[test]
+fn decode_invalid_read() {
+    for (base45, expected) in [
+        (b"BB8GGW".as_slice(), Base45Error::OutOfRange(3)),
+        (b"QED8:/".as_slice(), Base45Error::OutOfRange(3)),
+        (b"BB8Q".as_slice(), Base45Error::TruncatedBlock(3)),
+        (b"BB8QEd".as_slice(), Base45Error::InvalidSymbol(5, b'd')),
+    ] {
+        let error = decode(base45).unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidData, error.kind());
+        assert_eq!(Some(&expected), error.get_ref().unwrap().downcast_ref());
+    }
+}
@@ -0,0 +1,81 @@
+use std::io::{ErrorKind, Write};
+
+use base64_stream::{Base45Error, FromBase45Writer};
+
+#[test]
+fn decode_write() {
+    let mut writer = FromBase45Writer::new(Vec::new());
+
+    for chunk in [b"%".as_slice(), b"69 V", b"D92E", b"X", b"0"] {
+        writer.write_all(chunk).unwrap();
+    }
+
+    // only the final pair, which may still be the start of a triplet, is held back
+    assert_eq!(b"Hello!".as_slice(), writer.get_ref());
+    assert_eq!(b"Hello!!".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+fn decode_invalid_write() {
+    let mut writer = FromBase45Writer::new(Vec::new());
+
+    let error = writer.write_all(b"BB8GGW").unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+    assert_eq!(Some(&Base45Error::OutOfRange(3)), error.get_ref().unwrap().downcast_ref());
+
+    let mut writer = FromBase45Writer::new(Vec::new());
+
+    writer.write_all(b"QED8WEX").unwrap();
+
+    let error = writer.finish().unwrap_err();
+
+    assert_eq!(Some(&Base45Error::TruncatedBlock(6)), error.get_ref().unwrap().downcast_ref());
+}
+
+#[test]
+fn decode_retry_write() {
+    use std::io;
+
+    struct WouldBlockOnceWriter {
+        data:    Vec<u8>,
+        blocked: bool,
+    }
+
+    impl Write for WouldBlockOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.blocked {
+                self.blocked = true;
+
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = WouldBlockOnceWriter {
+        data: Vec::new(), blocked: false
+    };
+
+    let mut writer = FromBase45Writer::new(inner);
+
+    // an error means that nothing has been taken, so the same bytes are written again
+    let mut input = b"%69 VD92EX0".as_slice();
+
+    while !input.is_empty() {
+        match writer.write(input) {
+            Ok(c) => input = &input[c..],
+            Err(error) => assert_eq!(io::ErrorKind::WouldBlock, error.kind()),
+        }
+    }
+
+    assert!(writer.get_ref().blocked);
+    assert_eq!(b"Hello!!".as_slice(), writer.finish().unwrap().data);
+}
@@ -0,0 +1,132 @@
+use std::io::{self, ErrorKind, Read};
+
+use base64_stream::{Base58Alphabet, Base58Error, FromBase58Reader};
+
+struct OneByteReader<'a>(&'a [u8]);
+
+impl Read for OneByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+
+        buf[0] = self.0[0];
+        self.0 = &self.0[1..];
+
+        Ok(1)
+    }
+}
+
+fn decode(base58: &[u8]) -> io::Result<Vec<u8>> {
+    let mut plain = Vec::new();
+
+    FromBase58Reader::new(base58).read_to_end(&mut plain)?;
+
+    Ok(plain)
+}
+
+#[test]
+fn decode_read() {
+    assert_eq!(b"".as_slice(), decode(b"").unwrap());
+    assert_eq!(b"Hello World!".as_slice(), decode(b"2NEpo7TZRRrLZSi2U").unwrap());
+    assert_eq!(b"\x00\x00\x28\x7f\xb4\xcd".as_slice(), decode(b"11233QC4").unwrap());
+    assert_eq!(b"\x00\x00\x00".as_slice(), decode(b"111").unwrap());
+}
+
+#[test]
+fn decode_invalid_read() {
+    for (base58, offset, byte) in [(b"2NEpo0".as_slice(), 5, b'0'), (b"Il".as_slice(), 0, b'I')] {
+        let error = decode(base58).unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidData, error.kind());
+        assert_eq!(
+            Some(&Base58Error::InvalidSymbol(offset, byte)),
+            error.get_ref().unwrap().downcast_ref()
+        );
+    }
+}
+
+#[test]
+fn decode_alphabet_read() {
+    let mut plain = Vec::new();
+
+    FromBase58Reader::new(OneByteReader(b"2nePN7syqqRkyrH2t"))
+        .with_alphabet(Base58Alphabet::Flickr)
+        .read_to_end(&mut plain)
+        .unwrap();
+
+    assert_eq!(b"Hello World!".as_slice(), plain);
+}
+
+#[cfg(feature = "base58check")]
+#[test]
+fn decode_check_read() {
+    let mut plain = Vec::new();
+
+    FromBase58Reader::new(b"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM".as_slice())
+        .with_check()
+        .read_to_end(&mut plain)
+        .unwrap();
+
+    assert_eq!(
+        b"\x00\x01\x09\x66\x77\x60\x06\x95\x3d\x55\x67\x43\x9e\x5e\x39\xf8\x6a\x0d\x27\x3b\xee"
+            .as_slice(),
+        plain
+    );
+
+    for (base58, expected) in [
+        (b"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN".as_slice(), Base58Error::ChecksumMismatch),
+        (b"2NEp".as_slice(), Base58Error::MissingChecksum),
+    ] {
+        let error =
+            FromBase58Reader::new(base58).with_check().read_to_end(&mut Vec::new()).unwrap_err();
+
+        assert_eq!(ErrorKind::InvalidData, error.kind());
+        assert_eq!(Some(&expected), error.get_ref().unwrap().downcast_ref());
+    }
+}
+
+#[cfg(feature = "base58check")]
+#[test]
+fn round_trip_read() {
+    use std::io::Write;
+
+    use base64_stream::ToBase58Writer;
+
+    let plain = (0..5000u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+        .chain([0; 3])
+        .collect::<Vec<u8>>();
+
+    for alphabet in [Base58Alphabet::Bitcoin, Base58Alphabet::Ripple, Base58Alphabet::Flickr] {
+        let mut writer = ToBase58Writer::new(Vec::new()).with_alphabet(alphabet).with_check();
+
+        writer.write_all(&[0, 0]).unwrap();
+        writer.write_all(&plain).unwrap();
+
+        let base58 = writer.finish().unwrap();
+
+        let mut decoded = Vec::new();
+
+        FromBase58Reader::new(base58.as_slice())
+            .with_alphabet(alphabet)
+            .with_check()
+            .read_to_end(&mut decoded)
+            .unwrap();
+
+        assert_eq!([[0, 0].as_slice(), &plain].concat(), decoded);
+    }
+}
+
+#[test]
+fn decode_limit_read() {
+    use base64_stream::LimitExceeded;
+
+    let error = FromBase58Reader::new(OneByteReader(b"2NEpo7TZRRrLZSi2U"))
+        .with_limit(16)
+        .read_to_end(&mut Vec::new())
+        .unwrap_err();
+
+    assert_eq!(ErrorKind::Other, error.kind());
+    assert_eq!(Some(&LimitExceeded::Encoded(16)), error.get_ref().unwrap().downcast_ref());
+}
@@ -0,0 +1,55 @@
+use std::io::{Cursor, ErrorKind, Read};
+
+use base64_stream::FromBase64JsonReader;
+
+#[test]
+fn decode_json_read() {
+    let mut reader = FromBase64JsonReader::new(Cursor::new(
+        br#" "SGkgdGhlcmUsIHRoaXMgaXMgYSBzaW1wbGUgc2VudGVuY2UgdXNlZCBmb3IgdGVzdGluZyB0aGlzIGNyYXRlLiBJIGhvcGUgYWxsIGNhc2VzIGFyZSBjb3JyZWN0Lg==", "next": 1}"#.to_vec(),
+    ));
+
+    let mut test_data = String::new();
+
+    reader.read_to_string(&mut test_data).unwrap();
+
+    assert_eq!(
+        "Hi there, this is a simple sentence used for testing this crate. I hope all cases are \
+         correct.",
+        test_data
+    );
+
+    let mut rest = String::new();
+
+    reader.into_rest().read_to_string(&mut rest).unwrap();
+
+    assert_eq!(r#", "next": 1}"#, rest);
+}
+
+#[test]
+fn decode_json_escapes() {
+    let mut reader =
+        FromBase64JsonReader::<_, 4>::new2(Cursor::new(br#""+\/8A\r\n+\u002F/\/""#.to_vec()));
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"\xfb\xff\x00\xfb\xff\xff", test_data.as_slice());
+}
+
+#[test]
+fn decode_json_errors() {
+    for (json, kind) in [
+        (&br#"123"#[..], ErrorKind::InvalidData),
+        (br#""YWJj"#, ErrorKind::UnexpectedEof),
+        (br#""YWJj\"#, ErrorKind::UnexpectedEof),
+        (br#""YW\qJj""#, ErrorKind::InvalidData),
+        (b"\"YW\xc3\xa9Jj\"", ErrorKind::InvalidData),
+        (b"\"YW\nJj\"", ErrorKind::InvalidData),
+        (br#""YW!j""#, ErrorKind::InvalidData),
+    ] {
+        let mut reader = FromBase64JsonReader::new(Cursor::new(json.to_vec()));
+
+        assert_eq!(kind, reader.read_to_end(&mut Vec::new()).unwrap_err().kind(), "{json:?}");
+    }
+}
@@ -1,4 +1,4 @@
-use std::io::{Cursor, Error, ErrorKind, Read};
+use std::io::{Cursor, Error, ErrorKind, IoSliceMut, Read};
 
 use base64_stream::FromBase64Reader;
 
@@ -24,6 +24,19 @@ impl Read for CountedReader {
     }
 }
 
+#[derive(Debug)]
+struct OneByteReader {
+    data: Cursor<Vec<u8>>,
+}
+
+impl Read for OneByteReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let length = buf.len().min(1);
+
+        self.data.read(&mut buf[..length])
+    }
+}
+
 #[derive(Debug)]
 struct FailAfterFirstRead {
     data:       Cursor<&'static [u8]>,
@@ -217,3 +230,508 @@ fn decode_small_buffer() {
 
     assert_eq!(out, b"abcd");
 }
+
+fn read_in_chunks<R: Read>(mut reader: R, chunk_size: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut chunk = vec![0u8; chunk_size];
+
+    loop {
+        let c = reader.read(&mut chunk).unwrap();
+
+        if c == 0 {
+            return out;
+        }
+
+        out.extend_from_slice(&chunk[..c]);
+    }
+}
+
+#[test]
+fn decode_large_destination() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    for length in [0, 1, 2, 3, 4, 5, 767, 768, 769, 100_000] {
+        let plain: Vec<u8> = (0u8..=255).cycle().take(length).collect();
+        let encoded = STANDARD.encode(&plain);
+
+        let reader = FromBase64Reader::new(Cursor::new(encoded.into_bytes()));
+
+        assert_eq!(read_in_chunks(reader, 65536), plain);
+    }
+}
+
+#[test]
+fn decode_large_destination_after_small_read() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10_000).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let mut reader = FromBase64Reader::<_, 16>::new2(Cursor::new(encoded.into_bytes()));
+
+    let mut first = [0; 5];
+
+    reader.read_exact(&mut first).unwrap();
+
+    let mut out = first.to_vec();
+
+    out.extend_from_slice(&read_in_chunks(reader, 4099));
+
+    assert_eq!(out, plain);
+}
+
+#[test]
+fn decode_large_destination_one_byte_reads() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(1000).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let reader = FromBase64Reader::<_, 8>::new2(OneByteReader {
+        data: Cursor::new(encoded.into_bytes()),
+    });
+
+    assert_eq!(read_in_chunks(reader, 64), plain);
+}
+
+#[test]
+fn decode_large_destination_invalid_base64() {
+    let mut reader = FromBase64Reader::new(Cursor::new(vec![b'!'; 8192]));
+
+    let mut out = vec![0; 8192];
+
+    let error = reader.read(&mut out).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+fn decode_read_vectored() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let expected: Vec<u8> = (0u8..=255).cycle().take(1000).collect();
+    let encoded = STANDARD.encode(&expected);
+
+    let mut reader = FromBase64Reader::<_, 16>::new2(Cursor::new(encoded.into_bytes()));
+
+    let mut out = Vec::new();
+
+    loop {
+        let mut a = [0u8; 1];
+        let mut b = [0u8; 2];
+        let mut c = [0u8; 5];
+        let mut d = [0u8; 7];
+
+        let mut slices = [
+            IoSliceMut::new(&mut a),
+            IoSliceMut::new(&mut []),
+            IoSliceMut::new(&mut b),
+            IoSliceMut::new(&mut c),
+            IoSliceMut::new(&mut d),
+        ];
+
+        let read_length = reader.read_vectored(&mut slices).unwrap();
+
+        if read_length == 0 {
+            break;
+        }
+
+        let joined: Vec<u8> = [&a[..], &b[..], &c[..], &d[..]].concat();
+
+        out.extend_from_slice(&joined[..read_length]);
+    }
+
+    assert_eq!(expected, out);
+}
+
+#[test]
+fn decode_auto_detect_variants() {
+    use base64_stream::{
+        Base64Variant,
+        base64::{
+            Engine,
+            engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
+        },
+    };
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(5000).collect();
+
+    for (engine, variant) in [
+        (STANDARD, Base64Variant::Standard),
+        (STANDARD_NO_PAD, Base64Variant::StandardNoPad),
+        (URL_SAFE, Base64Variant::UrlSafe),
+        (URL_SAFE_NO_PAD, Base64Variant::UrlSafeNoPad),
+    ] {
+        let encoded = engine.encode(&plain);
+
+        // a large destination goes through `read_direct`, a small one through the buffer
+        for chunk_size in [1, 7, 10000] {
+            let mut reader =
+                FromBase64Reader::new(Cursor::new(encoded.as_bytes())).with_auto_detect();
+
+            let mut out = Vec::new();
+            let mut b = vec![0; chunk_size];
+
+            loop {
+                let c = reader.read(&mut b).unwrap();
+
+                if c == 0 {
+                    break;
+                }
+
+                out.extend_from_slice(&b[..c]);
+            }
+
+            assert_eq!(plain, out);
+            assert_eq!(Some(variant), reader.detected_variant());
+        }
+    }
+}
+
+#[test]
+fn decode_auto_detect_pending_alphabet() {
+    let mut reader = FromBase64Reader::new(Cursor::new(b"YWJjZGVm".to_vec())).with_auto_detect();
+
+    let mut b = [0; 3];
+
+    reader.read_exact(&mut b).unwrap();
+
+    assert_eq!(None, reader.detected_variant());
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"def", test_data.as_slice());
+    assert_eq!(Some(base64_stream::Base64Variant::Standard), reader.detected_variant());
+}
+
+#[test]
+fn decode_auto_detect_rejects_mixed_alphabets() {
+    let mut reader =
+        FromBase64Reader::new(Cursor::new(b"Pz8/Pz8_Pz8=".to_vec())).with_auto_detect();
+
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+fn decode_auto_detect_disabled() {
+    let mut reader = FromBase64Reader::new(Cursor::new(b"Pz8_".to_vec()));
+
+    assert!(reader.read_to_end(&mut Vec::new()).is_err());
+    assert_eq!(None, reader.detected_variant());
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn decode_digest() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+    use sha2::{Digest, Sha256};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    for chunk_size in [1, 100, 10000] {
+        let mut reader =
+            FromBase64Reader::new(Cursor::new(encoded.as_bytes())).with_digest(Sha256::new());
+
+        let mut b = vec![0; chunk_size];
+
+        while reader.read(&mut b).unwrap() > 0 {}
+
+        let (_, pending) = reader.into_parts();
+
+        assert_eq!(Sha256::digest(&plain).as_slice(), &*pending.digest.unwrap());
+    }
+}
+
+#[test]
+fn decode_progress() {
+    use std::sync::{Arc, Mutex};
+
+    use base64_stream::{
+        Progress,
+        base64::{Engine, engine::general_purpose::STANDARD},
+    };
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let reports_clone = reports.clone();
+
+    let mut reader = FromBase64Reader::new(Cursor::new(encoded.as_bytes()))
+        .with_progress(3000, move |progress| reports_clone.lock().unwrap().push(progress));
+
+    let mut b = [0; 1000];
+
+    while reader.read(&mut b).unwrap() > 0 {}
+
+    assert_eq!(
+        Progress {
+            plain: 10000, encoded: encoded.len() as u64
+        },
+        reader.progress()
+    );
+
+    let reports = reports.lock().unwrap();
+
+    assert_eq!(3, reports.len());
+
+    for (i, p) in reports.iter().enumerate() {
+        let threshold = (i as u64 + 1) * 3000;
+
+        assert!(p.plain >= threshold && p.plain < threshold + 1000);
+    }
+
+    assert!(reports.iter().all(|p| p.encoded >= p.plain * 4 / 3));
+}
+
+#[test]
+fn decode_decoded_limit() {
+    use base64_stream::{
+        LimitExceeded,
+        base64::{Engine, engine::general_purpose::STANDARD},
+    };
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let mut reader =
+        FromBase64Reader::new(Cursor::new(encoded.as_bytes())).with_decoded_limit(10000);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(plain, test_data);
+
+    let mut reader =
+        FromBase64Reader::new(Cursor::new(encoded.as_bytes())).with_decoded_limit(9999);
+
+    let mut test_data = Vec::new();
+
+    let error = reader.read_to_end(&mut test_data).unwrap_err();
+
+    assert_eq!(ErrorKind::Other, error.kind());
+    assert_eq!(
+        Some(&LimitExceeded::Decoded(9999)),
+        error.get_ref().and_then(|e| e.downcast_ref::<LimitExceeded>())
+    );
+    assert_eq!(&plain[..9999], test_data.as_slice());
+}
+
+#[test]
+fn decode_encoded_limit() {
+    use base64_stream::LimitExceeded;
+
+    let mut reader = FromBase64Reader::new(Cursor::new(b"YWJjZA==".to_vec())).with_encoded_limit(8);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"abcd", test_data.as_slice());
+
+    let mut reader = FromBase64Reader::new(Cursor::new(b"YWJjZA==".to_vec())).with_encoded_limit(7);
+
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(
+        Some(&LimitExceeded::Encoded(7)),
+        error.get_ref().and_then(|e| e.downcast_ref::<LimitExceeded>())
+    );
+
+    // the error does not turn into a clean EOF
+    let error = reader.read(&mut [0; 16]).unwrap_err();
+
+    assert_eq!(
+        Some(&LimitExceeded::Encoded(7)),
+        error.get_ref().and_then(|e| e.downcast_ref::<LimitExceeded>())
+    );
+}
+
+#[test]
+fn decode_into_parts() {
+    let mut reader = FromBase64Reader::new(Cursor::new(b"YWJjZA==\x00\x01raw".to_vec()));
+
+    let mut b = [0; 3];
+
+    reader.read_exact(&mut b).unwrap();
+
+    assert_eq!(b"abc", &b);
+    assert_eq!(b"YWJjZA==\x00\x01raw".len() as u64, reader.get_ref().position());
+
+    let mut b = [0; 1];
+
+    reader.read_exact(&mut b).unwrap();
+
+    let (inner, pending) = reader.into_parts();
+
+    assert_eq!(b"d", &b);
+    assert!(pending.plain.is_empty());
+    assert_eq!(b"\x00\x01raw", pending.encoded.as_slice());
+    assert_eq!(b"YWJjZA==\x00\x01raw".len() as u64, inner.position());
+}
+
+#[test]
+fn decode_into_parts_pending_plain() {
+    let mut reader = FromBase64Reader::new(Cursor::new(b"YWJjZGVm".to_vec()));
+
+    let mut b = [0; 1];
+
+    reader.read_exact(&mut b).unwrap();
+
+    let (_, pending) = reader.into_parts();
+
+    assert_eq!(b"bc", pending.plain.as_slice());
+    assert_eq!(b"ZGVm", pending.encoded.as_slice());
+}
+
+#[test]
+fn decode_terminator_padding() {
+    use base64_stream::Terminator;
+
+    let mut reader = FromBase64Reader::new(Cursor::new(b"YWJj\nZA==\x00\xffraw".to_vec()))
+        .with_terminator(Terminator::Padding);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"abcd", test_data.as_slice());
+
+    let mut rest = reader.into_rest();
+
+    let mut test_data = Vec::new();
+
+    rest.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"\x00\xffraw", test_data.as_slice());
+}
+
+#[test]
+fn decode_terminator_blank_line() {
+    use base64_stream::Terminator;
+
+    let mut reader =
+        FromBase64Reader::new(Cursor::new(b"YWJj\r\nZGVm\r\n\r\nnext part\r\n".to_vec()))
+            .with_terminator(Terminator::BlankLine);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"abcdef", test_data.as_slice());
+
+    let mut rest = reader.into_rest();
+
+    let mut test_data = Vec::new();
+
+    rest.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"next part\r\n", test_data.as_slice());
+}
+
+#[test]
+fn decode_terminator_delimiter() {
+    use base64_stream::Terminator;
+
+    let mut reader =
+        FromBase64Reader::<_, 4>::new2(Cursor::new(b"YWJjZGVmZw\n--boundary--YWJj".to_vec()))
+            .with_auto_detect()
+            .with_terminator(Terminator::Delimiter(b"\n--boundary--".to_vec()));
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"abcdefg", test_data.as_slice());
+
+    let mut rest = reader.into_rest();
+
+    let mut test_data = Vec::new();
+
+    rest.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"YWJj", test_data.as_slice());
+}
+
+#[test]
+fn decode_terminator_eof() {
+    use base64_stream::Terminator;
+
+    let mut reader = FromBase64Reader::new(Cursor::new(b"YWJjZA\n".to_vec()))
+        .with_auto_detect()
+        .with_terminator(Terminator::BlankLine);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"abcd", test_data.as_slice());
+
+    let (_, pushback) = reader.into_rest().into_parts();
+
+    assert!(pushback.is_empty());
+}
+
+#[test]
+fn decode_variant() {
+    use base64_stream::Base64Variant;
+
+    let mut reader = FromBase64Reader::new(Cursor::new(b"-_8".to_vec()))
+        .with_variant(Base64Variant::UrlSafeNoPad);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"\xfb\xff", test_data.as_slice());
+
+    let mut reader = FromBase64Reader::new(Cursor::new(b"-_8=".to_vec()))
+        .with_variant(Base64Variant::UrlSafeNoPad);
+
+    assert!(reader.read_to_end(&mut Vec::new()).is_err());
+}
+
+#[test]
+fn decode_alphabet() {
+    use base64::{DecodeError, DecodeSliceError};
+    use base64_stream::Base64Alphabet;
+
+    static STARRED: Base64Alphabet = Base64Alphabet::new(
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        Some(b'*'),
+    );
+
+    let mut reader = FromBase64Reader::new(Cursor::new(b",,79,GZvb2JhciE".to_vec()))
+        .with_alphabet(&Base64Alphabet::IMAP_MAILBOX);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"\xff\xfe\xfd\xfcfoobar!", test_data.as_slice());
+
+    let mut reader = FromBase64Reader::<_, 4>::new2(Cursor::new(b"Zm9vYmFyIQ**".to_vec()))
+        .with_alphabet(&STARRED);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b"foobar!", test_data.as_slice());
+
+    let mut reader =
+        FromBase64Reader::new(Cursor::new(b"Zm9vYmFyIQ==".to_vec())).with_alphabet(&STARRED);
+
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(
+        DecodeSliceError::DecodeError(DecodeError::InvalidByte(10, b'=')),
+        *error.into_inner().unwrap().downcast::<DecodeSliceError>().unwrap()
+    );
+}
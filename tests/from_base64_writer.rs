@@ -1,6 +1,6 @@
 use std::{
     fs::{self, File},
-    io::{self, Cursor, ErrorKind, Write},
+    io::{self, Cursor, ErrorKind, IoSlice, Write},
     path::Path,
 };
 
@@ -30,6 +30,25 @@ impl Write for FlushCountingWriter {
     }
 }
 
+#[derive(Debug, Default)]
+struct WriteCountingWriter {
+    data:        Vec<u8>,
+    write_count: usize,
+}
+
+impl Write for WriteCountingWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.write_count += 1;
+        self.data.extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+}
+
 const DATA_FOLDER: &str = "data";
 const DECODE_OUTPUT: &str = "decode_output.txt";
 
@@ -201,3 +220,169 @@ fn decode_finish_returns_inner_flush_error() {
 
     assert_eq!(ErrorKind::BrokenPipe, error.kind());
 }
+
+#[test]
+fn decode_write_vectored_carries_blocks_across_slices() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(100).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let mut slices = Vec::new();
+    let mut rest = encoded.as_bytes();
+
+    for length in [1, 1, 2, 5, 7, 1, 3, 4, 11, 2].into_iter().cycle() {
+        if rest.is_empty() {
+            break;
+        }
+
+        let (slice, tail) = rest.split_at(length.min(rest.len()));
+
+        slices.push(IoSlice::new(slice));
+        rest = tail;
+    }
+
+    let mut writer = FromBase64Writer::new(WriteCountingWriter::default());
+
+    assert_eq!(encoded.len(), writer.write_vectored(&slices).unwrap());
+
+    let inner = writer.finish().unwrap();
+
+    assert_eq!(plain, inner.data);
+    assert_eq!(1, inner.write_count);
+}
+
+#[test]
+fn decode_write_vectored_small_buffer() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(100).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let slices: Vec<IoSlice> = encoded.as_bytes().chunks(7).map(IoSlice::new).collect();
+
+    let mut writer = FromBase64Writer::<_, 5>::new2(Vec::new());
+
+    assert_eq!(encoded.len(), writer.write_vectored(&slices).unwrap());
+
+    assert_eq!(plain, writer.finish().unwrap());
+}
+
+#[test]
+fn decode_write_vectored_invalid_slice() {
+    let slices = [IoSlice::new(b"YW"), IoSlice::new(b"Jj!!!!")];
+
+    let mut writer = FromBase64Writer::new(Vec::new());
+
+    // the valid block is decoded and the error is left to the write which starts at it
+    assert_eq!(4, writer.write_vectored(&slices).unwrap());
+    assert_eq!(b"abc", writer.get_ref().as_slice());
+
+    let error = writer.write(b"!!!!").unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    assert_eq!(1, writer.write_vectored(&[IoSlice::new(b"Y"), IoSlice::new(b"!!!")]).unwrap());
+
+    let error = writer.write(b"!!!").unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+    assert_eq!(b"Y", writer.into_parts().1.encoded.as_slice());
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn decode_digest_write() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+    use sha2::{Digest, Sha256};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let mut writer = FromBase64Writer::new(Vec::new()).with_digest(Sha256::new());
+
+    for chunk in encoded.as_bytes().chunks(7) {
+        writer.write_all(chunk).unwrap();
+    }
+
+    let (out, digest) = writer.finish_with_digest().unwrap();
+
+    assert_eq!(plain, out);
+    assert_eq!(Sha256::digest(&plain).as_slice(), &*digest.unwrap());
+}
+
+#[test]
+fn decode_progress_write() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use base64_stream::{
+        Progress,
+        base64::{Engine, engine::general_purpose::STANDARD},
+    };
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+    let encoded = STANDARD.encode(&plain);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = calls.clone();
+
+    let mut writer = FromBase64Writer::new(Vec::new()).with_progress(1000, move |_| {
+        calls_clone.fetch_add(1, Ordering::Relaxed);
+    });
+
+    for chunk in encoded.as_bytes().chunks(100) {
+        writer.write_all(chunk).unwrap();
+    }
+
+    assert_eq!(
+        Progress {
+            plain: 10000, encoded: encoded.len() as u64
+        },
+        writer.progress()
+    );
+    assert_eq!(10, calls.load(Ordering::Relaxed));
+}
+
+#[test]
+fn decode_into_parts_write() {
+    let mut writer = FromBase64Writer::new(Vec::new());
+
+    writer.write_all(b"YWJjZA").unwrap();
+
+    assert_eq!(b"abc", writer.get_ref().as_slice());
+
+    let (inner, pending) = writer.into_parts();
+
+    assert_eq!(b"abc", inner.as_slice());
+    assert!(pending.plain.is_empty());
+    assert_eq!(b"ZA", pending.encoded.as_slice());
+}
+
+#[test]
+fn decode_alphabet() {
+    use base64_stream::Base64Alphabet;
+
+    static STARRED: Base64Alphabet = Base64Alphabet::new(
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        Some(b'*'),
+    );
+
+    let mut writer = FromBase64Writer::new(Vec::new()).with_alphabet(&Base64Alphabet::BCRYPT);
+
+    writer.write_all(b"8tt69d59YETqZE6eb07wZEO").unwrap();
+
+    assert_eq!(b"\xfa\xfb\xfc\xfd\xfe\xffhello world", writer.finish().unwrap().as_slice());
+
+    let mut writer = FromBase64Writer::new(Vec::new()).with_alphabet(&STARRED);
+
+    writer.write_all(b"Zm9vYmFyIQ**").unwrap();
+
+    assert_eq!(b"foobar!", writer.finish().unwrap().as_slice());
+
+    let mut writer = FromBase64Writer::new(Vec::new()).with_alphabet(&STARRED);
+
+    assert_eq!(ErrorKind::InvalidData, writer.write_all(b"Zm9vYmFyIQ==").unwrap_err().kind());
+}
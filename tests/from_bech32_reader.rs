@@ -0,0 +1,129 @@
+use std::io::{self, ErrorKind, Read, Write};
+
+use base64_stream::{Bech32Error, Bech32Variant, FromBech32Reader, ToBech32Writer};
+
+fn decode(string: &str) -> io::Result<Vec<u8>> {
+    let mut plain = Vec::new();
+
+    FromBech32Reader::new(string.as_bytes()).read_to_end(&mut plain)?;
+
+    Ok(plain)
+}
+
+fn decode_error(string: &str) -> Bech32Error {
+    let error = decode(string).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    error.into_inner().unwrap().downcast_ref::<Bech32Error>().unwrap().clone()
+}
+
+#[test]
+fn decode_read() {
+    let mut reader = FromBech32Reader::new(b"A12UEL5L".as_slice());
+
+    assert_eq!("a", reader.read_hrp().unwrap());
+    assert_eq!(Some(Bech32Variant::Bech32), reader.variant());
+    assert_eq!(0, reader.read(&mut [0; 4]).unwrap());
+
+    assert_eq!(
+        b"\x00\x44\x32\x14\xc7\x42\x54\xb6\x35\xcf\x84\x65\x3a\x56\xd7\xc6\x75\xbe\x77\xdf"
+            .as_slice(),
+        decode("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw").unwrap()
+    );
+}
+
+#[test]
+fn decode_bech32m_read() {
+    let mut reader = FromBech32Reader::new(b"a1lqfn3a".as_slice());
+
+    assert_eq!("a", reader.read_hrp().unwrap());
+    assert_eq!(Some(Bech32Variant::Bech32m), reader.variant());
+
+    let error = FromBech32Reader::new(b"a1lqfn3a".as_slice())
+        .with_variant(Bech32Variant::Bech32)
+        .read_to_end(&mut Vec::new())
+        .unwrap_err();
+
+    assert!(matches!(
+        error.get_ref().unwrap().downcast_ref(),
+        Some(Bech32Error::ChecksumMismatch(_))
+    ));
+}
+
+#[test]
+fn decode_invalid_read() {
+    assert_eq!(Bech32Error::MissingSeparator, decode_error("pzry9x0s0muk"));
+    assert_eq!(Bech32Error::InvalidHrpLength(0), decode_error("1pzry9x0s0muk"));
+    assert_eq!(Bech32Error::InvalidSymbol(2, b'b'), decode_error("x1b4n0q5v"));
+    assert_eq!(Bech32Error::MissingChecksum, decode_error("li1dgmt3"));
+    assert_eq!(Bech32Error::MixedCase, decode_error("A1g7sgd8"));
+    assert_eq!(Bech32Error::InvalidSymbol(1, b'\x7f'), decode_error("a\x7f1qqqqqqqqqqqq"));
+}
+
+#[test]
+fn decode_locate_errors_read() {
+    let mut writer = ToBech32Writer::new(Vec::new(), "wallet");
+
+    writer.write_all(b"an address-like identifier").unwrap();
+
+    let string = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    assert_eq!(b"an address-like identifier".as_slice(), decode(&string).unwrap());
+
+    let mut corrupted = string.clone().into_bytes();
+
+    corrupted[12] = if corrupted[12] == b'q' { b'p' } else { b'q' };
+
+    assert_eq!(
+        Bech32Error::ChecksumMismatch(vec![12]),
+        decode_error(std::str::from_utf8(&corrupted).unwrap())
+    );
+
+    corrupted[30] = if corrupted[30] == b'x' { b'z' } else { b'x' };
+
+    assert_eq!(
+        Bech32Error::ChecksumMismatch(vec![12, 30]),
+        decode_error(std::str::from_utf8(&corrupted).unwrap())
+    );
+}
+
+#[test]
+fn decode_limit_read() {
+    use base64_stream::LimitExceeded;
+
+    let plain = [0xA5; 100];
+
+    let mut writer = ToBech32Writer::new(Vec::new(), "wallet");
+
+    writer.write_all(&plain).unwrap();
+
+    let mut string = writer.finish().unwrap();
+
+    let error = FromBech32Reader::new(string.as_slice()).read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(ErrorKind::Other, error.kind());
+    assert_eq!(Some(&LimitExceeded::Encoded(90)), error.get_ref().unwrap().downcast_ref());
+
+    let mut decoded = Vec::new();
+
+    FromBech32Reader::new(string.as_slice())
+        .with_limit(string.len() as u64)
+        .read_to_end(&mut decoded)
+        .unwrap();
+
+    assert_eq!(plain.as_slice(), decoded);
+
+    // the errors of a string over 90 characters are not located
+    string[12] = if string[12] == b'q' { b'p' } else { b'q' };
+
+    let error = FromBech32Reader::new(string.as_slice())
+        .with_limit(string.len() as u64)
+        .read_to_end(&mut Vec::new())
+        .unwrap_err();
+
+    assert_eq!(
+        Some(&Bech32Error::ChecksumMismatch(Vec::new())),
+        error.get_ref().unwrap().downcast_ref()
+    );
+}
@@ -0,0 +1,82 @@
+use std::io::{ErrorKind, Read};
+
+use base64_stream::{FromQuotedPrintableReader, QuotedPrintableError};
+
+fn decode(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    let mut output = Vec::new();
+
+    FromQuotedPrintableReader::new(data).read_to_end(&mut output)?;
+
+    Ok(output)
+}
+
+fn quoted_printable_error(error: std::io::Error) -> QuotedPrintableError {
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    *error.into_inner().unwrap().downcast::<QuotedPrintableError>().unwrap()
+}
+
+#[test]
+fn decode_read() {
+    assert_eq!(b"a=b".as_slice(), decode(b"a=3Db").unwrap());
+    assert_eq!("café".as_bytes(), decode(b"caf=C3=a9").unwrap());
+}
+
+#[test]
+fn decode_soft_line_break_read() {
+    assert_eq!(b"Hello, World!".as_slice(), decode(b"Hello,=\r\n World=  \t\r\n!").unwrap());
+    assert_eq!(b"ab".as_slice(), decode(b"a=\nb=").unwrap());
+}
+
+#[test]
+fn decode_hard_line_break_read() {
+    assert_eq!(b"a\r\nb\r\nc\r\nd".as_slice(), decode(b"a  \nb\t\r\nc\rd").unwrap());
+    assert_eq!(b"a b".as_slice(), decode(b"a b \t").unwrap());
+}
+
+#[test]
+fn decode_line_ending_read() {
+    use base64_stream::LineEnding;
+
+    let mut reader =
+        FromQuotedPrintableReader::new(b"a\r\nb=0D=0A".as_slice()).with_line_ending(LineEnding::LF);
+
+    let mut output = Vec::new();
+
+    reader.read_to_end(&mut output).unwrap();
+
+    assert_eq!(b"a\nb\r\n".as_slice(), output);
+}
+
+#[test]
+fn decode_invalid_read() {
+    assert_eq!(
+        QuotedPrintableError::InvalidByte(3, b'G'),
+        quoted_printable_error(decode(b"ab=G1").unwrap_err())
+    );
+    assert_eq!(
+        QuotedPrintableError::InvalidByte(4, b'x'),
+        quoted_printable_error(decode(b"ab= x").unwrap_err())
+    );
+    assert_eq!(
+        QuotedPrintableError::IncompleteEscape,
+        quoted_printable_error(decode(b"ab=4").unwrap_err())
+    );
+    assert_eq!(
+        QuotedPrintableError::LineTooLong(1),
+        quoted_printable_error(decode(&[b"a".as_slice(), &[b' '; 1000]].concat()).unwrap_err())
+    );
+}
+
+#[test]
+fn decode_small_buffer_read() {
+    let data = b"Gr=C3=BC=C3=9Fe =\r\naus K=C3=B6ln=20\r\n=3D tab=09\r\n".repeat(20);
+
+    let mut reader = FromQuotedPrintableReader::<_, 4>::new2(data.as_slice());
+
+    let mut output = Vec::new();
+
+    reader.read_to_end(&mut output).unwrap();
+
+    assert_eq!("Grüße aus Köln \r\n= tab\t\r\n".repeat(20).as_bytes(), output);
+}
@@ -0,0 +1,102 @@
+use std::io::{ErrorKind, Write};
+
+use base64_stream::{FromQuotedPrintableWriter, ToQuotedPrintableWriter};
+
+#[test]
+fn decode_write() {
+    let mut writer = FromQuotedPrintableWriter::new(Vec::new());
+
+    writer.write_all(b"caf=C3=A9 =\r\nau lait  \r\n").unwrap();
+
+    assert_eq!("café au lait\r\n".as_bytes(), writer.finish().unwrap());
+}
+
+#[test]
+fn decode_one_byte_write() {
+    let data = b"Gr=C3=BC=C3=9Fe =\r\naus K=C3=B6ln=20\r\n=3D tab=09\r\n".repeat(20);
+
+    let mut writer = FromQuotedPrintableWriter::<_, 4>::new2(Vec::new());
+
+    for b in data.chunks(1) {
+        writer.write_all(b).unwrap();
+    }
+
+    assert_eq!("Grüße aus Köln \r\n= tab\t\r\n".repeat(20).as_bytes(), writer.finish().unwrap());
+}
+
+#[test]
+fn decode_incomplete_write() {
+    let mut writer = FromQuotedPrintableWriter::new(Vec::new());
+
+    writer.write_all(b"ab=F").unwrap();
+
+    assert_eq!(b"ab".as_slice(), writer.get_ref());
+
+    assert_eq!(ErrorKind::InvalidData, writer.finish().unwrap_err().kind());
+}
+
+#[test]
+fn round_trip_binary_write() {
+    let plain = (0..=255u8).cycle().take(5000).collect::<Vec<u8>>();
+
+    let mut writer = ToQuotedPrintableWriter::new(Vec::new()).with_binary();
+
+    writer.write_all(&plain).unwrap();
+
+    let data = writer.finish().unwrap();
+
+    assert!(data.split(|&b| b == b'\n').all(|line| line.len() <= 77));
+
+    let mut writer = FromQuotedPrintableWriter::new(Vec::new());
+
+    writer.write_all(&data).unwrap();
+
+    assert_eq!(plain, writer.finish().unwrap());
+}
+
+#[test]
+fn decode_retry_write() {
+    use std::io;
+
+    struct WouldBlockOnceWriter {
+        data:    Vec<u8>,
+        blocked: bool,
+    }
+
+    impl Write for WouldBlockOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.blocked {
+                self.blocked = true;
+
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = WouldBlockOnceWriter {
+        data: Vec::new(), blocked: false
+    };
+
+    let mut writer = FromQuotedPrintableWriter::new(inner);
+
+    // an error means that nothing has been taken, so the same bytes are written again
+    let mut input = b"hello=20world".as_slice();
+
+    while !input.is_empty() {
+        match writer.write(input) {
+            Ok(c) => input = &input[c..],
+            Err(error) => assert_eq!(io::ErrorKind::WouldBlock, error.kind()),
+        }
+    }
+
+    assert!(writer.get_ref().blocked);
+    assert_eq!(b"hello world".as_slice(), writer.finish().unwrap().data);
+}
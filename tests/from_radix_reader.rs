@@ -0,0 +1,62 @@
+use std::io::{self, Read};
+
+use base64_stream::{FromRadixReader, RadixEngine, RadixError};
+
+fn decode(engine: &'static RadixEngine, encoded: &str) -> Result<Vec<u8>, io::Error> {
+    let mut reader = FromRadixReader::<_, 4>::new2(encoded.as_bytes(), engine);
+
+    let mut plain = Vec::new();
+
+    reader.read_to_end(&mut plain)?;
+
+    Ok(plain)
+}
+
+fn decode_error(engine: &'static RadixEngine, encoded: &str) -> RadixError {
+    let error = decode(engine, encoded).unwrap_err();
+
+    *error.into_inner().unwrap().downcast::<RadixError>().unwrap()
+}
+
+#[test]
+fn decode_base32_read() {
+    for (plain, base32) in [
+        ("", ""),
+        ("f", "MY======"),
+        ("fo", "MZXQ===="),
+        ("foo", "MZXW6==="),
+        ("foob", "MZXW6YQ="),
+        ("fooba", "MZXW6YTB"),
+        ("foobar", "MZXW6YTBOI======"),
+    ] {
+        assert_eq!(plain.as_bytes(), decode(&RadixEngine::BASE32, base32).unwrap());
+    }
+
+    assert_eq!(b"foobar".as_slice(), decode(&RadixEngine::BASE16, "666f6F626172").unwrap());
+    assert_eq!(b"foo".as_slice(), decode(&RadixEngine::BASE8, "31467557").unwrap());
+}
+
+#[test]
+fn decode_custom_read() {
+    static CROCKFORD: RadixEngine = RadixEngine::new(5, b"0123456789ABCDEFGHJKMNPQRSTVWXYZ", None)
+        .with_alias(b'O', b'0')
+        .with_alias(b'I', b'1')
+        .with_alias(b'L', b'1')
+        .with_case_insensitive();
+
+    // "CSQPYRK1E8" in Crockford's base32, with aliases and mixed case
+    assert_eq!(b"foobar".as_slice(), decode(&CROCKFORD, "csqPYRKlE8").unwrap());
+    assert_eq!(b"foobar".as_slice(), decode(&CROCKFORD, "CSQPYRKIE8").unwrap());
+}
+
+#[test]
+fn decode_error_read() {
+    assert_eq!(RadixError::InvalidSymbol(2, b'1'), decode_error(&RadixEngine::BASE32, "MZ1W6==="));
+    assert_eq!(RadixError::InvalidSymbol(5, b'A'), decode_error(&RadixEngine::BASE32, "MZXQ=A=="));
+    assert_eq!(RadixError::InvalidSymbol(0, b'='), decode_error(&RadixEngine::BASE32, "========"));
+    assert_eq!(RadixError::InvalidPadding, decode_error(&RadixEngine::BASE32, "MY====="));
+    assert_eq!(RadixError::InvalidPadding, decode_error(&RadixEngine::BASE32, "MZXW6"));
+    assert_eq!(RadixError::InvalidTrailingBits, decode_error(&RadixEngine::BASE32, "MZ======"));
+    assert_eq!(RadixError::InvalidTrailingBits, decode_error(&RadixEngine::BASE32, "M======="));
+    assert_eq!(RadixError::InvalidTrailingBits, decode_error(&RadixEngine::BASE16, "666"));
+}
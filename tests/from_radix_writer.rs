@@ -0,0 +1,78 @@
+use std::io::{ErrorKind, Write};
+
+use base64_stream::{FromRadixWriter, RadixEngine, RadixError};
+
+#[test]
+fn decode_base32_one_byte_write() {
+    let mut writer = FromRadixWriter::<_, 4>::new2(Vec::new(), &RadixEngine::BASE32);
+
+    for b in b"MZXW6YTBOI======" {
+        writer.write_all(&[*b]).unwrap();
+    }
+
+    assert_eq!(16, writer.progress().encoded);
+    assert_eq!(b"foobar".as_slice(), writer.get_ref());
+    assert_eq!(b"foobar".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+fn decode_error_write() {
+    let mut writer = FromRadixWriter::new(Vec::new(), &RadixEngine::BASE32);
+
+    writer.write_all(b"MZXW6YTBOI").unwrap();
+
+    let error = writer.finish().unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+    assert_eq!(
+        RadixError::InvalidPadding,
+        *error.into_inner().unwrap().downcast::<RadixError>().unwrap()
+    );
+}
+
+#[test]
+fn decode_retry_write() {
+    use std::io;
+
+    struct WouldBlockOnceWriter {
+        data:    Vec<u8>,
+        blocked: bool,
+    }
+
+    impl Write for WouldBlockOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.blocked {
+                self.blocked = true;
+
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = WouldBlockOnceWriter {
+        data: Vec::new(), blocked: false
+    };
+
+    let mut writer = FromRadixWriter::new(inner, &RadixEngine::BASE32);
+
+    // an error means that nothing has been taken, so the same bytes are written again
+    let mut input = b"NBSWY3DPEB3W64TMMQ======".as_slice();
+
+    while !input.is_empty() {
+        match writer.write(input) {
+            Ok(c) => input = &input[c..],
+            Err(error) => assert_eq!(io::ErrorKind::WouldBlock, error.kind()),
+        }
+    }
+
+    assert!(writer.get_ref().blocked);
+    assert_eq!(b"hello world".as_slice(), writer.finish().unwrap().data);
+}
@@ -0,0 +1,85 @@
+use std::io::{ErrorKind, Read, Write};
+
+use base64_stream::{FromUuencodeReader, ToUuencodeWriter, UuencodeHeader, UuencodeVariant};
+
+#[test]
+fn uudecode_read() {
+    let mut reader =
+        FromUuencodeReader::new(b"From: someone\n\nbegin 755 cat.sh\n#0V%T\n`\nend\n".as_slice());
+
+    assert!(reader.header().is_none());
+
+    let mut plain = Vec::new();
+
+    reader.read_to_end(&mut plain).unwrap();
+
+    assert_eq!(b"Cat".as_slice(), plain);
+    assert_eq!(
+        Some(&UuencodeHeader {
+            mode: 0o755, name: "cat.sh".to_string()
+        }),
+        reader.header()
+    );
+}
+
+#[test]
+fn uudecode_stripped_spaces_read() {
+    // the spaces at the end of the lines of old encoders may have been stripped
+    let mut reader =
+        FromUuencodeReader::new(b"begin 644 zeros\r\n$\r\n\r\nend\r\ntrailer".as_slice());
+
+    assert_eq!("zeros", reader.read_header().unwrap().name);
+
+    let mut plain = Vec::new();
+
+    reader.read_to_end(&mut plain).unwrap();
+
+    assert_eq!([0u8; 4].as_slice(), plain);
+
+    let mut rest = Vec::new();
+
+    reader.into_rest().read_to_end(&mut rest).unwrap();
+
+    assert_eq!(b"trailer".as_slice(), rest);
+}
+
+#[test]
+fn uudecode_round_trip_read() {
+    let plain = (0..=255u8).cycle().take(10000).collect::<Vec<u8>>();
+
+    for variant in [UuencodeVariant::Uuencode, UuencodeVariant::Xxencode] {
+        let mut writer = ToUuencodeWriter::new(Vec::new(), 0o644, "data.bin").with_variant(variant);
+
+        writer.write_all(&plain).unwrap();
+
+        let data = writer.finish().unwrap();
+
+        let mut reader = FromUuencodeReader::new(data.as_slice()).with_variant(variant);
+
+        let mut decoded = Vec::new();
+
+        reader.read_to_end(&mut decoded).unwrap();
+
+        assert_eq!(plain, decoded);
+    }
+}
+
+#[test]
+fn uudecode_invalid_read() {
+    let mut reader = FromUuencodeReader::new(b"begin 644 a\n#0v%T\n`\nend\n".as_slice());
+
+    assert_eq!(ErrorKind::InvalidData, reader.read_to_end(&mut Vec::new()).unwrap_err().kind());
+
+    // a length character over `M` (45)
+    let mut reader = FromUuencodeReader::new(b"begin 644 a\nN0V%T\n`\nend\n".as_slice());
+
+    assert_eq!(ErrorKind::InvalidData, reader.read_to_end(&mut Vec::new()).unwrap_err().kind());
+
+    let mut reader = FromUuencodeReader::new(b"begin 644 a\n#0V%T\n`\n".as_slice());
+
+    assert_eq!(ErrorKind::UnexpectedEof, reader.read_to_end(&mut Vec::new()).unwrap_err().kind());
+
+    let mut reader = FromUuencodeReader::new(b"no data\n".as_slice());
+
+    assert_eq!(ErrorKind::UnexpectedEof, reader.read_to_end(&mut Vec::new()).unwrap_err().kind());
+}
@@ -0,0 +1,113 @@
+use std::io::{ErrorKind, Read, Write};
+
+use base64_stream::{FromYencReader, ToYencWriter, YencError, YencPart};
+
+fn yenc_error(error: std::io::Error) -> YencError {
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    *error.into_inner().unwrap().downcast::<YencError>().unwrap()
+}
+
+#[test]
+fn yenc_read() {
+    let data = [
+        b"Subject: test\r\n\r\n".as_slice(),
+        b"=ybegin line=128 size=5 name=a b.bin\r\n",
+        b"=@=J=M=}\x8b\r\n",
+        b"=yend size=5 crc32=E6164279\r\n",
+    ]
+    .concat();
+
+    let mut reader = FromYencReader::new(data.as_slice());
+
+    let mut plain = Vec::new();
+
+    reader.read_to_end(&mut plain).unwrap();
+
+    assert_eq!(b"\xd6\xe0\xe3\x13a".as_slice(), plain);
+
+    let header = reader.header().unwrap();
+
+    assert_eq!("a b.bin", header.name);
+    assert_eq!(5, header.size);
+    assert_eq!(Some(128), header.line_length);
+    assert!(header.part.is_none());
+    assert_eq!(Some(0xE6164279), reader.trailer().unwrap().crc32);
+}
+
+#[test]
+fn yenc_parts_read() {
+    let plain = (0..=255u8).cycle().take(10240).collect::<Vec<u8>>();
+
+    let mut data = Vec::new();
+
+    for (number, begin, end) in [(1, 1, 5000), (2, 5001, 10240)] {
+        let part = YencPart {
+            number,
+            total: Some(2),
+            begin,
+            end,
+        };
+
+        let mut writer = ToYencWriter::<_, 16>::new2(data, "file.bin", 10240)
+            .with_part(part)
+            .with_line_length(77);
+
+        writer.write_all(&plain[begin as usize - 1..end as usize]).unwrap();
+
+        data = writer.finish().unwrap();
+    }
+
+    let mut file = vec![0; 10240];
+
+    let mut rest: Box<dyn Read> = Box::new(data.as_slice());
+
+    for _ in 0..2 {
+        let mut reader = FromYencReader::<_, 16>::new2(rest);
+
+        let part = reader.read_header().unwrap().part.unwrap();
+
+        let mut decoded = Vec::new();
+
+        reader.read_to_end(&mut decoded).unwrap();
+
+        file[part.begin as usize - 1..part.end as usize].copy_from_slice(&decoded);
+
+        rest = Box::new(reader.into_rest());
+    }
+
+    assert_eq!(plain, file);
+}
+
+#[test]
+fn yenc_crc32_mismatch_read() {
+    let data = b"=ybegin line=128 size=1 name=x\r\n\x8b\r\n=yend size=1 crc32=00000000\r\n";
+
+    assert_eq!(
+        YencError::Crc32Mismatch {
+            expected: 0, actual: 0xE8B7BE43
+        },
+        yenc_error(FromYencReader::new(data.as_slice()).read_to_end(&mut Vec::new()).unwrap_err())
+    );
+}
+
+#[test]
+fn yenc_size_mismatch_read() {
+    let data = b"=ybegin line=128 size=2 name=x\r\n\x8b\r\n=yend size=2\r\n";
+
+    assert_eq!(
+        YencError::SizeMismatch {
+            expected: 2, actual: 1
+        },
+        yenc_error(FromYencReader::new(data.as_slice()).read_to_end(&mut Vec::new()).unwrap_err())
+    );
+}
+
+#[test]
+fn yenc_missing_end_read() {
+    let data = b"=ybegin line=128 size=1 name=x\r\n\x8b\r\n";
+
+    let error = FromYencReader::new(data.as_slice()).read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(ErrorKind::UnexpectedEof, error.kind());
+}
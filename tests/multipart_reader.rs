@@ -0,0 +1,239 @@
+use std::io::{self, Read, Write};
+
+use base64_stream::{MultipartReader, MultipartWriter};
+
+/// Returns one byte per read.
+struct OneByteReader<R: Read>(R);
+
+impl<R: Read> Read for OneByteReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = buf.len().min(1);
+
+        self.0.read(&mut buf[..length])
+    }
+}
+
+const MESSAGE: &[u8] = concat!(
+    "This is the preamble.\r\n",
+    "--frontier\r\n",
+    "Content-Type: text/plain\r\n",
+    "\r\n",
+    "Hello,\r\n",
+    "--front line\r\n",
+    "--frontier \t\r\n",
+    "Content-Type: application/octet-stream\r\n",
+    "Content-Transfer-Encoding: BASE64\r\n",
+    "Content-Disposition: attachment;\r\n",
+    " filename=\"a.bin\"\r\n",
+    "\r\n",
+    "+/8A\r\n",
+    "YWJj\r\n",
+    "--frontier--\r\n",
+    "This is the epilogue.\r\n",
+)
+.as_bytes();
+
+fn check_message<R: Read>(reader: R) {
+    let mut reader = MultipartReader::new(reader, "frontier");
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    assert_eq!(Some("text/plain"), part.content_type());
+    assert!(!part.is_base64());
+
+    let mut body = Vec::new();
+
+    part.read_to_end(&mut body).unwrap();
+
+    assert_eq!(b"Hello,\r\n--front line".as_slice(), body.as_slice());
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    assert!(part.is_base64());
+    assert_eq!(Some("attachment; filename=\"a.bin\""), part.header("content-disposition"));
+    assert_eq!(3, part.headers().len());
+
+    let mut body = Vec::new();
+
+    part.read_to_end(&mut body).unwrap();
+
+    assert_eq!(b"\xfb\xff\x00abc".as_slice(), body.as_slice());
+
+    assert!(reader.next_part().unwrap().is_none());
+    assert!(reader.next_part().unwrap().is_none());
+
+    let mut epilogue = Vec::new();
+
+    reader.into_rest().read_to_end(&mut epilogue).unwrap();
+
+    assert_eq!(b"This is the epilogue.\r\n".as_slice(), epilogue.as_slice());
+}
+
+#[test]
+fn multipart_read() {
+    check_message(MESSAGE);
+}
+
+#[test]
+fn multipart_one_byte_read() {
+    check_message(OneByteReader(MESSAGE));
+}
+
+#[test]
+fn multipart_lf_read() {
+    let message = String::from_utf8(MESSAGE.to_vec()).unwrap().replace("\r\n", "\n");
+
+    let mut reader = MultipartReader::new(message.as_bytes(), "frontier");
+
+    let mut body = Vec::new();
+
+    reader.next_part().unwrap().unwrap().read_to_end(&mut body).unwrap();
+
+    assert_eq!(b"Hello,\n--front line".as_slice(), body.as_slice());
+
+    body.clear();
+
+    reader.next_part().unwrap().unwrap().read_to_end(&mut body).unwrap();
+
+    assert_eq!(b"\xfb\xff\x00abc".as_slice(), body.as_slice());
+}
+
+#[test]
+fn multipart_skip_read() {
+    let mut reader = MultipartReader::new(MESSAGE, "frontier");
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    let mut buf = [0; 2];
+
+    part.read_exact(&mut buf).unwrap();
+
+    assert_eq!(b"He", &buf);
+
+    let part = reader.next_part().unwrap().unwrap();
+
+    assert!(part.is_base64());
+
+    assert!(reader.next_part().unwrap().is_none());
+}
+
+#[test]
+fn multipart_writer_read() {
+    let plain = (0..=255u8).cycle().take(10000).collect::<Vec<u8>>();
+
+    let mut writer = MultipartWriter::new(Vec::new(), "simple boundary");
+
+    let mut part = writer.start_attachment("application/octet-stream", "data.bin").unwrap();
+
+    part.write_all(&plain).unwrap();
+    part.finish().unwrap();
+
+    writer.start_part("text/plain", None).unwrap().finish().unwrap();
+
+    let message = writer.finish().unwrap();
+
+    let mut reader = MultipartReader::new(OneByteReader(message.as_slice()), "simple boundary");
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    assert_eq!(Some("attachment; filename=\"data.bin\""), part.header("Content-Disposition"));
+
+    let mut body = Vec::new();
+
+    part.read_to_end(&mut body).unwrap();
+
+    assert_eq!(plain, body);
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    body.clear();
+
+    part.read_to_end(&mut body).unwrap();
+
+    assert!(body.is_empty());
+
+    assert!(reader.next_part().unwrap().is_none());
+}
+
+#[test]
+fn multipart_missing_close_delimiter_read() {
+    let mut reader = MultipartReader::new(b"--b\r\n\r\nabc".as_slice(), "b");
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    let error = part.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(io::ErrorKind::UnexpectedEof, error.kind());
+}
+
+#[test]
+fn multipart_invalid_base64_read() {
+    let mut reader = MultipartReader::new(
+        b"--b\r\nContent-Transfer-Encoding: base64\r\n\r\nab!c\r\n--b--".as_slice(),
+        "b",
+    );
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    let error = part.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(io::ErrorKind::InvalidData, error.kind());
+}
+
+#[test]
+fn multipart_quoted_printable_read() {
+    let mut reader = MultipartReader::new(
+        b"--b\r\nContent-Transfer-Encoding: quoted-printable\r\n\r\ncaf=C3=A9 =\r\nau lait\r\n--b--"
+            .as_slice(),
+        "b",
+    );
+
+    let mut part = reader.next_part().unwrap().unwrap();
+
+    assert!(part.is_quoted_printable());
+
+    let mut body = Vec::new();
+
+    part.read_to_end(&mut body).unwrap();
+
+    assert_eq!("café au lait".as_bytes(), body.as_slice());
+}
+
+#[test]
+fn multipart_delimiter_prefix_read() {
+    let message = concat!(
+        "--frontier\r\n",
+        "\r\n",
+        "--frontierX\r\n",
+        "--frontier-x\r\n",
+        "--frontier \tx\r\n",
+        "--frontier\r\r\n",
+        "--frontier\r\n",
+        "\r\n",
+        "a\r\n",
+        "--frontier--",
+    );
+
+    for reader in
+        [Box::new(message.as_bytes()) as Box<dyn Read>, Box::new(OneByteReader(message.as_bytes()))]
+    {
+        let mut reader = MultipartReader::new(reader, "frontier");
+
+        let mut body = Vec::new();
+
+        reader.next_part().unwrap().unwrap().read_to_end(&mut body).unwrap();
+
+        assert_eq!(
+            b"--frontierX\r\n--frontier-x\r\n--frontier \tx\r\n--frontier\r".as_slice(),
+            body.as_slice()
+        );
+
+        body.clear();
+
+        reader.next_part().unwrap().unwrap().read_to_end(&mut body).unwrap();
+
+        assert_eq!(b"a".as_slice(), body.as_slice());
+
+        assert!(reader.next_part().unwrap().is_none());
+    }
+}
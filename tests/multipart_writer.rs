@@ -0,0 +1,115 @@
+use std::io::Write;
+
+use base64_stream::MultipartWriter;
+
+#[test]
+fn multipart_write() {
+    let mut writer = MultipartWriter::new(Vec::new(), "frontier");
+
+    let mut part = writer.start_part("text/plain; charset=utf-8", None).unwrap();
+
+    part.write_all(b"Hello").unwrap();
+    part.finish().unwrap();
+
+    let mut part = writer.start_attachment("application/octet-stream", "a \"b\".bin").unwrap();
+
+    part.write_all(&[0xFB, 0xFF]).unwrap();
+    part.finish().unwrap();
+
+    assert_eq!(2, writer.part_count());
+
+    let expected = concat!(
+        "--frontier\r\n",
+        "Content-Type: text/plain; charset=utf-8\r\n",
+        "Content-Transfer-Encoding: base64\r\n",
+        "\r\n",
+        "SGVsbG8=\r\n",
+        "--frontier\r\n",
+        "Content-Type: application/octet-stream\r\n",
+        "Content-Transfer-Encoding: base64\r\n",
+        "Content-Disposition: attachment; filename=\"a \\\"b\\\".bin\"\r\n",
+        "\r\n",
+        "+/8=\r\n",
+        "--frontier--\r\n",
+    );
+
+    assert_eq!(expected, String::from_utf8(writer.finish().unwrap()).unwrap());
+}
+
+#[test]
+fn multipart_line_wrap_write() {
+    use base64::Engine;
+
+    let plain = (0..=255u8).cycle().take(1000).collect::<Vec<u8>>();
+
+    let mut writer = MultipartWriter::new(Vec::new(), "b");
+
+    let mut part = writer.start_part("application/octet-stream", None).unwrap();
+
+    part.write_all(&plain).unwrap();
+    part.finish().unwrap();
+
+    let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    let body = output
+        .strip_prefix(concat!(
+            "--b\r\n",
+            "Content-Type: application/octet-stream\r\n",
+            "Content-Transfer-Encoding: base64\r\n",
+            "\r\n",
+        ))
+        .unwrap()
+        .strip_suffix("\r\n--b--\r\n")
+        .unwrap();
+
+    assert!(body.split("\r\n").all(|line| line.len() <= 76));
+    assert_eq!(base64::engine::general_purpose::STANDARD.encode(&plain), body.replace("\r\n", ""));
+}
+
+#[test]
+fn multipart_non_ascii_filename_write() {
+    let mut writer = MultipartWriter::new(Vec::new(), "b");
+
+    writer.start_attachment("image/png", "résumé 1.png").unwrap().finish().unwrap();
+
+    let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    assert!(output.contains(
+        "Content-Disposition: attachment; filename*=UTF-8''r%C3%A9sum%C3%A9%201.png\r\n"
+    ));
+}
+
+#[test]
+fn multipart_empty_write() {
+    let writer = MultipartWriter::new(Vec::new(), "b");
+
+    assert_eq!(b"--b--\r\n", writer.finish().unwrap().as_slice());
+}
+
+#[test]
+fn multipart_unfinished_part_write() {
+    let mut writer = MultipartWriter::new(Vec::new(), "b");
+
+    drop(writer.start_part("text/plain", None).unwrap());
+
+    assert!(writer.start_part("text/plain", None).is_err());
+    assert!(writer.finish().is_err());
+}
+
+#[test]
+fn multipart_header_injection_write() {
+    use std::io::ErrorKind;
+
+    let mut writer = MultipartWriter::new(Vec::new(), "b");
+
+    let error = writer.start_part("text/plain\r\nX-Injected: 1", None).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidInput, error.kind());
+    assert!(writer.get_ref().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn multipart_invalid_boundary() {
+    MultipartWriter::new(Vec::new(), "a\"b");
+}
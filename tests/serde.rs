@@ -0,0 +1,121 @@
+#![cfg(feature = "serde")]
+
+use std::{
+    cell::RefCell,
+    io::{self, Cursor, Read},
+    sync::Mutex,
+};
+
+use base64_stream::{
+    base64::{Engine, engine::general_purpose::STANDARD},
+    serde::Base64Seed,
+};
+use serde::{Deserialize, Serialize, de::DeserializeSeed};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Attachment {
+    name: String,
+    #[serde(with = "base64_stream::serde")]
+    data: Vec<u8>,
+}
+
+#[test]
+fn serde_vec() {
+    let data: Vec<u8> = (0..=255).cycle().take(10000).collect();
+
+    let attachment = Attachment {
+        name: String::from("a.bin"), data: data.clone()
+    };
+
+    let json = serde_json::to_string(&attachment).unwrap();
+
+    assert_eq!(format!(r#"{{"name":"a.bin","data":"{}"}}"#, STANDARD.encode(&data)), json);
+
+    let attachment: Attachment = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(data, attachment.data);
+
+    let attachment: Attachment = serde_json::from_reader(json.as_bytes()).unwrap();
+
+    assert_eq!(data, attachment.data);
+}
+
+#[test]
+fn serde_reader() {
+    #[derive(Serialize)]
+    struct Upload {
+        #[serde(with = "base64_stream::serde")]
+        cell:  RefCell<Cursor<Vec<u8>>>,
+        #[serde(with = "base64_stream::serde")]
+        mutex: Mutex<&'static [u8]>,
+    }
+
+    #[derive(Deserialize)]
+    struct Download {
+        #[serde(with = "base64_stream::serde")]
+        cell:  Cursor<Vec<u8>>,
+        #[serde(with = "base64_stream::serde")]
+        mutex: Vec<u8>,
+    }
+
+    let upload = Upload {
+        cell:  RefCell::new(Cursor::new(b"abcd".to_vec())),
+        mutex: Mutex::new(b"Hi there"),
+    };
+
+    let json = serde_json::to_string(&upload).unwrap();
+
+    assert_eq!(r#"{"cell":"YWJjZA==","mutex":"SGkgdGhlcmU="}"#, json);
+
+    let mut download: Download = serde_json::from_str(&json).unwrap();
+
+    let mut cell = Vec::new();
+
+    download.cell.set_position(0);
+    download.cell.read_to_end(&mut cell).unwrap();
+
+    assert_eq!(b"abcd", cell.as_slice());
+    assert_eq!(b"Hi there", download.mutex.as_slice());
+}
+
+#[test]
+fn serde_read_error() {
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("broken source"))
+        }
+    }
+
+    #[derive(Serialize)]
+    struct Upload {
+        #[serde(with = "base64_stream::serde")]
+        data: RefCell<Failing>,
+    }
+
+    let error = serde_json::to_string(&Upload {
+        data: RefCell::new(Failing)
+    })
+    .unwrap_err();
+
+    assert!(error.to_string().contains("broken source"));
+}
+
+#[test]
+fn serde_seed() {
+    let mut deserializer = serde_json::Deserializer::from_str(r#""YWJjZA==""#);
+
+    let sink = Base64Seed::new(b"data: ".to_vec()).deserialize(&mut deserializer).unwrap();
+
+    assert_eq!(b"data: abcd", sink.as_slice());
+
+    let error =
+        serde_json::from_str::<Attachment>(r#"{"name":"a","data":"YWJj!A=="}"#).unwrap_err();
+
+    assert!(error.is_data());
+
+    let error = serde_json::from_str::<Attachment>(r#"{"name":"a","data":1}"#).unwrap_err();
+
+    assert!(error.to_string().contains("a base64 string"));
+}
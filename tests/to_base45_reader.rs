@@ -0,0 +1,36 @@
+use std::io::{self, Read};
+
+use base64_stream::ToBase45Reader;
+
+struct OneByteReader<'a>(&'a [u8]);
+
+impl Read for OneByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() || buf.is_empty() {
+            return Ok(0);
+        }
+
+        buf[0] = self.0[0];
+        self.0 = &self.0[1..];
+
+        Ok(1)
+    }
+}
+
+#[test]
+fn encode_read() {
+    let mut base45 = String::new();
+
+    ToBase45Reader::new(b"Hello!!".as_slice()).read_to_string(&mut base45).unwrap();
+
+    assert_eq!("%69 VD92EX0", base45);
+}
+
+#[test]
+fn encode_one_byte_read() {
+    let mut base45 = String::new();
+
+    ToBase45Reader::<_, 4>::new2(OneByteReader(b"base-45")).read_to_string(&mut base45).unwrap();
+
+    assert_eq!("UJCLQE7W581", base45);
+}
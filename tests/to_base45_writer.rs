@@ -0,0 +1,80 @@
+use std::io::Write;
+
+use base64_stream::ToBase45Writer;
+
+fn encode(plain: &[u8]) -> Vec<u8> {
+    let mut writer = ToBase45Writer::new(Vec::new());
+
+    writer.write_all(plain).unwrap();
+
+    writer.finish().unwrap()
+}
+
+#[test]
+fn encode_write() {
+    assert_eq!(b"".as_slice(), encode(b""));
+    assert_eq!(b"BB8".as_slice(), encode(b"AB"));
+    assert_eq!(b"%69 VD92EX0".as_slice(), encode(b"Hello!!"));
+    assert_eq!(b"UJCLQE7W581".as_slice(), encode(b"base-45"));
+    assert_eq!(b"QED8WEX0".as_slice(), encode(b"ietf!"));
+}
+
+#[test]
+fn encode_carry_write() {
+    // the odd byte of every write completes a block with the next one
+    let mut writer = ToBase45Writer::new(Vec::new());
+
+    for chunk in [b"H".as_slice(), b"ell", b"o", b"!!"] {
+        writer.write_all(chunk).unwrap();
+    }
+
+    assert_eq!(b"%69 VD92E".as_slice(), writer.get_ref());
+    assert_eq!(b"%69 VD92EX0".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+fn encode_retry_write() {
+    use std::io;
+
+    struct WouldBlockOnceWriter {
+        data:    Vec<u8>,
+        blocked: bool,
+    }
+
+    impl Write for WouldBlockOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.blocked {
+                self.blocked = true;
+
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = WouldBlockOnceWriter {
+        data: Vec::new(), blocked: false
+    };
+
+    let mut writer = ToBase45Writer::new(inner);
+
+    // an error means that nothing has been taken, so the same bytes are written again
+    let mut input = b"Hello!!".as_slice();
+
+    while !input.is_empty() {
+        match writer.write(input) {
+            Ok(c) => input = &input[c..],
+            Err(error) => assert_eq!(io::ErrorKind::WouldBlock, error.kind()),
+        }
+    }
+
+    assert!(writer.get_ref().blocked);
+    assert_eq!(b"%69 VD92EX0".as_slice(), writer.finish().unwrap().data);
+}
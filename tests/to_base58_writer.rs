@@ -0,0 +1,81 @@
+use std::io::Write;
+
+use base64_stream::{Base58Alphabet, ToBase58Writer};
+
+fn encode(plain: &[u8]) -> String {
+    let mut writer = ToBase58Writer::new(Vec::new());
+
+    writer.write_all(plain).unwrap();
+
+    String::from_utf8(writer.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn encode_write() {
+    assert_eq!("", encode(b""));
+    assert_eq!("2NEpo7TZRRrLZSi2U", encode(b"Hello World!"));
+    assert_eq!(
+        "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z",
+        encode(b"The quick brown fox jumps over the lazy dog.")
+    );
+}
+
+#[test]
+fn encode_leading_zeros_write() {
+    assert_eq!("1", encode(b"\x00"));
+    assert_eq!("111", encode(b"\x00\x00\x00"));
+    assert_eq!("11233QC4", encode(b"\x00\x00\x28\x7f\xb4\xcd"));
+}
+
+#[test]
+fn encode_alphabets_write() {
+    for (alphabet, expected) in [
+        (Base58Alphabet::Ripple, "p4NFofTZRRiLZS5p7"),
+        (Base58Alphabet::Flickr, "2nePN7syqqRkyrH2t"),
+    ] {
+        let mut writer = ToBase58Writer::new(Vec::new()).with_alphabet(alphabet);
+
+        writer.write_all(b"Hello World!").unwrap();
+
+        assert_eq!(expected.as_bytes(), writer.finish().unwrap());
+    }
+}
+
+#[cfg(feature = "base58check")]
+#[test]
+fn encode_check_write() {
+    use sha2::{Digest, Sha256};
+
+    let plain =
+        b"\x00\x01\x09\x66\x77\x60\x06\x95\x3d\x55\x67\x43\x9e\x5e\x39\xf8\x6a\x0d\x27\x3b\xee";
+
+    let mut writer = ToBase58Writer::new(Vec::new()).with_check();
+
+    for b in plain {
+        writer.write_all(&[*b]).unwrap();
+    }
+
+    let output = writer.finish().unwrap();
+
+    assert_eq!(b"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM".as_slice(), output);
+
+    let hash = Sha256::digest(Sha256::digest(plain));
+
+    assert_eq!(encode(&[plain.as_slice(), &hash[..4]].concat()).as_bytes(), output);
+}
+
+#[test]
+fn encode_limit_write() {
+    use std::io::ErrorKind;
+
+    use base64_stream::LimitExceeded;
+
+    let mut writer = ToBase58Writer::new(Vec::new()).with_limit(4);
+
+    assert_eq!(4, writer.write(b"Hello").unwrap());
+
+    let error = writer.write(b"o").unwrap_err();
+
+    assert_eq!(ErrorKind::Other, error.kind());
+    assert_eq!(Some(&LimitExceeded::Decoded(4)), error.get_ref().unwrap().downcast_ref());
+}
@@ -0,0 +1,73 @@
+use std::{
+    fmt::{self, Write as _},
+    io::{Cursor, Write},
+};
+
+use base64_stream::{Base64ChunksDisplay, Base64Display, ToBase64FmtWriter};
+
+#[test]
+fn encode_write() {
+    let mut writer = ToBase64FmtWriter::new(String::new());
+
+    writer.write_all(b"Hi there, this is a simple sentence used for testing this crate. I hope all cases are correct.").unwrap();
+
+    let base64 = writer.finish().unwrap();
+
+    assert_eq!("SGkgdGhlcmUsIHRoaXMgaXMgYSBzaW1wbGUgc2VudGVuY2UgdXNlZCBmb3IgdGVzdGluZyB0aGlzIGNyYXRlLiBJIGhvcGUgYWxsIGNhc2VzIGFyZSBjb3JyZWN0Lg==", base64);
+}
+
+#[test]
+fn encode_write_small_buffer() {
+    let mut writer = ToBase64FmtWriter::<_, 4>::new2(String::new());
+
+    for b in b"Hi there, this is a simple sentence" {
+        writer.write_all(&[*b]).unwrap();
+    }
+
+    let base64 = writer.finish().unwrap();
+
+    assert_eq!("SGkgdGhlcmUsIHRoaXMgaXMgYSBzaW1wbGUgc2VudGVuY2U=", base64);
+}
+
+#[test]
+fn encode_write_error() {
+    struct Failing;
+
+    impl fmt::Write for Failing {
+        fn write_str(&mut self, _s: &str) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    let mut writer = ToBase64FmtWriter::new(Failing);
+
+    let error = writer.write_all(b"abc").unwrap_err();
+
+    assert!(error.get_ref().unwrap().is::<fmt::Error>());
+}
+
+#[test]
+fn encode_display() {
+    let display = Base64Display::new(Cursor::new(b"abcd".to_vec()));
+
+    assert_eq!("YWJjZA==", display.to_string());
+    assert_eq!("", display.to_string());
+
+    let mut s = String::new();
+
+    write!(s, "[{}]", Base64Display::new(b"".as_slice())).unwrap();
+
+    assert_eq!("[]", s);
+}
+
+#[test]
+fn encode_chunks_display() {
+    let display = Base64ChunksDisplay::new([b"a".as_slice(), b"bc", b"", b"d"]);
+
+    assert_eq!("YWJjZA==", display.to_string());
+    assert_eq!("YWJjZA==", format!("{display}"));
+
+    let chunks = vec![vec![0u8; 2], vec![0xFF; 1]];
+
+    assert_eq!("AAD/", Base64ChunksDisplay::new(&chunks).to_string());
+}
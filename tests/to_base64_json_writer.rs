@@ -0,0 +1,38 @@
+use std::io::Write;
+
+use base64_stream::{Base64Variant, ToBase64JsonWriter};
+
+#[test]
+fn encode_json_write() {
+    let mut json = b"{\"data\":".to_vec();
+
+    let mut writer = ToBase64JsonWriter::new(&mut json);
+
+    for chunk in b"Hi there, this is a simple sentence used for testing this crate. I hope all cases are correct.".chunks(5) {
+        writer.write_all(chunk).unwrap();
+    }
+
+    writer.finish().unwrap();
+
+    json.push(b'}');
+
+    let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+
+    assert_eq!("SGkgdGhlcmUsIHRoaXMgaXMgYSBzaW1wbGUgc2VudGVuY2UgdXNlZCBmb3IgdGVzdGluZyB0aGlzIGNyYXRlLiBJIGhvcGUgYWxsIGNhc2VzIGFyZSBjb3JyZWN0Lg==", value["data"]);
+}
+
+#[test]
+fn encode_json_empty() {
+    let writer = ToBase64JsonWriter::new(Vec::new());
+
+    assert_eq!(b"\"\"", writer.finish().unwrap().as_slice());
+}
+
+#[test]
+fn encode_json_variant() {
+    let mut writer = ToBase64JsonWriter::new(Vec::new()).with_variant(Base64Variant::UrlSafeNoPad);
+
+    writer.write_all(b"\xfb\xff").unwrap();
+
+    assert_eq!(b"\"-_8\"", writer.finish().unwrap().as_slice());
+}
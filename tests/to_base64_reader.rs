@@ -1,4 +1,4 @@
-use std::io::{Cursor, Error, ErrorKind, Read};
+use std::io::{Cursor, Error, ErrorKind, IoSliceMut, Read};
 
 use base64_stream::ToBase64Reader;
 
@@ -24,6 +24,19 @@ impl Read for CountedReader {
     }
 }
 
+#[derive(Debug)]
+struct OneByteReader {
+    data: Cursor<Vec<u8>>,
+}
+
+impl Read for OneByteReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let length = buf.len().min(1);
+
+        self.data.read(&mut buf[..length])
+    }
+}
+
 #[derive(Debug)]
 struct FailAfterFirstRead {
     data:       Cursor<&'static [u8]>,
@@ -200,3 +213,178 @@ fn encode_small_buffer() {
 
     assert_eq!(out, b"YWJjZA==");
 }
+
+fn read_in_chunks<R: Read>(mut reader: R, chunk_size: usize) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut chunk = vec![0u8; chunk_size];
+
+    loop {
+        let c = reader.read(&mut chunk).unwrap();
+
+        if c == 0 {
+            return out;
+        }
+
+        out.extend_from_slice(&chunk[..c]);
+    }
+}
+
+#[test]
+fn encode_large_destination() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    for length in [0, 1, 2, 3, 4, 5, 767, 768, 769, 100_000] {
+        let plain: Vec<u8> = (0u8..=255).cycle().take(length).collect();
+        let expected = STANDARD.encode(&plain);
+
+        let reader = ToBase64Reader::new(Cursor::new(plain));
+
+        assert_eq!(read_in_chunks(reader, 65536), expected.as_bytes());
+    }
+}
+
+#[test]
+fn encode_large_destination_after_small_read() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10_000).collect();
+    let expected = STANDARD.encode(&plain);
+
+    let mut reader = ToBase64Reader::<_, 16>::new2(Cursor::new(plain));
+
+    let mut first = [0; 5];
+
+    reader.read_exact(&mut first).unwrap();
+
+    let mut out = first.to_vec();
+
+    out.extend_from_slice(&read_in_chunks(reader, 4099));
+
+    assert_eq!(out, expected.as_bytes());
+}
+
+#[test]
+fn encode_large_destination_one_byte_reads() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(1000).collect();
+    let expected = STANDARD.encode(&plain);
+
+    let reader = ToBase64Reader::<_, 8>::new2(OneByteReader {
+        data: Cursor::new(plain)
+    });
+
+    assert_eq!(read_in_chunks(reader, 64), expected.as_bytes());
+}
+
+#[test]
+fn encode_read_vectored() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(1000).collect();
+    let expected = STANDARD.encode(&plain).into_bytes();
+
+    let mut reader = ToBase64Reader::<_, 16>::new2(Cursor::new(plain));
+
+    let mut out = Vec::new();
+
+    loop {
+        let mut a = [0u8; 1];
+        let mut b = [0u8; 2];
+        let mut c = [0u8; 5];
+        let mut d = [0u8; 7];
+
+        let mut slices = [
+            IoSliceMut::new(&mut a),
+            IoSliceMut::new(&mut []),
+            IoSliceMut::new(&mut b),
+            IoSliceMut::new(&mut c),
+            IoSliceMut::new(&mut d),
+        ];
+
+        let read_length = reader.read_vectored(&mut slices).unwrap();
+
+        if read_length == 0 {
+            break;
+        }
+
+        let joined: Vec<u8> = [&a[..], &b[..], &c[..], &d[..]].concat();
+
+        out.extend_from_slice(&joined[..read_length]);
+    }
+
+    assert_eq!(expected, out);
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn encode_digest() {
+    use sha2::{Digest, Sha256};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+
+    for chunk_size in [1, 100, 10000] {
+        let mut reader =
+            ToBase64Reader::new(Cursor::new(plain.as_slice())).with_digest(Sha256::new());
+
+        let mut b = vec![0; chunk_size];
+
+        while reader.read(&mut b).unwrap() > 0 {}
+
+        let (_, pending) = reader.into_parts();
+
+        assert_eq!(Sha256::digest(&plain).as_slice(), &*pending.digest.unwrap());
+    }
+}
+
+#[test]
+fn encode_progress() {
+    use base64_stream::Progress;
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+
+    let mut reader = ToBase64Reader::new(Cursor::new(plain.as_slice()));
+
+    let mut base64 = Vec::new();
+
+    reader.read_to_end(&mut base64).unwrap();
+
+    assert_eq!(
+        Progress {
+            plain: 10000, encoded: base64.len() as u64
+        },
+        reader.progress()
+    );
+}
+
+#[test]
+fn encode_into_parts() {
+    let mut reader = ToBase64Reader::new(Cursor::new(b"abcdef".to_vec()));
+
+    let mut b = [0; 2];
+
+    reader.read_exact(&mut b).unwrap();
+
+    reader.get_mut().set_position(6);
+
+    let (inner, pending) = reader.into_parts();
+
+    assert_eq!(b"YW", &b);
+    assert_eq!(b"def", pending.plain.as_slice());
+    assert_eq!(b"Jj", pending.encoded.as_slice());
+    assert_eq!(6, inner.position());
+}
+
+#[test]
+fn encode_alphabet() {
+    use base64_stream::Base64Alphabet;
+
+    let mut reader = ToBase64Reader::<_, 4>::new2(Cursor::new(b"\xff\xfe\xfd\xfcfoobar!".to_vec()))
+        .with_alphabet(&Base64Alphabet::IMAP_MAILBOX);
+
+    let mut test_data = Vec::new();
+
+    reader.read_to_end(&mut test_data).unwrap();
+
+    assert_eq!(b",,79,GZvb2JhciE", test_data.as_slice());
+}
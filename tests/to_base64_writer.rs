@@ -1,6 +1,6 @@
 use std::{
     fs::{self, File},
-    io::{self, Cursor, ErrorKind, Write},
+    io::{self, Cursor, ErrorKind, IoSlice, Write},
     path::Path,
 };
 
@@ -30,6 +30,25 @@ impl Write for FlushCountingWriter {
     }
 }
 
+#[derive(Debug, Default)]
+struct WriteCountingWriter {
+    data:        Vec<u8>,
+    write_count: usize,
+}
+
+impl Write for WriteCountingWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.write_count += 1;
+        self.data.extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+}
+
 const DATA_FOLDER: &str = "data";
 const ENCODE_OUTPUT: &str = "encode_output.txt";
 
@@ -190,3 +209,264 @@ fn encode_finish_returns_inner_flush_error() {
 
     assert_eq!(ErrorKind::BrokenPipe, error.kind());
 }
+
+#[test]
+fn encode_write_vectored_carries_blocks_across_slices() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(100).collect();
+
+    let mut slices = Vec::new();
+    let mut rest = plain.as_slice();
+
+    for length in [1, 1, 2, 5, 7, 1, 3, 4, 11, 2].into_iter().cycle() {
+        if rest.is_empty() {
+            break;
+        }
+
+        let (slice, tail) = rest.split_at(length.min(rest.len()));
+
+        slices.push(IoSlice::new(slice));
+        rest = tail;
+    }
+
+    let mut writer = ToBase64Writer::new(WriteCountingWriter::default());
+
+    assert_eq!(plain.len(), writer.write_vectored(&slices).unwrap());
+
+    let inner = writer.finish().unwrap();
+
+    assert_eq!(STANDARD.encode(&plain).as_bytes(), inner.data);
+    assert_eq!(2, inner.write_count);
+}
+
+#[test]
+fn encode_write_vectored_small_buffer() {
+    use base64_stream::base64::{Engine, engine::general_purpose::STANDARD};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(100).collect();
+
+    let slices: Vec<IoSlice> = plain.chunks(7).map(IoSlice::new).collect();
+
+    let mut writer = ToBase64Writer::<_, 6>::new2(Vec::new());
+
+    assert_eq!(plain.len(), writer.write_vectored(&slices).unwrap());
+
+    assert_eq!(STANDARD.encode(&plain).as_bytes(), writer.finish().unwrap());
+}
+
+#[test]
+fn encode_write_joins_pending_block_with_the_rest() {
+    let mut writer = ToBase64Writer::new(WriteCountingWriter::default());
+
+    writer.write_all(b"a").unwrap();
+    writer.write_all(b"bcdef").unwrap();
+
+    let inner = writer.finish().unwrap();
+
+    assert_eq!(b"YWJjZGVm", inner.data.as_slice());
+    assert_eq!(1, inner.write_count);
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn encode_digest_write() {
+    use sha2::{Digest, Sha256};
+
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+
+    let mut writer = ToBase64Writer::new(Vec::new()).with_digest(Sha256::new());
+
+    for chunk in plain.chunks(7) {
+        writer.write_all(chunk).unwrap();
+    }
+
+    let (_, digest) = writer.finish_with_digest().unwrap();
+
+    assert_eq!(Sha256::digest(&plain).as_slice(), &*digest.unwrap());
+
+    let mut writer = ToBase64Writer::new(Vec::new()).with_digest(Sha256::new());
+
+    writer.write_all(b"abcd").unwrap();
+
+    let (_, pending) = writer.into_parts();
+
+    assert_eq!(b"d", pending.plain.as_slice());
+    assert_eq!(Sha256::digest(b"abcd").as_slice(), &*pending.digest.unwrap());
+}
+
+#[test]
+fn encode_progress_write() {
+    use base64_stream::Progress;
+
+    let mut writer = ToBase64Writer::new(Vec::new());
+
+    writer.write_all(b"abcd").unwrap();
+
+    assert_eq!(
+        Progress {
+            plain: 4, encoded: 4
+        },
+        writer.progress()
+    );
+}
+
+#[test]
+fn encode_progress_callback_write() {
+    use std::{cell::Cell, sync::mpsc};
+
+    let (sender, receiver) = mpsc::channel();
+    let calls = Cell::new(0);
+
+    // neither the `Cell` nor the closure owning it is `Sync`
+    let mut writer = ToBase64Writer::new(Vec::new()).with_progress(2, move |progress| {
+        calls.set(calls.get() + 1);
+
+        sender.send((calls.get(), progress.plain)).unwrap();
+    });
+
+    writer.write_all(b"abcd").unwrap();
+    writer.write_all(b"e").unwrap();
+    writer.write_all(b"f").unwrap();
+
+    assert_eq!(vec![(1, 4), (2, 6)], receiver.try_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn encode_into_parts_write() {
+    let mut writer = ToBase64Writer::new(Vec::new());
+
+    writer.write_all(b"abcd").unwrap();
+
+    writer.get_mut().push(b'!');
+
+    let (inner, pending) = writer.into_parts();
+
+    assert_eq!(b"YWJj!", inner.as_slice());
+    assert_eq!(b"d", pending.plain.as_slice());
+    assert!(pending.encoded.is_empty());
+}
+
+#[test]
+fn encode_variant() {
+    use base64_stream::Base64Variant;
+
+    let mut writer = ToBase64Writer::new(Vec::new()).with_variant(Base64Variant::UrlSafeNoPad);
+
+    writer.write_all(b"\xfb\xff").unwrap();
+
+    assert_eq!(b"-_8", writer.finish().unwrap().as_slice());
+}
+
+#[test]
+fn encode_alphabet() {
+    use base64_stream::Base64Alphabet;
+
+    static STARRED: Base64Alphabet = Base64Alphabet::new(
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        Some(b'*'),
+    );
+
+    let mut writer = ToBase64Writer::new(Vec::new()).with_alphabet(&Base64Alphabet::BCRYPT);
+
+    writer.write_all(b"\xfa\xfb\xfc\xfd\xfe\xffhello world").unwrap();
+
+    assert_eq!(b"8tt69d59YETqZE6eb07wZEO", writer.finish().unwrap().as_slice());
+
+    let mut writer = ToBase64Writer::<_, 4>::new2(Vec::new()).with_alphabet(&STARRED);
+
+    for b in b"foobar!" {
+        writer.write_all(&[*b]).unwrap();
+    }
+
+    assert_eq!(b"Zm9vYmFyIQ**", writer.finish().unwrap().as_slice());
+}
+
+#[test]
+#[should_panic(expected = "the padding must not be in the alphabet")]
+fn encode_alphabet_padding_in_symbols() {
+    use base64_stream::Base64Alphabet;
+
+    Base64Alphabet::new(
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+        Some(b'/'),
+    );
+}
+
+#[test]
+#[should_panic(expected = "`=` is reserved for the padding")]
+fn encode_alphabet_reserved_symbol() {
+    use base64_stream::Base64Alphabet;
+
+    Base64Alphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+=", None);
+}
+
+#[test]
+fn encode_line_wrap() {
+    use base64::Engine;
+    use base64_stream::LineEnding;
+
+    let plain = (0..=255u8).cycle().take(1000).collect::<Vec<u8>>();
+
+    let mut writer = ToBase64Writer::new(Vec::new()).with_line_wrap(76, LineEnding::CRLF);
+
+    for b in plain.chunks(7) {
+        writer.write_all(b).unwrap();
+    }
+
+    let expected = base64::engine::general_purpose::STANDARD
+        .encode(&plain)
+        .as_bytes()
+        .chunks(76)
+        .collect::<Vec<_>>()
+        .join(b"\r\n".as_ref());
+
+    assert_eq!(expected, writer.finish().unwrap());
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn encode_digest_retry_write() {
+    use sha2::{Digest, Sha256};
+
+    struct FailOnceWriter {
+        data:   Vec<u8>,
+        failed: bool,
+    }
+
+    impl Write for FailOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.failed {
+                self.failed = true;
+
+                return Err(io::Error::other("failed once"));
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = FailOnceWriter {
+        data: Vec::new(), failed: false
+    };
+
+    let mut writer = ToBase64Writer::new(inner).with_digest(Sha256::new());
+
+    assert!(writer.write(b"abc").is_err());
+    assert_eq!(0, writer.progress().plain);
+
+    writer.write_all(b"abc").unwrap();
+
+    assert_eq!(3, writer.progress().plain);
+
+    let (inner, digest) = writer.finish_with_digest().unwrap();
+
+    assert_eq!(b"YWJj", inner.data.as_slice());
+    assert_eq!(Sha256::digest(b"abc").as_slice(), &*digest.unwrap());
+}
@@ -0,0 +1,105 @@
+use std::io::Write;
+
+use base64_stream::{Bech32Variant, ToBech32Writer};
+
+fn encode(hrp: &str, variant: Bech32Variant, plain: &[u8]) -> String {
+    let mut writer = ToBech32Writer::new(Vec::new(), hrp).with_variant(variant);
+
+    for b in plain {
+        writer.write_all(&[*b]).unwrap();
+    }
+
+    String::from_utf8(writer.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn encode_write() {
+    assert_eq!("a12uel5l", encode("A", Bech32Variant::Bech32, b""));
+    assert_eq!(
+        "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+        encode(
+            "abcdef",
+            Bech32Variant::Bech32,
+            b"\x00\x44\x32\x14\xc7\x42\x54\xb6\x35\xcf\x84\x65\x3a\x56\xd7\xc6\x75\xbe\x77\xdf"
+        )
+    );
+}
+
+#[test]
+fn encode_incremental_write() {
+    let mut writer = ToBech32Writer::new(Vec::new(), "a");
+
+    writer.write_all(b"\x00\x44\x32").unwrap();
+
+    // 24 bits make 4 whole characters, and the last 4 bits wait for the next byte
+    assert_eq!(b"a1qpzr".as_slice(), writer.get_ref());
+}
+
+#[test]
+fn encode_bech32m_write() {
+    assert_eq!("a1lqfn3a", encode("a", Bech32Variant::Bech32m, b""));
+    assert_eq!(
+        "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+        encode(
+            "abcdef",
+            Bech32Variant::Bech32m,
+            b"\xff\xbb\xcd\xeb\x38\xbd\xab\x49\xca\x30\x7b\x9a\xc5\xa9\x28\x39\x8a\x41\x88\x20"
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid human-readable part")]
+fn encode_invalid_hrp_write() {
+    ToBech32Writer::new(Vec::new(), "a b");
+}
+
+#[test]
+fn encode_retry_write() {
+    use std::io;
+
+    struct WouldBlockOnceWriter {
+        data:    Vec<u8>,
+        blocked: bool,
+    }
+
+    impl Write for WouldBlockOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.blocked {
+                self.blocked = true;
+
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = WouldBlockOnceWriter {
+        data: Vec::new(), blocked: false
+    };
+
+    let mut writer = ToBech32Writer::new(inner, "a");
+
+    // an error means that nothing has been taken, so the same bytes are written again
+    let mut input = b"hello world".as_slice();
+
+    while !input.is_empty() {
+        match writer.write(input) {
+            Ok(c) => input = &input[c..],
+            Err(error) => assert_eq!(io::ErrorKind::WouldBlock, error.kind()),
+        }
+    }
+
+    assert!(writer.get_ref().blocked);
+    assert_eq!(
+        encode("a", Bech32Variant::Bech32, b"hello world").into_bytes().as_slice(),
+        writer.finish().unwrap().data
+    );
+}
@@ -0,0 +1,53 @@
+use std::io::Read;
+
+use base64_stream::ToQuotedPrintableReader;
+
+#[test]
+fn encode_read() {
+    let mut reader = ToQuotedPrintableReader::new(b"a=b c \nd\xff\t".as_slice());
+
+    let mut output = Vec::new();
+
+    reader.read_to_end(&mut output).unwrap();
+
+    assert_eq!(b"a=3Db c=20\r\nd=FF=09".as_slice(), output);
+}
+
+#[test]
+fn encode_small_buffer_read() {
+    let plain = "Grüße aus Köln \r\n= tab\t\r\n".repeat(20);
+
+    let mut reader = ToQuotedPrintableReader::<_, 4>::new2(plain.as_bytes());
+
+    let mut output = Vec::new();
+
+    let mut buf = [0; 3];
+
+    loop {
+        let c = reader.read(&mut buf).unwrap();
+
+        if c == 0 {
+            break;
+        }
+
+        output.extend_from_slice(&buf[..c]);
+    }
+
+    let mut expected = Vec::new();
+
+    ToQuotedPrintableReader::new(plain.as_bytes()).read_to_end(&mut expected).unwrap();
+
+    assert_eq!(expected, output);
+    assert!(output.split(|&b| b == b'\n').all(|line| line.len() <= 77));
+}
+
+#[test]
+fn encode_binary_read() {
+    let mut reader = ToQuotedPrintableReader::new(b"\r\n".as_slice()).with_binary();
+
+    let mut output = Vec::new();
+
+    reader.read_to_end(&mut output).unwrap();
+
+    assert_eq!(b"=0D=0A".as_slice(), output);
+}
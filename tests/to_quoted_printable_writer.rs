@@ -0,0 +1,129 @@
+use std::io::Write;
+
+use base64_stream::ToQuotedPrintableWriter;
+
+fn encode(plain: &[u8]) -> Vec<u8> {
+    let mut writer = ToQuotedPrintableWriter::new(Vec::new());
+
+    writer.write_all(plain).unwrap();
+
+    writer.finish().unwrap()
+}
+
+#[test]
+fn encode_write() {
+    assert_eq!(b"Hello, World!".as_slice(), encode(b"Hello, World!"));
+    assert_eq!(b"a=3Db".as_slice(), encode(b"a=b"));
+    assert_eq!(b"caf=C3=A9".as_slice(), encode("café".as_bytes()));
+    assert_eq!(b"=00=1B=7F".as_slice(), encode(b"\x00\x1b\x7f"));
+}
+
+#[test]
+fn encode_line_breaks_write() {
+    assert_eq!(b"a\r\nb\r\nc\r\nd".as_slice(), encode(b"a\nb\r\nc\rd"));
+    assert_eq!(b"a\r\n\r\n".as_slice(), encode(b"a\n\n"));
+}
+
+#[test]
+fn encode_trailing_whitespace_write() {
+    assert_eq!(b"a b=20\r\nc=09".as_slice(), encode(b"a b \nc\t"));
+    assert_eq!(b"a  =3D".as_slice(), encode(b"a  ="));
+}
+
+#[test]
+fn encode_soft_line_break_write() {
+    let output = encode(&[b'a'; 160]);
+
+    let lines = output.split(|&b| b == b'\n').collect::<Vec<_>>();
+
+    assert_eq!(3, lines.len());
+    assert_eq!(b"=\r".len() + 75, lines[0].len());
+    assert!(lines[0].ends_with(b"=\r"));
+    assert_eq!([b'a'; 10].as_slice(), lines[2]);
+
+    // an escape is never split across lines
+    let output = encode(&[0xFF; 30]);
+
+    assert!(output.starts_with(&[b"=FF".as_slice(); 25].concat()));
+    assert_eq!(b"=\r\n=FF=FF=FF=FF=FF".as_slice(), &output[75..]);
+}
+
+#[test]
+fn encode_binary_write() {
+    let mut writer = ToQuotedPrintableWriter::new(Vec::new()).with_binary();
+
+    writer.write_all(b"a\r\nb \n").unwrap();
+
+    assert_eq!(b"a=0D=0Ab =0A".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+fn encode_line_ending_write() {
+    use base64_stream::LineEnding;
+
+    let mut writer = ToQuotedPrintableWriter::new(Vec::new()).with_line_ending(LineEnding::LF);
+
+    writer.write_all(b"a \r\nb").unwrap();
+
+    assert_eq!(b"a=20\nb".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+fn encode_one_byte_write() {
+    let plain = "Grüße aus Köln \r\n= tab\t\r\n".repeat(20);
+
+    let mut writer = ToQuotedPrintableWriter::<_, 4>::new2(Vec::new());
+
+    for b in plain.as_bytes().chunks(1) {
+        writer.write_all(b).unwrap();
+    }
+
+    assert_eq!(encode(plain.as_bytes()), writer.finish().unwrap());
+}
+
+#[test]
+fn encode_retry_write() {
+    use std::io;
+
+    struct WouldBlockOnceWriter {
+        data:    Vec<u8>,
+        blocked: bool,
+    }
+
+    impl Write for WouldBlockOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.blocked {
+                self.blocked = true;
+
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = WouldBlockOnceWriter {
+        data: Vec::new(), blocked: false
+    };
+
+    let mut writer = ToQuotedPrintableWriter::new(inner);
+
+    // an error means that nothing has been taken, so the same bytes are written again
+    let mut input = b"hello".as_slice();
+
+    while !input.is_empty() {
+        match writer.write(input) {
+            Ok(c) => input = &input[c..],
+            Err(error) => assert_eq!(io::ErrorKind::WouldBlock, error.kind()),
+        }
+    }
+
+    assert!(writer.get_ref().blocked);
+    assert_eq!(b"hello".as_slice(), writer.finish().unwrap().data);
+}
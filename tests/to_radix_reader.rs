@@ -0,0 +1,30 @@
+use std::io::Read;
+
+use base64_stream::{RadixEngine, ToRadixReader};
+
+#[test]
+fn encode_base32_read() {
+    let mut reader = ToRadixReader::<_, 4>::new2(b"foobar".as_slice(), &RadixEngine::BASE32);
+
+    let mut base32 = String::new();
+
+    reader.read_to_string(&mut base32).unwrap();
+
+    assert_eq!("MZXW6YTBOI======", base32);
+    assert_eq!(6, reader.progress().plain);
+    assert_eq!(16, reader.progress().encoded);
+}
+
+#[test]
+fn encode_base16_one_byte_read() {
+    let mut reader = ToRadixReader::new(b"foobar".as_slice(), &RadixEngine::BASE16);
+
+    let mut base16 = Vec::new();
+    let mut buf = [0u8; 1];
+
+    while reader.read(&mut buf).unwrap() > 0 {
+        base16.push(buf[0]);
+    }
+
+    assert_eq!(b"666F6F626172".as_slice(), base16);
+}
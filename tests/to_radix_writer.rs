@@ -0,0 +1,123 @@
+use std::io::Write;
+
+use base64_stream::{RadixEngine, ToBase64Writer, ToRadixWriter};
+
+fn encode(engine: &'static RadixEngine, plain: &[u8]) -> String {
+    let mut writer = ToRadixWriter::new(Vec::new(), engine);
+
+    writer.write_all(plain).unwrap();
+
+    String::from_utf8(writer.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn encode_base32_write() {
+    for (plain, base32) in [
+        ("", ""),
+        ("f", "MY======"),
+        ("fo", "MZXQ===="),
+        ("foo", "MZXW6==="),
+        ("foob", "MZXW6YQ="),
+        ("fooba", "MZXW6YTB"),
+        ("foobar", "MZXW6YTBOI======"),
+    ] {
+        assert_eq!(base32, encode(&RadixEngine::BASE32, plain.as_bytes()));
+    }
+
+    assert_eq!("CPNMUOJ1E8======", encode(&RadixEngine::BASE32_HEX, b"foobar"));
+}
+
+#[test]
+fn encode_base16_base8_write() {
+    assert_eq!("666F6F626172", encode(&RadixEngine::BASE16, b"foobar"));
+    assert_eq!("31467557", encode(&RadixEngine::BASE8, b"foo"));
+    assert_eq!("314674", encode(&RadixEngine::BASE8, b"fo"));
+}
+
+#[test]
+fn encode_base64_write() {
+    let plain = (0..=255).collect::<Vec<u8>>();
+
+    for length in (0..=16).chain([255, 256]) {
+        let mut writer = ToBase64Writer::new(Vec::new());
+
+        writer.write_all(&plain[..length]).unwrap();
+
+        let base64 = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+        assert_eq!(base64, encode(&RadixEngine::BASE64, &plain[..length]));
+    }
+}
+
+#[test]
+fn encode_custom_write() {
+    static Z_BASE_32: RadixEngine = RadixEngine::new(5, b"ybndrfg8ejkmcpqxot1uwisza345h769", None);
+
+    let mut writer = ToRadixWriter::<_, 4>::new2(Vec::new(), &Z_BASE_32).with_progress(1, |_| ());
+
+    for b in b"foobar" {
+        writer.write_all(&[*b]).unwrap();
+    }
+
+    assert_eq!(6, writer.progress().plain);
+    assert_eq!(b"c3zs6aubqe".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+#[should_panic(expected = "duplicates")]
+fn invalid_alphabet() {
+    RadixEngine::new(4, b"0123456789ABCDEA", None);
+}
+
+#[test]
+#[should_panic(expected = "printable ASCII")]
+fn unprintable_alphabet() {
+    RadixEngine::new(1, b"0\t", None);
+}
+
+#[test]
+fn encode_retry_write() {
+    use std::io;
+
+    struct WouldBlockOnceWriter {
+        data:    Vec<u8>,
+        blocked: bool,
+    }
+
+    impl Write for WouldBlockOnceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !self.blocked {
+                self.blocked = true;
+
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            self.data.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let inner = WouldBlockOnceWriter {
+        data: Vec::new(), blocked: false
+    };
+
+    let mut writer = ToRadixWriter::new(inner, &RadixEngine::BASE32);
+
+    // an error means that nothing has been taken, so the same bytes are written again
+    let mut input = b"hello world".as_slice();
+
+    while !input.is_empty() {
+        match writer.write(input) {
+            Ok(c) => input = &input[c..],
+            Err(error) => assert_eq!(io::ErrorKind::WouldBlock, error.kind()),
+        }
+    }
+
+    assert!(writer.get_ref().blocked);
+    assert_eq!(b"NBSWY3DPEB3W64TMMQ======".as_slice(), writer.finish().unwrap().data);
+}
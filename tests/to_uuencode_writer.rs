@@ -0,0 +1,53 @@
+use std::io::Write;
+
+use base64_stream::ToUuencodeWriter;
+
+#[test]
+fn uuencode_write() {
+    let mut writer = ToUuencodeWriter::new(Vec::new(), 0o644, "cat.txt");
+
+    writer.write_all(b"Cat").unwrap();
+
+    assert_eq!(b"begin 644 cat.txt\n#0V%T\n`\nend\n".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+fn uuencode_full_line_write() {
+    let plain = (0..47u8).collect::<Vec<u8>>();
+
+    let mut writer = ToUuencodeWriter::new(Vec::new(), 0o600, "a b");
+
+    for b in plain.chunks(1) {
+        writer.write_all(b).unwrap();
+    }
+
+    let expected = concat!(
+        "begin 600 a b\n",
+        "M``$\"`P0%!@<(\"0H+#`T.#Q`1$A,4%187&!D:&QP='A\\@(2(C)\"4F)R@I*BLL\n",
+        "\"+2X`\n",
+        "`\n",
+        "end\n",
+    );
+
+    assert_eq!(expected.as_bytes(), writer.finish().unwrap());
+}
+
+#[test]
+fn uuencode_empty_write() {
+    let writer = ToUuencodeWriter::new(Vec::new(), 0o644, "empty");
+
+    assert_eq!(b"begin 644 empty\n`\nend\n".as_slice(), writer.finish().unwrap());
+}
+
+#[test]
+fn xxencode_write() {
+    use base64_stream::{LineEnding, UuencodeVariant};
+
+    let mut writer = ToUuencodeWriter::new(Vec::new(), 0o644, "cat.txt")
+        .with_variant(UuencodeVariant::Xxencode)
+        .with_line_ending(LineEnding::CRLF);
+
+    writer.write_all(b"Cat").unwrap();
+
+    assert_eq!(b"begin 644 cat.txt\r\n1Eq3o\r\n+\r\nend\r\n".as_slice(), writer.finish().unwrap());
+}
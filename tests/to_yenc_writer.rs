@@ -0,0 +1,73 @@
+use std::io::{ErrorKind, Write};
+
+use base64_stream::ToYencWriter;
+
+#[test]
+fn yenc_write() {
+    let mut writer = ToYencWriter::new(Vec::new(), "a b.bin", 5);
+
+    writer.write_all(b"\xd6\xe0\xe3\x13a").unwrap();
+
+    let expected = [
+        b"=ybegin line=128 size=5 name=a b.bin\r\n".as_slice(),
+        b"=@=J=M=}\x8b\r\n",
+        b"=yend size=5 crc32=e6164279\r\n",
+    ]
+    .concat();
+
+    assert_eq!(expected, writer.finish().unwrap());
+}
+
+#[test]
+fn yenc_line_edges_write() {
+    // a dot starts the first line, a tab ends it and a space starts the second one
+    let mut writer = ToYencWriter::new(Vec::new(), "x", 6).with_line_length(4);
+
+    writer.write_all(b"\x04\x07\xdf\xf6\x07\xf6").unwrap();
+
+    let output = writer.finish().unwrap();
+
+    let lines = output.split(|&b| b == b'\n').collect::<Vec<_>>();
+
+    assert_eq!(b"=n1=I\r".as_slice(), lines[1]);
+    assert_eq!(b"=`1=`\r".as_slice(), lines[2]);
+    assert!(lines[3].starts_with(b"=yend size=6 "));
+}
+
+#[test]
+fn yenc_part_write() {
+    use base64_stream::YencPart;
+
+    let plain = (0..=255u8).cycle().take(10240).collect::<Vec<u8>>();
+
+    let mut writer = ToYencWriter::new(Vec::new(), "file.bin", 10240)
+        .with_part(YencPart {
+            number: 2, total: Some(2), begin: 5001, end: 10240
+        })
+        .with_file_crc32(0xBBCE3B9D);
+
+    writer.write_all(&plain[5000..]).unwrap();
+
+    let output = writer.finish().unwrap();
+
+    assert!(output.starts_with(
+        b"=ybegin part=2 total=2 line=128 size=10240 name=file.bin\r\n=ypart begin=5001 \
+          end=10240\r\n"
+    ));
+    assert!(output.ends_with(b"\r\n=yend size=5240 part=2 pcrc32=960ac9c5 crc32=bbce3b9d\r\n"));
+    assert!(output.split(|&b| b == b'\n').all(|line| line.len() <= 130));
+}
+
+#[test]
+fn yenc_size_mismatch_write() {
+    let mut writer = ToYencWriter::new(Vec::new(), "x", 2);
+
+    assert_eq!(2, writer.write(b"abc").unwrap());
+    assert_eq!(ErrorKind::InvalidInput, writer.write(b"c").unwrap_err().kind());
+
+    let mut writer = ToYencWriter::new(Vec::new(), "x", 2);
+
+    writer.write_all(b"a").unwrap();
+
+    assert_eq!(ErrorKind::InvalidInput, writer.finish().unwrap_err().kind());
+}
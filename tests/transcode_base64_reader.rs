@@ -0,0 +1,142 @@
+use std::io::{Cursor, ErrorKind, Read};
+
+use base64_stream::{
+    LineEnding, TranscodeBase64Reader,
+    base64::{
+        DecodeError, DecodeSliceError, Engine,
+        engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
+    },
+};
+
+fn decode_error(error: std::io::Error) -> DecodeError {
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    match *error.into_inner().unwrap().downcast::<DecodeSliceError>().unwrap() {
+        DecodeSliceError::DecodeError(error) => error,
+        DecodeSliceError::OutputSliceTooSmall => unreachable!(),
+    }
+}
+
+#[test]
+fn transcode_read() {
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+
+    let mut reader = TranscodeBase64Reader::new(
+        Cursor::new(STANDARD.encode(&plain)),
+        &STANDARD,
+        &URL_SAFE_NO_PAD,
+    );
+
+    let mut url_safe = String::new();
+
+    reader.read_to_string(&mut url_safe).unwrap();
+
+    assert_eq!(URL_SAFE_NO_PAD.encode(&plain), url_safe);
+}
+
+#[test]
+fn transcode_padding_read() {
+    for length in 0..10 {
+        let plain: Vec<u8> = (0u8..).take(length).collect();
+
+        let mut reader = TranscodeBase64Reader::new(
+            Cursor::new(URL_SAFE_NO_PAD.encode(&plain)),
+            &URL_SAFE_NO_PAD,
+            &STANDARD,
+        );
+
+        let mut base64 = String::new();
+
+        reader.read_to_string(&mut base64).unwrap();
+
+        assert_eq!(STANDARD.encode(&plain), base64);
+    }
+}
+
+#[test]
+fn transcode_rewrap_read() {
+    let plain: Vec<u8> = (0u8..=255).cycle().take(1000).collect();
+
+    let encoded = STANDARD.encode(&plain);
+
+    let wrapped: Vec<u8> = encoded.as_bytes().chunks(76).collect::<Vec<_>>().join(b"\r\n".as_ref());
+
+    let mut reader = TranscodeBase64Reader::new(Cursor::new(wrapped), &STANDARD, &URL_SAFE)
+        .with_line_wrap(64, LineEnding::LF);
+
+    let mut rewrapped = String::new();
+
+    reader.read_to_string(&mut rewrapped).unwrap();
+
+    let expected =
+        URL_SAFE.encode(&plain).as_bytes().chunks(64).collect::<Vec<_>>().join(b"\n".as_ref());
+
+    assert_eq!(expected, rewrapped.as_bytes());
+}
+
+#[test]
+fn transcode_small_buffer_read() {
+    let plain: Vec<u8> = (0u8..=255).cycle().take(100).collect();
+
+    let mut reader = TranscodeBase64Reader::<_, 5>::new2(
+        Cursor::new(STANDARD.encode(&plain)),
+        &STANDARD,
+        &STANDARD_NO_PAD,
+    )
+    .with_line_wrap(1, LineEnding::CRLF);
+
+    let mut rewrapped = Vec::new();
+    let mut b = [0; 3];
+
+    loop {
+        let c = reader.read(&mut b).unwrap();
+
+        if c == 0 {
+            break;
+        }
+
+        rewrapped.extend_from_slice(&b[..c]);
+    }
+
+    let expected = STANDARD_NO_PAD
+        .encode(&plain)
+        .as_bytes()
+        .chunks(1)
+        .collect::<Vec<_>>()
+        .join(b"\r\n".as_ref());
+
+    assert_eq!(expected, rewrapped);
+}
+
+#[test]
+fn transcode_invalid_byte_offset_read() {
+    let mut reader = TranscodeBase64Reader::<_, 4>::new2(
+        Cursor::new(b"YWJj\r\nZGVm\r\nZ!hp".to_vec()),
+        &STANDARD,
+        &URL_SAFE,
+    );
+
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(DecodeError::InvalidByte(13, b'!'), decode_error(error));
+}
+
+#[test]
+fn transcode_data_after_padding_read() {
+    let mut reader =
+        TranscodeBase64Reader::new(Cursor::new(b"YQ==\nYWJj".to_vec()), &STANDARD, &URL_SAFE);
+
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(DecodeError::InvalidByte(5, b'Y'), decode_error(error));
+}
+
+#[test]
+fn transcode_invalid_padding_read() {
+    let mut reader =
+        TranscodeBase64Reader::new(Cursor::new(b"YWJjZA".to_vec()), &STANDARD, &URL_SAFE);
+
+    let error = reader.read_to_end(&mut Vec::new()).unwrap_err();
+
+    assert_eq!(DecodeError::InvalidPadding, decode_error(error));
+}
@@ -0,0 +1,84 @@
+use std::io::{ErrorKind, Write};
+
+use base64_stream::{
+    LineEnding, TranscodeBase64Writer,
+    base64::{
+        DecodeError, DecodeSliceError, Engine,
+        engine::general_purpose::{STANDARD, URL_SAFE, URL_SAFE_NO_PAD},
+    },
+};
+
+fn decode_error(error: std::io::Error) -> DecodeError {
+    assert_eq!(ErrorKind::InvalidData, error.kind());
+
+    match *error.into_inner().unwrap().downcast::<DecodeSliceError>().unwrap() {
+        DecodeSliceError::DecodeError(error) => error,
+        DecodeSliceError::OutputSliceTooSmall => unreachable!(),
+    }
+}
+
+#[test]
+fn transcode_write() {
+    let plain: Vec<u8> = (0u8..=255).cycle().take(10000).collect();
+
+    let mut writer = TranscodeBase64Writer::new(Vec::new(), &URL_SAFE_NO_PAD, &STANDARD);
+
+    writer.write_all(URL_SAFE_NO_PAD.encode(&plain).as_bytes()).unwrap();
+
+    assert_eq!(STANDARD.encode(&plain).into_bytes(), writer.finish().unwrap());
+}
+
+#[test]
+fn transcode_padding_write() {
+    for length in 0..10 {
+        let plain: Vec<u8> = (0u8..).take(length).collect();
+
+        let mut writer = TranscodeBase64Writer::new(Vec::new(), &STANDARD, &URL_SAFE_NO_PAD);
+
+        writer.write_all(STANDARD.encode(&plain).as_bytes()).unwrap();
+
+        assert_eq!(URL_SAFE_NO_PAD.encode(&plain).into_bytes(), writer.finish().unwrap());
+    }
+}
+
+#[test]
+fn transcode_rewrap_byte_by_byte_write() {
+    let plain: Vec<u8> = (0u8..=255).cycle().take(1000).collect();
+
+    let wrapped =
+        STANDARD.encode(&plain).as_bytes().chunks(64).collect::<Vec<_>>().join(b"\n".as_ref());
+
+    let mut writer = TranscodeBase64Writer::<_, 12>::new2(Vec::new(), &STANDARD, &URL_SAFE)
+        .with_line_wrap(76, LineEnding::CRLF);
+
+    for b in wrapped.chunks(1) {
+        writer.write_all(b).unwrap();
+    }
+
+    let expected =
+        URL_SAFE.encode(&plain).as_bytes().chunks(76).collect::<Vec<_>>().join(b"\r\n".as_ref());
+
+    assert_eq!(expected, writer.finish().unwrap());
+}
+
+#[test]
+fn transcode_invalid_byte_offset_write() {
+    let mut writer = TranscodeBase64Writer::new(Vec::new(), &STANDARD, &URL_SAFE);
+
+    writer.write_all(b"YWJj\nZG").unwrap();
+
+    let error = writer.write_all(b"V\n!").unwrap_err();
+
+    assert_eq!(DecodeError::InvalidByte(9, b'!'), decode_error(error));
+}
+
+#[test]
+fn transcode_invalid_length_write() {
+    let mut writer = TranscodeBase64Writer::new(Vec::new(), &URL_SAFE_NO_PAD, &STANDARD);
+
+    writer.write_all(b"YWJj\nZ").unwrap();
+
+    let error = writer.finish().unwrap_err();
+
+    assert_eq!(DecodeError::InvalidLength(5), decode_error(error));
+}